@@ -1,4 +1,6 @@
-use na::{DMatrix, DVector, Matrix2, Matrix3, Matrix4, OMatrix, Vector2, Vector3, Vector4, U10};
+use na::{
+    Complex, DMatrix, DVector, Matrix2, Matrix3, Matrix4, OMatrix, Vector2, Vector3, Vector4, U10,
+};
 use rand::Rng;
 use rand_isaac::IsaacRng;
 use std::ops::{Add, Div, Mul, Sub};
@@ -136,6 +138,20 @@ fn mat500_mul_mat500(bench: &mut criterion::Criterion) {
     bench.bench_function("mat500_mul_mat500", move |bh| bh.iter(|| &a * &b));
 }
 
+fn mat500_mul_mat500_complex(bench: &mut criterion::Criterion) {
+    let a = DMatrix::<Complex<f64>>::from_fn(500, 500, |i, j| Complex::new(i as f64, j as f64));
+    let b = DMatrix::<Complex<f64>>::from_fn(500, 500, |i, j| Complex::new(j as f64, i as f64));
+
+    bench.bench_function("mat500_mul_mat500_complex", move |bh| bh.iter(|| &a * &b));
+}
+
+fn mat500_mul_mat500_i64(bench: &mut criterion::Criterion) {
+    let a = DMatrix::<i64>::from_fn(500, 500, |i, j| (i * 7 + j * 3) as i64 % 17);
+    let b = DMatrix::<i64>::from_fn(500, 500, |i, j| (i * 5 + j * 11) as i64 % 13);
+
+    bench.bench_function("mat500_mul_mat500_i64", move |bh| bh.iter(|| &a * &b));
+}
+
 fn iter(bench: &mut criterion::Criterion) {
     let a = DMatrix::<f64>::new_random(1000, 1000);
 
@@ -259,6 +275,8 @@ criterion_group!(
     mat10_mul_mat10_static,
     mat100_mul_mat100,
     mat500_mul_mat500,
+    mat500_mul_mat500_complex,
+    mat500_mul_mat500_i64,
     iter,
     iter_rev,
     copy_from,
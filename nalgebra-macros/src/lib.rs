@@ -6,7 +6,7 @@
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
-use quote::{quote, ToTokens, TokenStreamExt};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use syn::parse::{Error, Parse, ParseStream, Result};
 use syn::punctuated::Punctuated;
 use syn::Expr;
@@ -188,6 +188,130 @@ pub fn dmatrix(stream: TokenStream) -> TokenStream {
     proc_macro::TokenStream::from(output)
 }
 
+/// Construct a dynamic matrix by stacking sub-matrices (blocks).
+///
+/// **Note: Requires the `macro` feature to be enabled (enabled by default)**.
+///
+/// The syntax is the same as for [`matrix!`], except that each entry is an expression evaluating
+/// to a matrix (or a reference to a matrix, or a matrix slice) instead of a scalar. All the blocks
+/// of a block row must have the same number of rows, and all the blocks of a block column must
+/// have the same number of columns. The blocks may have different types, e.g. static and dynamic
+/// matrices can be mixed, as long as they share the same scalar type. The result is always a
+/// `DMatrix`, and each block expression is evaluated exactly once, in row-major order.
+///
+/// # Panics
+///
+/// The dimensions of the blocks are only known at runtime, so incompatible block sizes result in
+/// a panic identifying the offending block. A different number of blocks on two block rows is a
+/// compile-time error.
+///
+/// # Examples
+///
+/// ```
+/// use nalgebra::{block_matrix, dmatrix, DMatrix, Matrix2, Vector2};
+///
+/// // Assemble the KKT matrix [H  Aᵀ; A  0] of an equality-constrained quadratic program.
+/// let h = Matrix2::new(2.0, 0.5,
+///                      0.5, 1.0);
+/// let a = Vector2::new(1.0, 1.0).transpose();
+/// let kkt = block_matrix![h,  a.transpose();
+///                         a,  DMatrix::zeros(1, 1)];
+///
+/// assert_eq!(kkt, dmatrix![2.0, 0.5, 1.0;
+///                          0.5, 1.0, 1.0;
+///                          1.0, 1.0, 0.0]);
+/// ```
+#[proc_macro]
+pub fn block_matrix(stream: TokenStream) -> TokenStream {
+    let matrix = parse_macro_input!(stream as Matrix);
+
+    let nblock_rows = matrix.nrows();
+    let nblock_cols = matrix.ncols();
+
+    if nblock_rows == 0 {
+        return proc_macro::TokenStream::from(quote! { nalgebra::DMatrix::zeros(0, 0) });
+    }
+
+    let block = |i: usize, j: usize| format_ident!("__block_matrix_block_{}_{}", i, j);
+
+    let mut bindings = TokenStream2::new();
+    let mut checks = TokenStream2::new();
+    let mut copies = TokenStream2::new();
+
+    for (i, row) in matrix.rows.iter().enumerate() {
+        for (j, expr) in row.iter().enumerate() {
+            let b = block(i, j);
+            bindings.extend(quote! { let #b = #expr; });
+        }
+    }
+
+    let row_heights: Vec<_> = (0..nblock_rows)
+        .map(|i| {
+            let b = block(i, 0);
+            quote! { #b.nrows() }
+        })
+        .collect();
+    let col_widths: Vec<_> = (0..nblock_cols)
+        .map(|j| {
+            let b = block(0, j);
+            quote! { #b.ncols() }
+        })
+        .collect();
+
+    for i in 0..nblock_rows {
+        for j in 0..nblock_cols {
+            let b = block(i, j);
+            checks.extend(quote! {
+                if #b.nrows() != __block_matrix_row_heights[#i] {
+                    panic!(
+                        "block_matrix!: block ({}, {}) has {} rows, but block ({}, 0) on the same block row has {} rows.",
+                        #i, #j, #b.nrows(), #i, __block_matrix_row_heights[#i]
+                    );
+                }
+                if #b.ncols() != __block_matrix_col_widths[#j] {
+                    panic!(
+                        "block_matrix!: block ({}, {}) has {} columns, but block (0, {}) on the same block column has {} columns.",
+                        #i, #j, #b.ncols(), #j, __block_matrix_col_widths[#j]
+                    );
+                }
+            });
+            copies.extend(quote! {
+                let __block_matrix_start = (
+                    __block_matrix_row_heights[..#i].iter().sum::<usize>(),
+                    __block_matrix_col_widths[..#j].iter().sum::<usize>(),
+                );
+                let __block_matrix_shape =
+                    (__block_matrix_row_heights[#i], __block_matrix_col_widths[#j]);
+                for (dst, src) in __block_matrix_result
+                    .slice_mut(__block_matrix_start, __block_matrix_shape)
+                    .iter_mut()
+                    .zip(#b.iter())
+                {
+                    *dst = Clone::clone(src);
+                }
+            });
+        }
+    }
+
+    let output = quote! {
+        {
+            #bindings
+            let __block_matrix_row_heights: [usize; #nblock_rows] = [#(#row_heights),*];
+            let __block_matrix_col_widths: [usize; #nblock_cols] = [#(#col_widths),*];
+            #checks
+
+            let mut __block_matrix_result = nalgebra::DMatrix::zeros(
+                __block_matrix_row_heights.iter().sum(),
+                __block_matrix_col_widths.iter().sum(),
+            );
+            #copies
+            __block_matrix_result
+        }
+    };
+
+    proc_macro::TokenStream::from(output)
+}
+
 struct Vector {
     elements: Vec<Expr>,
 }
@@ -4,7 +4,7 @@ use nalgebra::{
     Point1, Point2, Point3, Point4, Point5, Point6, SMatrix, SVector, Vector1, Vector2, Vector3,
     Vector4, Vector5, Vector6,
 };
-use nalgebra_macros::{dmatrix, dvector, matrix, point, vector};
+use nalgebra_macros::{block_matrix, dmatrix, dvector, matrix, point, vector};
 
 fn check_statically_same_type<T>(_: &T, _: &T) {}
 
@@ -305,3 +305,68 @@ fn dvector_arbitrary_expressions() {
     let a_expected = DVector::from_column_slice(&[1 + 2, 2 * 3, 4 * f(5 + 6), 7 - 8 * 9]);
     assert_eq_and_type!(a, a_expected);
 }
+
+#[rustfmt::skip]
+#[test]
+fn block_matrix_mixed_blocks() {
+    let a = Matrix2::new(1, 2,
+                         3, 4);
+    let b = DMatrix::from_row_slice(2, 1, &[5,
+                                            6]);
+    let c = Matrix1x2::new(7, 8);
+    let m = block_matrix![a,  &b;
+                          c,  DMatrix::from_element(1, 1, 9)];
+    assert_eq_and_type!(m, dmatrix![1, 2, 5;
+                                    3, 4, 6;
+                                    7, 8, 9]);
+
+    // Blocks can be slices and arbitrary expressions, and the blocks of a single row can be used.
+    let big = Matrix3x4::new(1,  2,  3,  4,
+                             5,  6,  7,  8,
+                             9, 10, 11, 12);
+    let m = block_matrix![big.fixed_columns::<2>(2), f(Matrix3::identity() * 2)];
+    assert_eq_and_type!(m, dmatrix![3,  4, 2, 0, 0;
+                                    7,  8, 0, 2, 0;
+                                    11, 12, 0, 0, 2]);
+
+    let m = block_matrix![big.row(0); big.rows(1, 2)];
+    assert_eq_and_type!(m, DMatrix::from_row_slice(3, 4, big.transpose().as_slice()));
+
+    let empty: DMatrix<f64> = block_matrix![];
+    assert_eq!(empty.shape(), (0, 0));
+
+    let with_empty_blocks = block_matrix![DMatrix::<i32>::zeros(0, 2), DMatrix::zeros(0, 1);
+                                          a,                           b];
+    assert_eq_and_type!(with_empty_blocks, dmatrix![1, 2, 5;
+                                                    3, 4, 6]);
+}
+
+#[test]
+fn block_matrix_evaluates_blocks_once_in_row_major_order() {
+    let mut order = Vec::new();
+    let mut block = |index: i32| {
+        order.push(index);
+        Matrix1x2::new(index, index)
+    };
+    let m = block_matrix![block(0), block(1); block(2), block(3)];
+    assert_eq!(order, vec![0, 1, 2, 3]);
+    assert_eq!(m, dmatrix![0, 0, 1, 1; 2, 2, 3, 3]);
+}
+
+#[test]
+#[should_panic(
+    expected = "block_matrix!: block (1, 1) has 2 rows, but block (1, 0) on the same block row has 1 rows."
+)]
+fn block_matrix_mismatched_block_rows() {
+    let _ = block_matrix![Matrix2::<i32>::identity(), Matrix2::identity();
+                          Matrix1x2::zeros(), Matrix2::zeros()];
+}
+
+#[test]
+#[should_panic(
+    expected = "block_matrix!: block (1, 0) has 3 columns, but block (0, 0) on the same block column has 2 columns."
+)]
+fn block_matrix_mismatched_block_columns() {
+    let _ = block_matrix![Matrix2::<i32>::identity(), Matrix2::identity();
+                          Matrix1x3::zeros(), Matrix1x2::zeros()];
+}
@@ -1,7 +1,7 @@
 use std::mem::replace;
 use std::ops::Range;
 
-use num_traits::One;
+use num_traits::{One, Zero};
 
 use nalgebra::Scalar;
 
@@ -178,6 +178,19 @@ impl<T> CsMatrix<T> {
         CsLaneIterMut::new(&self.sparsity_pattern, &mut self.values)
     }
 
+    #[inline]
+    #[must_use]
+    pub fn map<T2, F>(&self, f: F) -> CsMatrix<T2>
+    where
+        T: Clone,
+        F: FnMut(T) -> T2,
+    {
+        CsMatrix {
+            sparsity_pattern: self.sparsity_pattern.clone(),
+            values: self.values.iter().cloned().map(f).collect(),
+        }
+    }
+
     #[inline]
     #[must_use]
     pub fn filter<P>(&self, predicate: P) -> Self
@@ -214,6 +227,53 @@ impl<T> CsMatrix<T> {
         Self::from_pattern_and_values(new_pattern, new_values)
     }
 
+    /// Keeps only the explicit entries decided by the given predicate, compacting the minor
+    /// indices and values in place.
+    ///
+    /// The predicate is evaluated for all entries before the matrix is modified, so the matrix
+    /// is left untouched if it panics.
+    pub fn retain<P>(&mut self, predicate: P)
+    where
+        P: Fn(usize, usize, &T) -> bool,
+    {
+        let mut keep = Vec::with_capacity(self.pattern().nnz());
+        for (i, lane) in self.lane_iter().enumerate() {
+            for (&j, value) in lane.minor_indices().iter().zip(lane.values) {
+                keep.push(predicate(i, j, value));
+            }
+        }
+
+        let (major_dim, minor_dim) = (self.pattern().major_dim(), self.pattern().minor_dim());
+        let (pattern, mut values) = replace(self, CsMatrix::new(0, 0)).take_pattern_and_values();
+        let (mut offsets, mut indices) = pattern.disassemble();
+
+        let mut lane_begin = 0;
+        let mut nnz = 0;
+        for i in 0..major_dim {
+            let lane_end = offsets[i + 1];
+            for k in lane_begin..lane_end {
+                if keep[k] {
+                    // Entries are only ever moved towards the front, so the relative order
+                    // within each lane, and hence the sortedness, is preserved.
+                    indices[nnz] = indices[k];
+                    values.swap(nnz, k);
+                    nnz += 1;
+                }
+            }
+
+            lane_begin = lane_end;
+            offsets[i + 1] = nnz;
+        }
+
+        indices.truncate(nnz);
+        values.truncate(nnz);
+
+        let new_pattern = SparsityPattern::from_offsets_and_indices_unchecked(
+            major_dim, minor_dim, offsets, indices,
+        );
+        *self = Self::from_pattern_and_values(new_pattern, values);
+    }
+
     /// Returns the diagonal of the matrix as a sparse matrix.
     #[must_use]
     pub fn diagonal_as_matrix(&self) -> Self
@@ -225,6 +285,52 @@ impl<T> CsMatrix<T> {
     }
 }
 
+impl<T: Zero> CsMatrix<T> {
+    /// Checks that `eq` holds for every pair of corresponding entries in `self` and `other`.
+    ///
+    /// Every entry in the union of both sparsity patterns is visited, and entries that are not
+    /// explicitly stored in one of the matrices are treated as zeros. Returns `false` if the
+    /// dimensions of the matrices differ.
+    pub fn entries_all_match(&self, other: &Self, mut eq: impl FnMut(&T, &T) -> bool) -> bool {
+        if self.pattern().major_dim() != other.pattern().major_dim()
+            || self.pattern().minor_dim() != other.pattern().minor_dim()
+        {
+            return false;
+        }
+
+        let zero = T::zero();
+        (0..self.pattern().major_dim()).all(|major_idx| {
+            let a = self.get_lane(major_idx).expect("Index is in bounds.");
+            let b = other.get_lane(major_idx).expect("Index is in bounds.");
+            let (a_indices, a_values) = (a.minor_indices(), a.values());
+            let (b_indices, b_values) = (b.minor_indices(), b.values());
+
+            let (mut p, mut q) = (0, 0);
+            while p < a_indices.len() || q < b_indices.len() {
+                let a_idx = a_indices.get(p).copied().unwrap_or(usize::MAX);
+                let b_idx = b_indices.get(q).copied().unwrap_or(usize::MAX);
+                let matches = if a_idx < b_idx {
+                    p += 1;
+                    eq(&a_values[p - 1], &zero)
+                } else if b_idx < a_idx {
+                    q += 1;
+                    eq(&zero, &b_values[q - 1])
+                } else {
+                    p += 1;
+                    q += 1;
+                    eq(&a_values[p - 1], &b_values[q - 1])
+                };
+
+                if !matches {
+                    return false;
+                }
+            }
+
+            true
+        })
+    }
+}
+
 impl<T: Scalar + One> CsMatrix<T> {
     #[inline]
     pub fn identity(n: usize) -> Self {
@@ -8,8 +8,9 @@ use crate::csr::CsrMatrix;
 use crate::pattern::{SparsityPattern, SparsityPatternFormatError, SparsityPatternIter};
 use crate::{SparseEntry, SparseEntryMut, SparseFormatError, SparseFormatErrorKind};
 
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use nalgebra::Scalar;
-use num_traits::One;
+use num_traits::{One, Zero};
 use std::slice::{Iter, IterMut};
 
 /// A CSC representation of a sparse matrix.
@@ -452,6 +453,37 @@ impl<T> CscMatrix<T> {
             .expect("Out of bounds matrix indices encountered")
     }
 
+    /// Returns a reference to the explicitly stored value at the given row/col indices.
+    ///
+    /// Returns `None` if the indices are out of bounds, or if the entry is not explicitly
+    /// stored, i.e. if it is a structural zero.
+    ///
+    /// Each call to this function incurs the cost of a binary search among the explicitly
+    /// stored row entries for the given column, i.e. `O(log k)` where `k` is the number of
+    /// explicitly stored entries in the column.
+    #[must_use]
+    pub fn get(&self, row_index: usize, col_index: usize) -> Option<&T> {
+        match self.get_entry(row_index, col_index)? {
+            SparseEntry::NonZero(value) => Some(value),
+            SparseEntry::Zero => None,
+        }
+    }
+
+    /// Returns a mutable reference to the explicitly stored value at the given row/col indices.
+    ///
+    /// Returns `None` if the indices are out of bounds, or if the entry is not explicitly
+    /// stored, i.e. if it is a structural zero.
+    ///
+    /// Each call to this function incurs the cost of a binary search among the explicitly
+    /// stored row entries for the given column, i.e. `O(log k)` where `k` is the number of
+    /// explicitly stored entries in the column.
+    pub fn get_mut(&mut self, row_index: usize, col_index: usize) -> Option<&mut T> {
+        match self.get_entry_mut(row_index, col_index)? {
+            SparseEntryMut::NonZero(value) => Some(value),
+            SparseEntryMut::Zero => None,
+        }
+    }
+
     /// Returns a triplet of slices `(col_offsets, row_indices, values)` that make up the CSC data.
     #[must_use]
     pub fn csc_data(&self) -> (&[usize], &[usize], &[T]) {
@@ -464,6 +496,34 @@ impl<T> CscMatrix<T> {
         self.cs.cs_data_mut()
     }
 
+    /// Returns a new matrix with the same sparsity pattern, whose explicitly stored entries are
+    /// obtained by applying `f` to the explicitly stored entries of this matrix.
+    ///
+    /// Structural zeros are not visited by `f`, and remain zero in the result. This is the sparse
+    /// counterpart of [`Matrix::map`](nalgebra::Matrix::map).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra_sparse::{coo::CooMatrix, CscMatrix};
+    /// let mut coo = CooMatrix::new(2, 3);
+    /// coo.push(0, 1, -1.5f32);
+    /// coo.push(1, 2, 2.0f32);
+    /// let matrix = CscMatrix::from(&coo);
+    ///
+    /// let abs = matrix.map(|x| f64::from(x.abs()));
+    /// assert_eq!(abs.pattern(), matrix.pattern());
+    /// assert_eq!(abs.values(), &[1.5f64, 2.0]);
+    /// ```
+    #[must_use]
+    pub fn map<T2, F>(&self, f: F) -> CscMatrix<T2>
+    where
+        T: Clone,
+        F: FnMut(T) -> T2,
+    {
+        CscMatrix { cs: self.cs.map(f) }
+    }
+
     /// Creates a sparse matrix that contains only the explicit entries decided by the
     /// given predicate.
     #[must_use]
@@ -503,6 +563,30 @@ impl<T> CscMatrix<T> {
         self.filter(|i, j, _| i >= j)
     }
 
+    /// Returns a new matrix representing the strictly upper triangular part of this matrix.
+    ///
+    /// The result excludes the diagonal of the matrix, so that the matrix is the sum of
+    /// [`strictly_lower_triangle`](Self::strictly_lower_triangle),
+    /// [`diagonal_as_csc`](Self::diagonal_as_csc) and this.
+    #[must_use]
+    pub fn strictly_upper_triangle(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.filter(|i, j, _| i < j)
+    }
+
+    /// Returns a new matrix representing the strictly lower triangular part of this matrix.
+    ///
+    /// The result excludes the diagonal of the matrix.
+    #[must_use]
+    pub fn strictly_lower_triangle(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.filter(|i, j, _| i > j)
+    }
+
     /// Returns the diagonal of the matrix as a sparse matrix.
     #[must_use]
     pub fn diagonal_as_csc(&self) -> Self
@@ -524,6 +608,93 @@ impl<T> CscMatrix<T> {
     }
 }
 
+impl<T> AbsDiffEq for CscMatrix<T>
+where
+    T: AbsDiffEq + Zero,
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    /// Compares the entries of both matrices in the union of their sparsity patterns, treating
+    /// entries that are not explicitly stored as exact zeros.
+    ///
+    /// Matrices of different dimensions are never equal.
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.cs
+            .entries_all_match(&other.cs, |a, b| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl<T> RelativeEq for CscMatrix<T>
+where
+    T: RelativeEq + Zero,
+    T::Epsilon: Copy,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    /// Compares the entries of both matrices in the union of their sparsity patterns, treating
+    /// entries that are not explicitly stored as exact zeros.
+    ///
+    /// Unlike `==`, which also compares the sparsity patterns, this considers an explicitly
+    /// stored zero to be equal to an entry that is not stored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra_sparse::coo::CooMatrix;
+    /// # use nalgebra_sparse::csc::CscMatrix;
+    /// let mut coo = CooMatrix::new(2, 2);
+    /// coo.push(0, 0, 1.0);
+    /// let a = CscMatrix::from(&coo);
+    ///
+    /// coo.push(1, 1, 0.0);
+    /// coo.push(0, 0, 1.0e-12);
+    /// let b = CscMatrix::from(&coo);
+    ///
+    /// assert_ne!(a, b);
+    /// assert_relative_eq!(a, b, epsilon = 1.0e-10);
+    /// ```
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.cs
+            .entries_all_match(&other.cs, |a, b| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl<T> UlpsEq for CscMatrix<T>
+where
+    T: UlpsEq + Zero,
+    T::Epsilon: Copy,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    /// Compares the entries of both matrices in the union of their sparsity patterns, treating
+    /// entries that are not explicitly stored as exact zeros.
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.cs
+            .entries_all_match(&other.cs, |a, b| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
 /// Convert pattern format errors into more meaningful CSC-specific errors.
 ///
 /// This ensures that the terminology is consistent: we are talking about rows and columns,
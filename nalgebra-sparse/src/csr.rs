@@ -7,9 +7,14 @@ use crate::csc::CscMatrix;
 use crate::pattern::{SparsityPattern, SparsityPatternFormatError, SparsityPatternIter};
 use crate::{SparseEntry, SparseEntryMut, SparseFormatError, SparseFormatErrorKind};
 
-use nalgebra::Scalar;
-use num_traits::One;
-
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use nalgebra::storage::Storage;
+use nalgebra::{ClosedAdd, ClosedMul, ComplexField, DVector, Dim, Matrix, Scalar};
+use num_traits::{One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use std::ops::Range;
 use std::slice::{Iter, IterMut};
 
 /// A CSR representation of a sparse matrix.
@@ -145,6 +150,298 @@ impl<T> CsrMatrix<T> {
         }
     }
 
+    /// Constructs a block-diagonal CSR matrix with the given blocks placed along its diagonal.
+    ///
+    /// The blocks do not need to be square. The number of rows (resp. columns) of the result is
+    /// the sum of the number of rows (resp. columns) of all the blocks, and only the explicitly
+    /// stored entries of the blocks are stored in the result. If `blocks` is empty, a `0x0`
+    /// matrix is returned.
+    pub fn block_diagonal(blocks: &[&CsrMatrix<T>]) -> Self
+    where
+        T: Clone,
+    {
+        let nrows = blocks.iter().map(|b| b.nrows()).sum();
+        let ncols = blocks.iter().map(|b| b.ncols()).sum();
+        let nnz = blocks.iter().map(|b| b.nnz()).sum();
+
+        let mut row_offsets = Vec::with_capacity(nrows + 1);
+        let mut col_indices = Vec::with_capacity(nnz);
+        let mut values = Vec::with_capacity(nnz);
+        row_offsets.push(0);
+
+        let mut col_offset = 0;
+        for block in blocks {
+            for row in block.row_iter() {
+                col_indices.extend(row.col_indices().iter().map(|j| j + col_offset));
+                values.extend_from_slice(row.values());
+                row_offsets.push(col_indices.len());
+            }
+            col_offset += block.ncols();
+        }
+
+        Self::try_from_csr_data(nrows, ncols, row_offsets, col_indices, values)
+            .expect("Internal error: block diagonal matrix must be valid.")
+    }
+
+    /// Stacks the given matrices vertically, i.e., concatenates their rows.
+    ///
+    /// If `blocks` is empty, a `0x0` matrix is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the blocks do not all have the same number of columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// # use nalgebra_sparse::CsrMatrix;
+    /// let a = CsrMatrix::from(&DMatrix::from_row_slice(1, 3, &[1.0, 0.0, 2.0]));
+    /// let b = CsrMatrix::from(&DMatrix::from_row_slice(2, 3, &[0.0, 3.0, 0.0,
+    ///                                                          4.0, 0.0, 5.0]));
+    /// let stacked = CsrMatrix::vstack(&[&a, &b]);
+    /// assert_eq!(DMatrix::from(&stacked), DMatrix::from_row_slice(3, 3, &[1.0, 0.0, 2.0,
+    ///                                                                     0.0, 3.0, 0.0,
+    ///                                                                     4.0, 0.0, 5.0]));
+    /// ```
+    pub fn vstack(blocks: &[&CsrMatrix<T>]) -> Self
+    where
+        T: Clone,
+    {
+        let ncols = blocks.first().map(|b| b.ncols()).unwrap_or(0);
+        assert!(
+            blocks.iter().all(|b| b.ncols() == ncols),
+            "All blocks must have the same number of columns to be stacked vertically."
+        );
+
+        let nrows = blocks.iter().map(|b| b.nrows()).sum();
+        let nnz = blocks.iter().map(|b| b.nnz()).sum();
+
+        let mut row_offsets = Vec::with_capacity(nrows + 1);
+        let mut col_indices = Vec::with_capacity(nnz);
+        let mut values = Vec::with_capacity(nnz);
+        row_offsets.push(0);
+
+        for block in blocks {
+            // Each row of a block is a row of the result, so we only need to shift the offsets.
+            let nnz_offset = col_indices.len();
+            row_offsets.extend(block.row_offsets()[1..].iter().map(|p| p + nnz_offset));
+            col_indices.extend_from_slice(block.col_indices());
+            values.extend_from_slice(block.values());
+        }
+
+        Self::try_from_csr_data(nrows, ncols, row_offsets, col_indices, values)
+            .expect("Internal error: vertically stacked matrix must be valid.")
+    }
+
+    /// Stacks the given matrices horizontally, i.e., concatenates their columns.
+    ///
+    /// If `blocks` is empty, a `0x0` matrix is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the blocks do not all have the same number of rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// # use nalgebra_sparse::CsrMatrix;
+    /// let a = CsrMatrix::from(&DMatrix::from_row_slice(2, 1, &[1.0,
+    ///                                                          0.0]));
+    /// let b = CsrMatrix::from(&DMatrix::from_row_slice(2, 2, &[0.0, 2.0,
+    ///                                                          3.0, 0.0]));
+    /// let stacked = CsrMatrix::hstack(&[&a, &b]);
+    /// assert_eq!(DMatrix::from(&stacked), DMatrix::from_row_slice(2, 3, &[1.0, 0.0, 2.0,
+    ///                                                                     0.0, 3.0, 0.0]));
+    /// ```
+    pub fn hstack(blocks: &[&CsrMatrix<T>]) -> Self
+    where
+        T: Clone,
+    {
+        let nrows = blocks.first().map(|b| b.nrows()).unwrap_or(0);
+        assert!(
+            blocks.iter().all(|b| b.nrows() == nrows),
+            "All blocks must have the same number of rows to be stacked horizontally."
+        );
+
+        let ncols = blocks.iter().map(|b| b.ncols()).sum();
+        let nnz = blocks.iter().map(|b| b.nnz()).sum();
+
+        let mut row_offsets = Vec::with_capacity(nrows + 1);
+        let mut col_indices = Vec::with_capacity(nnz);
+        let mut values = Vec::with_capacity(nnz);
+        row_offsets.push(0);
+
+        for i in 0..nrows {
+            // The blocks are visited from left to right, so the shifted column indices of the
+            // row remain sorted.
+            let mut col_offset = 0;
+            for block in blocks {
+                let row = block.row(i);
+                col_indices.extend(row.col_indices().iter().map(|j| j + col_offset));
+                values.extend_from_slice(row.values());
+                col_offset += block.ncols();
+            }
+            row_offsets.push(col_indices.len());
+        }
+
+        Self::try_from_csr_data(nrows, ncols, row_offsets, col_indices, values)
+            .expect("Internal error: horizontally stacked matrix must be valid.")
+    }
+
+    /// Computes the kronecker sum `self ⊗ Iₘ + Iₙ ⊗ rhs` of two square matrices, where `n` and
+    /// `m` are the dimensions of `self` and `rhs`.
+    ///
+    /// This is the standard way of assembling separable operators, e.g. the 2D finite-difference
+    /// Laplacian is the kronecker sum of two 1D Laplacians. The result is assembled directly in
+    /// `O(m * nnz(self) + n * nnz(rhs))` time, without forming the two kronecker products. An entry
+    /// is stored in the result if it is stored in either of the two terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` or `rhs` is not square.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// # use nalgebra_sparse::CsrMatrix;
+    /// // 1D Laplacians on grids with 3 and 2 points
+    /// let lx = DMatrix::from_row_slice(3, 3, &[2.0, -1.0, 0.0,
+    ///                                          -1.0, 2.0, -1.0,
+    ///                                          0.0, -1.0, 2.0]);
+    /// let ly = DMatrix::from_row_slice(2, 2, &[2.0, -1.0,
+    ///                                          -1.0, 2.0]);
+    ///
+    /// let laplacian = CsrMatrix::from(&lx).kronecker_sum(&CsrMatrix::from(&ly));
+    /// assert_eq!(DMatrix::from(&laplacian), lx.kronecker_sum(&ly));
+    /// // 6 diagonal entries, and 2 entries for each of the 7 edges of the 3x2 grid
+    /// assert_eq!(laplacian.nnz(), 6 + 2 * 7);
+    /// ```
+    pub fn kronecker_sum(&self, rhs: &CsrMatrix<T>) -> Self
+    where
+        T: Scalar + ClosedAdd,
+    {
+        assert!(
+            self.nrows() == self.ncols() && rhs.nrows() == rhs.ncols(),
+            "Unable to compute the kronecker sum of non-square matrices."
+        );
+
+        let (n, m) = (self.nrows(), rhs.nrows());
+        let nnz = self.nnz() * m + rhs.nnz() * n;
+
+        let mut row_offsets = Vec::with_capacity(n * m + 1);
+        let mut col_indices = Vec::with_capacity(nnz);
+        let mut values = Vec::with_capacity(nnz);
+        row_offsets.push(0);
+
+        for (i1, row1) in self.row_iter().enumerate() {
+            // In the row `i1 * m + i2`, the entries of `self ⊗ Iₘ` are in the columns
+            // `j1 * m + i2`, and those of `Iₙ ⊗ rhs` lie in the diagonal block of columns
+            // `i1 * m + j2`. Only the diagonal entry of `self` may overlap with the latter.
+            let (cols1, vals1) = (row1.col_indices(), row1.values());
+            let (diag_start, diag_end, diag) = match cols1.binary_search(&i1) {
+                Ok(k) => (k, k + 1, Some(&vals1[k])),
+                Err(k) => (k, k, None),
+            };
+
+            for (i2, row2) in rhs.row_iter().enumerate() {
+                for (j1, v1) in cols1[..diag_start].iter().zip(&vals1[..diag_start]) {
+                    col_indices.push(j1 * m + i2);
+                    values.push(v1.inlined_clone());
+                }
+
+                let mut diag = diag;
+                for (&j2, v2) in row2.col_indices().iter().zip(row2.values()) {
+                    if j2 >= i2 {
+                        if let Some(d) = diag.take() {
+                            if j2 == i2 {
+                                col_indices.push(i1 * m + j2);
+                                values.push(d.inlined_clone() + v2.inlined_clone());
+                                continue;
+                            }
+                            col_indices.push(i1 * m + i2);
+                            values.push(d.inlined_clone());
+                        }
+                    }
+                    col_indices.push(i1 * m + j2);
+                    values.push(v2.inlined_clone());
+                }
+                if let Some(d) = diag {
+                    col_indices.push(i1 * m + i2);
+                    values.push(d.inlined_clone());
+                }
+
+                for (j1, v1) in cols1[diag_end..].iter().zip(&vals1[diag_end..]) {
+                    col_indices.push(j1 * m + i2);
+                    values.push(v1.inlined_clone());
+                }
+
+                row_offsets.push(col_indices.len());
+            }
+        }
+
+        Self::try_from_csr_data(n * m, n * m, row_offsets, col_indices, values)
+            .expect("Internal error: kronecker sum must be a valid CSR matrix.")
+    }
+
+    /// Constructs a CSR matrix from a dense matrix, storing only the entries whose magnitude is
+    /// strictly greater than `threshold`.
+    ///
+    /// The magnitude of an entry is its modulus, so that complex entries are compared with the
+    /// threshold by their absolute value. With a zero threshold, this is equivalent to the
+    /// conversion from a dense matrix, except that entries such as `NaN` are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// # use nalgebra_sparse::CsrMatrix;
+    /// let dense = DMatrix::from_row_slice(2, 3, &[1.0, 1.0e-12, 0.0,
+    ///                                             0.0,  -2.0,  -1.0e-9]);
+    /// let csr = CsrMatrix::from_dense_with_threshold(&dense, 1.0e-6);
+    /// assert_eq!(csr.nnz(), 2);
+    /// assert_eq!(csr.col_indices(), &[0, 1]);
+    /// assert_eq!(csr.values(), &[1.0, -2.0]);
+    /// ```
+    pub fn from_dense_with_threshold<R, C, S>(
+        dense: &Matrix<T, R, C, S>,
+        threshold: T::RealField,
+    ) -> Self
+    where
+        T: ComplexField,
+        R: Dim,
+        C: Dim,
+        S: Storage<T, R, C>,
+    {
+        let mut row_offsets = Vec::with_capacity(dense.nrows() + 1);
+        let mut col_indices = Vec::new();
+        let mut values = Vec::new();
+        row_offsets.push(0);
+
+        // Visiting the entries row by row yields sorted column indices in each row.
+        for i in 0..dense.nrows() {
+            for j in 0..dense.ncols() {
+                let v = dense[(i, j)];
+                if v.modulus() > threshold {
+                    col_indices.push(j);
+                    values.push(v);
+                }
+            }
+            row_offsets.push(col_indices.len());
+        }
+
+        Self::try_from_csr_data(
+            dense.nrows(),
+            dense.ncols(),
+            row_offsets,
+            col_indices,
+            values,
+        )
+        .expect("Internal error: thresholded dense matrix must be a valid CSR matrix.")
+    }
+
     /// Try to construct a CSR matrix from raw CSR data.
     ///
     /// It is assumed that each row contains unique and sorted column indices that are in
@@ -397,6 +694,62 @@ impl<T> CsrMatrix<T> {
         self.cs.pattern()
     }
 
+    /// Computes summary statistics of the sparsity pattern of this matrix.
+    ///
+    /// This is intended as a quick diagnostic, e.g. when debugging the assembly of a matrix.
+    /// Only the sparsity pattern is inspected, so explicitly stored zeros count as stored
+    /// entries. The statistics are computed in `O(nrows + nnz)` time.
+    ///
+    /// Examples
+    /// --------
+    ///
+    /// ```
+    /// # use nalgebra_sparse::csr::CsrMatrix;
+    /// let row_offsets = vec![0, 2, 2, 5];
+    /// let col_indices = vec![0, 2, 0, 1, 2];
+    /// let values = vec![1, 2, 3, 4, 5];
+    /// let csr = CsrMatrix::try_from_csr_data(3, 3, row_offsets, col_indices, values).unwrap();
+    ///
+    /// let stats = csr.sparsity_stats();
+    /// assert_eq!(stats.nnz, 5);
+    /// assert_eq!(stats.density, 5.0 / 9.0);
+    /// assert_eq!((stats.min_row_nnz, stats.max_row_nnz), (0, 3));
+    /// assert_eq!(stats.mean_row_nnz, 5.0 / 3.0);
+    /// assert_eq!(stats.empty_rows, 1);
+    /// assert!(!stats.full_diagonal);
+    /// ```
+    #[must_use]
+    pub fn sparsity_stats(&self) -> SparsityStats {
+        let pattern = self.pattern();
+        let (nrows, ncols, nnz) = (self.nrows(), self.ncols(), self.nnz());
+
+        let row_nnz = pattern.major_offsets().windows(2).map(|w| w[1] - w[0]);
+        let min_row_nnz = row_nnz.clone().min().unwrap_or(0);
+        let max_row_nnz = row_nnz.clone().max().unwrap_or(0);
+        let empty_rows = row_nnz.filter(|&count| count == 0).count();
+
+        let full_diagonal =
+            (0..nrows.min(ncols)).all(|i| pattern.lane(i).binary_search(&i).is_ok());
+
+        let ratio = |numerator: usize, denominator: usize| {
+            if denominator == 0 {
+                0.0
+            } else {
+                numerator as f64 / denominator as f64
+            }
+        };
+
+        SparsityStats {
+            nnz,
+            density: ratio(nnz, nrows * ncols),
+            min_row_nnz,
+            max_row_nnz,
+            mean_row_nnz: ratio(nnz, nrows),
+            empty_rows,
+            full_diagonal,
+        }
+    }
+
     /// Reinterprets the CSR matrix as its transpose represented by a CSC matrix.
     ///
     /// This operation does not touch the CSR data, and is effectively a no-op.
@@ -454,6 +807,37 @@ impl<T> CsrMatrix<T> {
             .expect("Out of bounds matrix indices encountered")
     }
 
+    /// Returns a reference to the explicitly stored value at the given row/col indices.
+    ///
+    /// Returns `None` if the indices are out of bounds, or if the entry is not explicitly
+    /// stored, i.e. if it is a structural zero.
+    ///
+    /// Each call to this function incurs the cost of a binary search among the explicitly
+    /// stored column entries for the given row, i.e. `O(log k)` where `k` is the number of
+    /// explicitly stored entries in the row.
+    #[must_use]
+    pub fn get(&self, row_index: usize, col_index: usize) -> Option<&T> {
+        match self.get_entry(row_index, col_index)? {
+            SparseEntry::NonZero(value) => Some(value),
+            SparseEntry::Zero => None,
+        }
+    }
+
+    /// Returns a mutable reference to the explicitly stored value at the given row/col indices.
+    ///
+    /// Returns `None` if the indices are out of bounds, or if the entry is not explicitly
+    /// stored, i.e. if it is a structural zero.
+    ///
+    /// Each call to this function incurs the cost of a binary search among the explicitly
+    /// stored column entries for the given row, i.e. `O(log k)` where `k` is the number of
+    /// explicitly stored entries in the row.
+    pub fn get_mut(&mut self, row_index: usize, col_index: usize) -> Option<&mut T> {
+        match self.get_entry_mut(row_index, col_index)? {
+            SparseEntryMut::NonZero(value) => Some(value),
+            SparseEntryMut::Zero => None,
+        }
+    }
+
     /// Returns a triplet of slices `(row_offsets, col_indices, values)` that make up the CSR data.
     #[must_use]
     pub fn csr_data(&self) -> (&[usize], &[usize], &[T]) {
@@ -466,6 +850,102 @@ impl<T> CsrMatrix<T> {
         self.cs.cs_data_mut()
     }
 
+    /// Computes `y = alpha * self * x + beta * y` in place, without allocating.
+    ///
+    /// This follows the semantics of the BLAS `gemv` routine and of the dense
+    /// [`Matrix::gemv`](nalgebra::Matrix::gemv), with the matrix stored in CSR format. Each entry of
+    /// `y` is computed from a single row of the matrix, which makes it well-suited for iterative
+    /// solvers that reuse the same output vector across iterations.
+    ///
+    /// If `beta` is zero, `y` is overwritten and its initial values are never read, so they may
+    /// be uninitialized garbage such as `NaN`. Otherwise, `y` is scaled before the product is
+    /// accumulated.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the length of `x` is not equal to the number of columns of the matrix, or if the
+    /// length of `y` is not equal to its number of rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::DVector;
+    /// # use nalgebra_sparse::{coo::CooMatrix, CsrMatrix};
+    /// let mut coo = CooMatrix::new(2, 3);
+    /// coo.push(0, 0, 1.0);
+    /// coo.push(0, 2, 2.0);
+    /// coo.push(1, 1, 3.0);
+    /// let matrix = CsrMatrix::from(&coo);
+    ///
+    /// let x = DVector::from_vec(vec![1.0, 2.0, 3.0]);
+    /// let mut y = DVector::from_vec(vec![10.0, 20.0]);
+    /// matrix.gemv(2.0, &x, 0.5, &mut y);
+    /// assert_eq!(y, DVector::from_vec(vec![2.0 * 7.0 + 5.0, 2.0 * 6.0 + 10.0]));
+    ///
+    /// // With `beta == 0`, the initial content of `y` is ignored.
+    /// let mut y = DVector::from_vec(vec![f64::NAN, f64::NAN]);
+    /// matrix.gemv(1.0, &x, 0.0, &mut y);
+    /// assert_eq!(y, DVector::from_vec(vec![7.0, 6.0]));
+    /// ```
+    pub fn gemv(&self, alpha: T, x: &DVector<T>, beta: T, y: &mut DVector<T>)
+    where
+        T: Scalar + ClosedAdd + ClosedMul + Zero,
+    {
+        assert_eq!(
+            self.ncols(),
+            x.len(),
+            "The length of x must be equal to the number of columns of the matrix."
+        );
+        assert_eq!(
+            self.nrows(),
+            y.len(),
+            "The length of y must be equal to the number of rows of the matrix."
+        );
+
+        let overwrite = beta.is_zero();
+
+        for (y_i, row) in y.iter_mut().zip(self.row_iter()) {
+            let mut dot = T::zero();
+            for (&j, v_ij) in row.col_indices().iter().zip(row.values()) {
+                dot += v_ij.inlined_clone() * x[j].inlined_clone();
+            }
+
+            if overwrite {
+                *y_i = alpha.inlined_clone() * dot;
+            } else {
+                *y_i = beta.inlined_clone() * y_i.inlined_clone() + alpha.inlined_clone() * dot;
+            }
+        }
+    }
+
+    /// Returns a new matrix with the same sparsity pattern, whose explicitly stored entries are
+    /// obtained by applying `f` to the explicitly stored entries of this matrix.
+    ///
+    /// Structural zeros are not visited by `f`, and remain zero in the result. This is the sparse
+    /// counterpart of [`Matrix::map`](nalgebra::Matrix::map).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra_sparse::{coo::CooMatrix, CsrMatrix};
+    /// let mut coo = CooMatrix::new(2, 3);
+    /// coo.push(0, 1, -1.5f32);
+    /// coo.push(1, 2, 2.0f32);
+    /// let matrix = CsrMatrix::from(&coo);
+    ///
+    /// let abs = matrix.map(|x| f64::from(x.abs()));
+    /// assert_eq!(abs.pattern(), matrix.pattern());
+    /// assert_eq!(abs.values(), &[1.5f64, 2.0]);
+    /// ```
+    #[must_use]
+    pub fn map<T2, F>(&self, f: F) -> CsrMatrix<T2>
+    where
+        T: Clone,
+        F: FnMut(T) -> T2,
+    {
+        CsrMatrix { cs: self.cs.map(f) }
+    }
+
     /// Creates a sparse matrix that contains only the explicit entries decided by the
     /// given predicate.
     #[must_use]
@@ -481,6 +961,66 @@ impl<T> CsrMatrix<T> {
         }
     }
 
+    /// Keeps only the explicit entries decided by the given predicate, dropping all the others.
+    ///
+    /// Unlike [`filter`](Self::filter), this works in place: the column indices and values of the
+    /// retained entries are compacted at the front of the existing storage, and remain sorted.
+    /// Note that the capacity of the underlying buffers is not shrunk. If `predicate` panics, the
+    /// matrix is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra_sparse::{coo::CooMatrix, CsrMatrix};
+    /// let mut coo = CooMatrix::new(2, 3);
+    /// coo.push(0, 0, 1);
+    /// coo.push(0, 2, 2);
+    /// coo.push(1, 1, 3);
+    /// coo.push(1, 2, 4);
+    /// let mut matrix = CsrMatrix::from(&coo);
+    ///
+    /// // Keep the upper-triangular entries with an even value.
+    /// matrix.retain(|i, j, v| i <= j && v % 2 == 0);
+    /// assert_eq!(matrix.row_offsets(), &[0, 1, 2]);
+    /// assert_eq!(matrix.col_indices(), &[2, 2]);
+    /// assert_eq!(matrix.values(), &[2, 4]);
+    /// ```
+    pub fn retain<P>(&mut self, predicate: P)
+    where
+        P: Fn(usize, usize, &T) -> bool,
+    {
+        self.cs.retain(predicate)
+    }
+
+    /// Drops, in place, the explicit entries with a modulus smaller than or equal to `threshold`.
+    ///
+    /// This is typically used to remove the near-zero entries left behind by cancellations
+    /// during assembly. Entries such as `NaN` are dropped as well. See [`retain`](Self::retain)
+    /// for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra_sparse::{coo::CooMatrix, CsrMatrix};
+    /// let mut coo = CooMatrix::new(2, 2);
+    /// coo.push(0, 0, 1.0);
+    /// coo.push(0, 1, 1.0e-14);
+    /// coo.push(1, 0, 0.0);
+    /// coo.push(1, 1, -2.0);
+    /// let mut matrix = CsrMatrix::from(&coo);
+    ///
+    /// matrix.prune(1.0e-12);
+    /// assert_eq!(matrix.nnz(), 2);
+    /// assert_eq!(matrix.col_indices(), &[0, 1]);
+    /// assert_eq!(matrix.values(), &[1.0, -2.0]);
+    /// ```
+    pub fn prune(&mut self, threshold: T::RealField)
+    where
+        T: ComplexField,
+    {
+        self.retain(|_, _, v| v.modulus() > threshold)
+    }
+
     /// Returns a new matrix representing the upper triangular part of this matrix.
     ///
     /// The result includes the diagonal of the matrix.
@@ -503,6 +1043,30 @@ impl<T> CsrMatrix<T> {
         self.filter(|i, j, _| i >= j)
     }
 
+    /// Returns a new matrix representing the strictly upper triangular part of this matrix.
+    ///
+    /// The result excludes the diagonal of the matrix, so that the matrix is the sum of
+    /// [`strictly_lower_triangle`](Self::strictly_lower_triangle),
+    /// [`diagonal_as_csr`](Self::diagonal_as_csr) and this.
+    #[must_use]
+    pub fn strictly_upper_triangle(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.filter(|i, j, _| i < j)
+    }
+
+    /// Returns a new matrix representing the strictly lower triangular part of this matrix.
+    ///
+    /// The result excludes the diagonal of the matrix.
+    #[must_use]
+    pub fn strictly_lower_triangle(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.filter(|i, j, _| i > j)
+    }
+
     /// Returns the diagonal of the matrix as a sparse matrix.
     #[must_use]
     pub fn diagonal_as_csr(&self) -> Self
@@ -514,6 +1078,65 @@ impl<T> CsrMatrix<T> {
         }
     }
 
+    /// Extracts the submatrix made of the rows in `rows` and the columns in `cols`.
+    ///
+    /// The explicitly stored entries of the submatrix are those of `self` that lie within the
+    /// given ranges, with their row and column indices shifted by `rows.start` and `cols.start`
+    /// respectively.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if one of the ranges is decreasing or out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra_sparse::CsrMatrix;
+    /// let matrix = CsrMatrix::try_from_csr_data(3, 4, vec![0, 2, 3, 5], vec![0, 3, 1, 0, 2],
+    ///                                           vec![1, 2, 3, 4, 5]).unwrap();
+    /// let sub = matrix.submatrix(1..3, 1..4);
+    /// assert_eq!((sub.nrows(), sub.ncols()), (2, 3));
+    /// assert_eq!(sub.row_offsets(), &[0, 1, 2]);
+    /// assert_eq!(sub.col_indices(), &[0, 1]);
+    /// assert_eq!(sub.values(), &[3, 5]);
+    /// ```
+    #[must_use]
+    pub fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> Self
+    where
+        T: Clone,
+    {
+        assert!(
+            rows.start <= rows.end && rows.end <= self.nrows(),
+            "Row range out of bounds."
+        );
+        assert!(
+            cols.start <= cols.end && cols.end <= self.ncols(),
+            "Column range out of bounds."
+        );
+
+        let mut row_offsets = Vec::with_capacity(rows.len() + 1);
+        let mut col_indices = Vec::new();
+        let mut values = Vec::new();
+        row_offsets.push(0);
+
+        for i in rows.clone() {
+            let row = self.row(i);
+            let row_cols = row.col_indices();
+            // The column indices are sorted, so the selected entries are contiguous.
+            let lower_bound = |j| match row_cols.binary_search(&j) {
+                Ok(k) | Err(k) => k,
+            };
+            let range = lower_bound(cols.start)..lower_bound(cols.end);
+
+            col_indices.extend(row_cols[range.clone()].iter().map(|j| j - cols.start));
+            values.extend_from_slice(&row.values()[range]);
+            row_offsets.push(col_indices.len());
+        }
+
+        Self::try_from_csr_data(rows.len(), cols.len(), row_offsets, col_indices, values)
+            .expect("Internal error: submatrix must be a valid CSR matrix.")
+    }
+
     /// Compute the transpose of the matrix.
     #[must_use]
     pub fn transpose(&self) -> CsrMatrix<T>
@@ -522,6 +1145,279 @@ impl<T> CsrMatrix<T> {
     {
         CscMatrix::from(self).transpose_as_csr()
     }
+
+    /// Computes the symmetric completion of the matrix.
+    ///
+    /// The sparsity pattern of the result is the union of the pattern of the matrix and its
+    /// transpose (see [`SparsityPattern::symmetrize`]). Entries explicitly stored in `self` keep
+    /// their value, while entries `(i, j)` that are only stored as `(j, i)` are mirrored from
+    /// there. In particular, this turns a matrix of which only the lower (or upper) triangle
+    /// has been assembled into the full symmetric matrix.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the matrix is not square.
+    #[must_use]
+    pub fn symmetrize(&self) -> CsrMatrix<T>
+    where
+        T: Scalar,
+    {
+        let pattern = self.pattern().symmetrize();
+        let transpose = self.transpose();
+        let mut values = Vec::with_capacity(pattern.nnz());
+
+        for (i, (row, row_t)) in self.row_iter().zip(transpose.row_iter()).enumerate() {
+            let (mut cols, mut vals) = (row.col_indices(), row.values());
+            let (mut cols_t, mut vals_t) = (row_t.col_indices(), row_t.values());
+
+            // Every column of the symmetrized row is stored in at least one of the two rows,
+            // and we prefer the value stored in the original matrix.
+            for &j in pattern.lane(i) {
+                let in_row = cols.first() == Some(&j);
+                let in_row_t = cols_t.first() == Some(&j);
+                let value = if in_row { &vals[0] } else { &vals_t[0] };
+                values.push(value.inlined_clone());
+
+                if in_row {
+                    cols = &cols[1..];
+                    vals = &vals[1..];
+                }
+                if in_row_t {
+                    cols_t = &cols_t[1..];
+                    vals_t = &vals_t[1..];
+                }
+            }
+        }
+
+        Self::try_from_pattern_and_values(pattern, values)
+            .expect("Internal error: Symmetrized pattern and values must be consistent.")
+    }
+
+    /// Applies the permutation `perm` symmetrically to the rows and columns of the matrix.
+    ///
+    /// The permutation lists the old indices in their new order, which is how fill-reducing
+    /// orderings such as reverse Cuthill-McKee or AMD are usually returned: the entry `(i, j)` of
+    /// the result is the entry `(perm[i], perm[j])` of `self`. In other words, this computes
+    /// `P * A * P^T` where `P` is the permutation matrix whose `i`-th row is the `perm[i]`-th
+    /// row of the identity. The column indices of each row of the result are sorted.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the matrix is not square, or if `perm` is not a permutation of
+    /// `0 .. self.nrows()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra_sparse::CsrMatrix;
+    /// // [ 1 2 0 ]
+    /// // [ 0 3 0 ]
+    /// // [ 4 0 5 ]
+    /// let matrix = CsrMatrix::try_from_csr_data(3, 3, vec![0, 2, 3, 5], vec![0, 1, 1, 0, 2],
+    ///                                           vec![1, 2, 3, 4, 5]).unwrap();
+    /// let permuted = matrix.permute_symmetric(&[2, 0, 1]);
+    /// // [ 5 4 0 ]
+    /// // [ 0 1 2 ]
+    /// // [ 0 0 3 ]
+    /// assert_eq!(permuted.row_offsets(), &[0, 2, 4, 5]);
+    /// assert_eq!(permuted.col_indices(), &[0, 1, 1, 2, 2]);
+    /// assert_eq!(permuted.values(), &[5, 4, 1, 2, 3]);
+    /// ```
+    #[must_use]
+    pub fn permute_symmetric(&self, perm: &[usize]) -> CsrMatrix<T>
+    where
+        T: Clone,
+    {
+        let n = self.nrows();
+        assert_eq!(n, self.ncols(), "Matrix must be square.");
+        assert_eq!(
+            perm.len(),
+            n,
+            "The permutation must have as many elements as the matrix has rows."
+        );
+
+        // inv_perm[old] = new
+        let mut inv_perm = vec![usize::MAX; n];
+        for (new, &old) in perm.iter().enumerate() {
+            assert!(
+                old < n && inv_perm[old] == usize::MAX,
+                "The permutation must contain each index of 0..n exactly once."
+            );
+            inv_perm[old] = new;
+        }
+
+        let mut row_offsets = Vec::with_capacity(n + 1);
+        let mut col_indices = Vec::with_capacity(self.nnz());
+        let mut values = Vec::with_capacity(self.nnz());
+        let mut row_order = Vec::new();
+        row_offsets.push(0);
+
+        for &old_row in perm {
+            let row = self.row(old_row);
+            // Pairs of (new column index, position in the original row)
+            row_order.clear();
+            row_order.extend(
+                row.col_indices()
+                    .iter()
+                    .enumerate()
+                    .map(|(k, &j)| (inv_perm[j], k)),
+            );
+            row_order.sort_unstable();
+
+            for &(j, k) in &row_order {
+                col_indices.push(j);
+                values.push(row.values()[k].clone());
+            }
+            row_offsets.push(col_indices.len());
+        }
+
+        Self::try_from_csr_data(n, n, row_offsets, col_indices, values)
+            .expect("Internal error: Permuted matrix must be a valid CSR matrix.")
+    }
+}
+
+impl<T> AbsDiffEq for CsrMatrix<T>
+where
+    T: AbsDiffEq + Zero,
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    /// Compares the entries of both matrices in the union of their sparsity patterns, treating
+    /// entries that are not explicitly stored as exact zeros.
+    ///
+    /// Matrices of different dimensions are never equal.
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.cs
+            .entries_all_match(&other.cs, |a, b| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl<T> RelativeEq for CsrMatrix<T>
+where
+    T: RelativeEq + Zero,
+    T::Epsilon: Copy,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    /// Compares the entries of both matrices in the union of their sparsity patterns, treating
+    /// entries that are not explicitly stored as exact zeros.
+    ///
+    /// Unlike `==`, which also compares the sparsity patterns, this considers an explicitly
+    /// stored zero to be equal to an entry that is not stored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra_sparse::coo::CooMatrix;
+    /// # use nalgebra_sparse::csr::CsrMatrix;
+    /// let mut coo = CooMatrix::new(2, 2);
+    /// coo.push(0, 0, 1.0);
+    /// let a = CsrMatrix::from(&coo);
+    ///
+    /// coo.push(1, 1, 0.0);
+    /// coo.push(0, 0, 1.0e-12);
+    /// let b = CsrMatrix::from(&coo);
+    ///
+    /// assert_ne!(a, b);
+    /// assert_relative_eq!(a, b, epsilon = 1.0e-10);
+    /// ```
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.cs
+            .entries_all_match(&other.cs, |a, b| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl<T> UlpsEq for CsrMatrix<T>
+where
+    T: UlpsEq + Zero,
+    T::Epsilon: Copy,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    /// Compares the entries of both matrices in the union of their sparsity patterns, treating
+    /// entries that are not explicitly stored as exact zeros.
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.cs
+            .entries_all_match(&other.cs, |a, b| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+/// The minimum number of explicitly stored entries processed by a single task in
+/// [`CsrMatrix::par_mul`].
+#[cfg(feature = "parallel")]
+const PAR_MUL_MIN_NNZ_PER_TASK: usize = 4096;
+
+#[cfg(feature = "parallel")]
+impl<T> CsrMatrix<T> {
+    /// Computes the matrix-vector product `self * x` in parallel.
+    ///
+    /// Each entry of the result only depends on a single row of the matrix, so the rows are
+    /// distributed among the threads of the current `rayon` thread pool. Every row is accumulated
+    /// sequentially, so the result is deterministic and does not depend on the number of threads.
+    ///
+    /// The rows are split into tasks containing at least a few thousand explicitly stored
+    /// entries on average, so that small matrices are processed by a single thread and the
+    /// threading overhead stays negligible. This requires the `parallel` feature.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the length of `x` is not equal to the number of columns of the matrix.
+    #[must_use]
+    pub fn par_mul(&self, x: &DVector<T>) -> DVector<T>
+    where
+        T: Scalar + ClosedAdd + ClosedMul + Zero + Send + Sync,
+    {
+        assert_eq!(
+            self.ncols(),
+            x.len(),
+            "The length of x must be equal to the number of columns of the matrix."
+        );
+
+        let (row_offsets, col_indices, values) = self.csr_data();
+        let min_rows_per_task =
+            (self.nrows() / (self.nnz() / PAR_MUL_MIN_NNZ_PER_TASK).max(1)).max(1);
+        let mut result = DVector::zeros(self.nrows());
+
+        result
+            .as_mut_slice()
+            .par_iter_mut()
+            .with_min_len(min_rows_per_task)
+            .enumerate()
+            .for_each(|(i, y_i)| {
+                let row_range = row_offsets[i]..row_offsets[i + 1];
+                let row_cols = &col_indices[row_range.clone()];
+                let row_values = &values[row_range];
+
+                let mut dot = T::zero();
+                for (&j, v_ij) in row_cols.iter().zip(row_values) {
+                    dot += v_ij.inlined_clone() * x[j].inlined_clone();
+                }
+                *y_i = dot;
+            });
+
+        result
+    }
 }
 
 /// Convert pattern format errors into more meaningful CSR-specific errors.
@@ -730,3 +1626,26 @@ where
         self.lane_iter.next().map(|lane| CsrRowMut { lane })
     }
 }
+
+/// Summary statistics of the sparsity pattern of a [`CsrMatrix`].
+///
+/// Created by [`CsrMatrix::sparsity_stats`]. Explicitly stored zeros count as stored entries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparsityStats {
+    /// The number of explicitly stored entries.
+    pub nnz: usize,
+    /// The fraction of explicitly stored entries among all the entries of the matrix, or `0.0`
+    /// if the matrix has no entries.
+    pub density: f64,
+    /// The smallest number of explicitly stored entries in a row, or `0` if there are no rows.
+    pub min_row_nnz: usize,
+    /// The largest number of explicitly stored entries in a row, or `0` if there are no rows.
+    pub max_row_nnz: usize,
+    /// The mean number of explicitly stored entries per row, or `0.0` if there are no rows.
+    pub mean_row_nnz: f64,
+    /// The number of rows without any explicitly stored entry.
+    pub empty_rows: usize,
+    /// Whether every entry of the main diagonal is explicitly stored. For a rectangular matrix,
+    /// the main diagonal has `min(nrows, ncols)` entries.
+    pub full_diagonal: bool,
+}
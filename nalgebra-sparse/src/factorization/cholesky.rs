@@ -1,5 +1,5 @@
 use crate::csc::CscMatrix;
-use crate::ops::serial::spsolve_csc_lower_triangular;
+use crate::ops::serial::{spmm_csc_dense, spsolve_csc_lower_triangular};
 use crate::ops::Op;
 use crate::pattern::SparsityPattern;
 use core::{iter, mem};
@@ -78,11 +78,29 @@ pub struct CscCholesky<T> {
 pub enum CholeskyError {
     /// The matrix is not positive definite.
     NotPositiveDefinite,
+    /// The matrix is structurally singular: the given column does not contain any explicitly
+    /// stored entry.
+    ///
+    /// Only the columns of the matrix are checked up front. Since the matrix is required to be
+    /// symmetric, an empty row implies an empty column with the same index. If the matrix is not
+    /// symmetric, an empty row still leaves the corresponding diagonal entry unstored, which is
+    /// reported as [`CholeskyError::NotPositiveDefinite`] instead.
+    ///
+    /// This is a factorization error rather than a [`SparseFormatError`](crate::SparseFormatError),
+    /// since a matrix with empty columns is still a valid sparse matrix.
+    EmptyColumn(usize),
 }
 
 impl Display for CholeskyError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Matrix is not positive definite")
+        match self {
+            CholeskyError::NotPositiveDefinite => write!(f, "Matrix is not positive definite"),
+            CholeskyError::EmptyColumn(col) => write!(
+                f,
+                "Matrix is structurally singular: column {} is empty",
+                col
+            ),
+        }
     }
 }
 
@@ -98,7 +116,8 @@ impl<T: RealField> CscCholesky<T> {
     /// # Errors
     ///
     /// Returns an error if the numerical factorization fails. This can occur if the matrix is not
-    /// symmetric positive definite.
+    /// symmetric positive definite. In particular, [`CholeskyError::EmptyColumn`] is returned
+    /// without attempting the factorization if the matrix has an empty column.
     ///
     /// # Panics
     ///
@@ -108,6 +127,10 @@ impl<T: RealField> CscCholesky<T> {
         symbolic: CscSymbolicCholesky,
         values: &[T],
     ) -> Result<Self, CholeskyError> {
+        if let Some(&col) = symbolic.m_pattern.find_empty_lanes().first() {
+            return Err(CholeskyError::EmptyColumn(col));
+        }
+
         assert_eq!(
             symbolic.l_pattern.nnz(),
             symbolic.u_pattern.nnz(),
@@ -143,7 +166,8 @@ impl<T: RealField> CscCholesky<T> {
     /// # Errors
     ///
     /// Returns an error if the numerical factorization fails. This can occur if the matrix is not
-    /// symmetric positive definite.
+    /// symmetric positive definite. In particular, [`CholeskyError::EmptyColumn`] is returned
+    /// without attempting the factorization if the matrix has an empty column.
     ///
     /// # Panics
     ///
@@ -288,6 +312,104 @@ impl<T: RealField> CscCholesky<T> {
         let mut x = y;
         spsolve_csc_lower_triangular(Op::Transpose(self.l()), &mut x).expect(expect_msg);
     }
+
+    /// Solves the system `A X = B` and returns the solution along with the norm of the
+    /// residual `A X - B`.
+    ///
+    /// The factorization does not retain the values of `A`, so the factored matrix must be
+    /// provided again in order to compute the residual. The returned norm is the Frobenius
+    /// norm of `A X - B`, which is the Euclidean norm when `B` has a single column. A residual
+    /// that is large relative to the norm of `B` indicates that the solution is unreliable,
+    /// e.g. because `A` is ill-conditioned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `A` or `B` do not have as many rows as the factored matrix.
+    #[must_use]
+    pub fn solve_with_residual<'a>(
+        &'a self,
+        a: &CscMatrix<T>,
+        b: impl Into<DMatrixSlice<'a, T>>,
+    ) -> (DMatrix<T>, T) {
+        let mut residual = b.into().clone_owned();
+        let mut x = residual.clone();
+        self.solve_mut(&mut x);
+
+        // Compute A X - B in-place
+        spmm_csc_dense(
+            -T::one(),
+            &mut residual,
+            T::one(),
+            Op::NoOp(a),
+            Op::NoOp(&x),
+        );
+        let residual_norm = residual.norm();
+
+        (x, residual_norm)
+    }
+
+    /// Computes the dense inverse of the factored matrix.
+    ///
+    /// The inverse of a sparse matrix is generally dense, so this is only advisable for
+    /// matrices of moderate size.
+    #[must_use]
+    pub fn inverse(&self) -> DMatrix<T> {
+        let n = self.l_factor.nrows();
+        self.solve(&DMatrix::identity(n, n))
+    }
+
+    /// Returns `true` if every pivot of the factorization, i.e. the square of every diagonal
+    /// entry of `L`, is greater than `eps`.
+    fn pivots_greater_than(&self, eps: T) -> bool {
+        self.l_factor.col_iter().all(|col| {
+            // The diagonal entry is the first entry of each column of L.
+            let l_jj = col.values()[0];
+            l_jj * l_jj > eps
+        })
+    }
+}
+
+impl<T: RealField> CscMatrix<T> {
+    /// Computes the dense inverse of this symmetric positive definite matrix through its sparse
+    /// Cholesky factorization.
+    ///
+    /// This is useful, for example, to compute a full covariance matrix from a sparse precision
+    /// matrix. Symmetry is not checked. The inverse of a sparse matrix is generally dense, so
+    /// this is only advisable for matrices of moderate size.
+    ///
+    /// Returns `None` if the factorization fails, or if one of its pivots, i.e. the square of
+    /// a diagonal entry of the Cholesky factor, is not greater than `eps`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// # use nalgebra_sparse::coo::CooMatrix;
+    /// # use nalgebra_sparse::csc::CscMatrix;
+    /// let mut coo = CooMatrix::new(2, 2);
+    /// coo.push(0, 0, 4.0);
+    /// coo.push(1, 0, 2.0);
+    /// coo.push(0, 1, 2.0);
+    /// coo.push(1, 1, 2.0);
+    /// let precision = CscMatrix::from(&coo);
+    ///
+    /// let covariance = precision.cholesky_inverse(1.0e-12).unwrap();
+    /// let expected = DMatrix::from_row_slice(2, 2, &[0.5, -0.5, -0.5, 1.0]);
+    /// assert!((covariance - expected).norm() < 1.0e-12);
+    /// ```
+    #[must_use]
+    pub fn cholesky_inverse(&self, eps: T) -> Option<DMatrix<T>> {
+        let cholesky = CscCholesky::factor(self).ok()?;
+        if cholesky.pivots_greater_than(eps) {
+            Some(cholesky.inverse())
+        } else {
+            None
+        }
+    }
 }
 
 fn reach(
@@ -0,0 +1,165 @@
+use crate::csc::CscMatrix;
+use crate::factorization::CholeskyError;
+use crate::ops::serial::spsolve_csc_lower_triangular;
+use crate::ops::Op;
+use nalgebra::{DMatrix, DMatrixSlice, DMatrixSliceMut, RealField};
+use std::cmp::Ordering;
+
+/// Computes the zero fill-in incomplete Cholesky factor `L` of a symmetric positive definite
+/// matrix, such that `A ≈ L L^T`.
+///
+/// This is a shorthand for [`CscIc0::factor`] followed by [`CscIc0::take_l`]. The factor has the
+/// same sparsity pattern as the lower triangle of `A`, and can be used directly with
+/// [`spsolve_csc_lower_triangular`](crate::ops::serial::spsolve_csc_lower_triangular).
+///
+/// Returns `None` if a non-positive pivot is encountered, or if the factorization fails for any
+/// of the other reasons documented on [`CscIc0::factor`].
+///
+/// # Panics
+///
+/// Panics if the matrix is not square.
+pub fn ic0<T: RealField>(a: &CscMatrix<T>) -> Option<CscMatrix<T>> {
+    CscIc0::factor(a).ok().map(CscIc0::take_l)
+}
+
+/// A zero fill-in incomplete Cholesky factorization `A ≈ L L^T` of a [`CscMatrix`], also known
+/// as IC(0).
+///
+/// The factor `L` is computed by performing the Cholesky factorization of `A` while discarding
+/// every entry that lies outside of the sparsity pattern of the lower triangle of `A`. Hence
+/// `L` has exactly the same sparsity pattern as the lower triangle of `A`.
+///
+/// The product `L L^T` is generally only an approximation of `A`, which makes this factorization
+/// mostly useful as a preconditioner for the conjugate gradient method. Applying the
+/// preconditioner to a vector `b` amounts to computing `(L L^T)⁻¹ b` with
+/// [`solve`](Self::solve).
+#[derive(Debug, Clone)]
+pub struct CscIc0<T> {
+    l_factor: CscMatrix<T>,
+}
+
+impl<T: RealField> CscIc0<T> {
+    /// Computes the IC(0) factorization of the provided matrix.
+    ///
+    /// The matrix must be symmetric positive definite. Only its lower triangle is read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a non-positive pivot is encountered. This may happen even for some
+    /// symmetric positive definite matrices, since entries outside of the pattern are dropped.
+    /// It also happens if one of the diagonal entries of the matrix is not explicitly stored.
+    /// If the matrix has an empty column, [`CholeskyError::EmptyColumn`] is returned without
+    /// attempting the factorization.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn factor(matrix: &CscMatrix<T>) -> Result<Self, CholeskyError> {
+        assert_eq!(
+            matrix.nrows(),
+            matrix.ncols(),
+            "Matrix must be square for incomplete Cholesky factorization."
+        );
+
+        if let Some(&col) = matrix.pattern().find_empty_lanes().first() {
+            return Err(CholeskyError::EmptyColumn(col));
+        }
+
+        let (offsets, indices, mut values) = matrix.lower_triangle().disassemble();
+        // Maps the row indices of the column being updated to their offset in `values`
+        let mut work_offsets = vec![usize::MAX; matrix.nrows()];
+
+        for k in 0..matrix.ncols() {
+            let (begin, end) = (offsets[k], offsets[k + 1]);
+
+            // The diagonal is the first entry of each column of the lower triangle. A NaN pivot
+            // is rejected as well.
+            if begin == end
+                || indices[begin] != k
+                || values[begin].partial_cmp(&T::zero()) != Some(Ordering::Greater)
+            {
+                return Err(CholeskyError::NotPositiveDefinite);
+            }
+
+            let l_kk = values[begin].sqrt();
+            values[begin] = l_kk;
+            for l_ik in &mut values[begin + 1..end] {
+                *l_ik /= l_kk;
+            }
+
+            // Update the columns on the right of the current column, ignoring any fill-in
+            // outside of the pattern of these columns.
+            for p in (begin + 1)..end {
+                let j = indices[p];
+                let l_jk = values[p];
+
+                for q in offsets[j]..offsets[j + 1] {
+                    work_offsets[indices[q]] = q;
+                }
+
+                for r in p..end {
+                    let work_offset = work_offsets[indices[r]];
+                    if work_offset != usize::MAX {
+                        let l_ik = values[r];
+                        values[work_offset] -= l_ik * l_jk;
+                    }
+                }
+
+                for q in offsets[j]..offsets[j + 1] {
+                    work_offsets[indices[q]] = usize::MAX;
+                }
+            }
+        }
+
+        let l_factor =
+            CscMatrix::try_from_csc_data(matrix.nrows(), matrix.ncols(), offsets, indices, values)
+                .expect("Internal error: the factor has the same pattern as the lower triangle.");
+
+        Ok(Self { l_factor })
+    }
+
+    /// Returns a reference to the incomplete Cholesky factor `L`.
+    #[must_use]
+    pub fn l(&self) -> &CscMatrix<T> {
+        &self.l_factor
+    }
+
+    /// Returns the incomplete Cholesky factor `L`.
+    pub fn take_l(self) -> CscMatrix<T> {
+        self.l_factor
+    }
+
+    /// Solves the system `L L^T X = B`, where `X` and `B` are dense matrices.
+    ///
+    /// This applies the preconditioner to every column of `B`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `B` does not have as many rows as the factored matrix.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<'a>(&'a self, b: impl Into<DMatrixSlice<'a, T>>) -> DMatrix<T> {
+        let b = b.into();
+        let mut output = b.clone_owned();
+        self.solve_mut(&mut output);
+        output
+    }
+
+    /// Solves the system `L L^T X = B`, where `X` and `B` are dense matrices.
+    ///
+    /// The result is stored in-place in `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` does not have as many rows as the factored matrix.
+    pub fn solve_mut<'a>(&'a self, b: impl Into<DMatrixSliceMut<'a, T>>) {
+        let expect_msg = "If the incomplete Cholesky factorization succeeded,\
+            then the triangular solve should never fail";
+        // Solve LY = B
+        let mut y = b.into();
+        spsolve_csc_lower_triangular(Op::NoOp(self.l()), &mut y).expect(expect_msg);
+
+        // Solve L^T X = Y
+        let mut x = y;
+        spsolve_csc_lower_triangular(Op::Transpose(self.l()), &mut x).expect(expect_msg);
+    }
+}
@@ -0,0 +1,233 @@
+use crate::csr::CsrMatrix;
+use nalgebra::{DMatrix, DMatrixSlice, DMatrixSliceMut, RealField};
+use std::fmt::{Display, Formatter};
+
+/// A zero fill-in incomplete LU factorization `A ≈ L U` of a [`CsrMatrix`], also known as ILU(0).
+///
+/// The factors `L` and `U` are computed by performing Gaussian elimination on `A` while
+/// discarding every entry that lies outside of the sparsity pattern of `A`. Hence, the
+/// combined pattern of `L` and `U` is exactly the pattern of `A`, except for the unit diagonal
+/// of `L` which is stored explicitly. No pivoting is performed.
+///
+/// The product `L U` is generally only an approximation of `A`, which makes this factorization
+/// mostly useful as a preconditioner for iterative solvers. Applying the preconditioner to a
+/// vector `b` amounts to computing `(L U)⁻¹ b` with [`solve`](Self::solve).
+#[derive(Debug, Clone)]
+pub struct CsrIlu0<T> {
+    l_factor: CsrMatrix<T>,
+    u_factor: CsrMatrix<T>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+/// Possible errors produced by the incomplete LU factorization.
+pub enum Ilu0Error {
+    /// A zero pivot was encountered in the given row.
+    ///
+    /// This happens if the diagonal entry of the row is not explicitly stored, or if it becomes
+    /// zero during the elimination.
+    ZeroPivot(usize),
+    /// The matrix is structurally singular: the given row does not contain any explicitly
+    /// stored entry.
+    EmptyRow(usize),
+    /// The matrix is structurally singular: the given column does not contain any explicitly
+    /// stored entry.
+    ///
+    /// Unlike the Cholesky factorizations, the matrix is not assumed to be symmetric, so rows
+    /// and columns are checked separately. Empty rows are reported first.
+    EmptyColumn(usize),
+}
+
+impl Display for Ilu0Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Ilu0Error::ZeroPivot(row) => write!(f, "Zero pivot encountered in row {}", row),
+            Ilu0Error::EmptyRow(row) => {
+                write!(f, "Matrix is structurally singular: row {} is empty", row)
+            }
+            Ilu0Error::EmptyColumn(col) => {
+                write!(
+                    f,
+                    "Matrix is structurally singular: column {} is empty",
+                    col
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Ilu0Error {}
+
+impl<T: RealField> CsrIlu0<T> {
+    /// Computes the ILU(0) factorization of the provided matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a zero pivot is encountered. This occurs in particular if one of the
+    /// diagonal entries of the matrix is not explicitly stored. If the matrix has an empty row or
+    /// column, [`Ilu0Error::EmptyRow`] or [`Ilu0Error::EmptyColumn`] is returned without
+    /// attempting the factorization.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn factor(matrix: &CsrMatrix<T>) -> Result<Self, Ilu0Error> {
+        assert_eq!(
+            matrix.nrows(),
+            matrix.ncols(),
+            "Matrix must be square for incomplete LU factorization."
+        );
+
+        if let Some(&row) = matrix.pattern().find_empty_lanes().first() {
+            return Err(Ilu0Error::EmptyRow(row));
+        }
+        let mut nonempty_cols = vec![false; matrix.ncols()];
+        for &j in matrix.col_indices() {
+            nonempty_cols[j] = true;
+        }
+        if let Some(col) = nonempty_cols.iter().position(|&nonempty| !nonempty) {
+            return Err(Ilu0Error::EmptyColumn(col));
+        }
+
+        let n = matrix.nrows();
+        let offsets = matrix.row_offsets();
+        let indices = matrix.col_indices();
+        let mut values = matrix.values().to_vec();
+
+        // Offsets of the diagonal entries of the rows that have already been factored
+        let mut diag_offsets = Vec::with_capacity(n);
+        // Maps the column indices of the current row to their offset in `values`
+        let mut work_offsets = vec![usize::MAX; n];
+
+        for i in 0..n {
+            let row_range = offsets[i]..offsets[i + 1];
+            for p in row_range.clone() {
+                work_offsets[indices[p]] = p;
+            }
+
+            // Eliminate the entries of the current row on the left of the diagonal, ignoring
+            // any fill-in outside of the pattern of the current row.
+            for p in row_range.clone() {
+                let k = indices[p];
+                if k >= i {
+                    break;
+                }
+
+                let l_ik = values[p] / values[diag_offsets[k]];
+                values[p] = l_ik;
+
+                for q in (diag_offsets[k] + 1)..offsets[k + 1] {
+                    let work_offset = work_offsets[indices[q]];
+                    if work_offset != usize::MAX {
+                        let u_kj = values[q];
+                        values[work_offset] -= l_ik * u_kj;
+                    }
+                }
+            }
+
+            for p in row_range.clone() {
+                work_offsets[indices[p]] = usize::MAX;
+            }
+
+            let row_indices = &indices[row_range];
+            match row_indices.binary_search(&i) {
+                Ok(local_offset) if !values[offsets[i] + local_offset].is_zero() => {
+                    diag_offsets.push(offsets[i] + local_offset);
+                }
+                _ => return Err(Ilu0Error::ZeroPivot(i)),
+            }
+        }
+
+        let factored =
+            CsrMatrix::try_from_csr_data(n, n, offsets.to_vec(), indices.to_vec(), values)
+                .expect("Internal error: the factored matrix has the same pattern as the input.");
+
+        // L is unit lower triangular, so we replace its diagonal by ones.
+        let mut l_factor = factored.filter(|i, j, _| i >= j);
+        for (i, j, v) in l_factor.triplet_iter_mut() {
+            if i == j {
+                *v = T::one();
+            }
+        }
+        let u_factor = factored.upper_triangle();
+
+        Ok(Self { l_factor, u_factor })
+    }
+
+    /// Returns a reference to the unit lower triangular factor `L`.
+    ///
+    /// The unit diagonal is stored explicitly.
+    #[must_use]
+    pub fn l(&self) -> &CsrMatrix<T> {
+        &self.l_factor
+    }
+
+    /// Returns a reference to the upper triangular factor `U`.
+    #[must_use]
+    pub fn u(&self) -> &CsrMatrix<T> {
+        &self.u_factor
+    }
+
+    /// Returns the factors `L` and `U`.
+    pub fn take_l_and_u(self) -> (CsrMatrix<T>, CsrMatrix<T>) {
+        (self.l_factor, self.u_factor)
+    }
+
+    /// Solves the system `L U X = B`, where `X` and `B` are dense matrices.
+    ///
+    /// This applies the preconditioner to every column of `B`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `B` does not have as many rows as the factored matrix.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<'a>(&'a self, b: impl Into<DMatrixSlice<'a, T>>) -> DMatrix<T> {
+        let b = b.into();
+        let mut output = b.clone_owned();
+        self.solve_mut(&mut output);
+        output
+    }
+
+    /// Solves the system `L U X = B`, where `X` and `B` are dense matrices.
+    ///
+    /// The result is stored in-place in `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` does not have as many rows as the factored matrix.
+    pub fn solve_mut<'a>(&'a self, b: impl Into<DMatrixSliceMut<'a, T>>) {
+        let mut x = b.into();
+        assert_eq!(
+            x.nrows(),
+            self.l_factor.nrows(),
+            "Dimension mismatch in incomplete LU solve."
+        );
+
+        for j in 0..x.ncols() {
+            let mut x_col_j = x.column_mut(j);
+
+            // Solve L Y = B by forward substitution. The diagonal of L is one.
+            for (i, row) in self.l_factor.row_iter().enumerate() {
+                let mut x_ij = x_col_j[i];
+                for (&k, l_ik) in row.col_indices().iter().zip(row.values()) {
+                    if k < i {
+                        x_ij -= *l_ik * x_col_j[k];
+                    }
+                }
+                x_col_j[i] = x_ij;
+            }
+
+            // Solve U X = Y by backward substitution. The diagonal of U is the first entry of
+            // each row, and is non-zero by construction.
+            for i in (0..x_col_j.nrows()).rev() {
+                let row = self.u_factor.row(i);
+                let (cols, values) = (row.col_indices(), row.values());
+                let mut x_ij = x_col_j[i];
+                for (&k, u_ik) in cols.iter().zip(values).skip(1) {
+                    x_ij -= *u_ik * x_col_j[k];
+                }
+                x_col_j[i] = x_ij / values[0];
+            }
+        }
+    }
+}
@@ -1,6 +1,11 @@
 //! Matrix factorization for sparse matrices.
 //!
-//! Currently, the only factorization provided here is the [`CscCholesky`] factorization.
+//! Currently, the factorizations provided here are the [`CscCholesky`] factorization, the
+//! [`CscIc0`] incomplete Cholesky factorization and the [`CsrIlu0`] incomplete LU factorization.
 mod cholesky;
+mod ic;
+mod ilu;
 
 pub use cholesky::*;
+pub use ic::*;
+pub use ilu::*;
@@ -23,6 +23,9 @@
 //!   `proptest-support` is enabled.
 //! - [matrixcompare support](https://crates.io/crates/matrixcompare) for effortless
 //!   (approximate) comparison of matrices in test code (requires the `compare` feature).
+//! - The [reverse Cuthill-McKee](ordering::reverse_cuthill_mckee) bandwidth-reducing ordering.
+//! - Parallel [CSR matrix-vector products](csr::CsrMatrix::par_mul) powered by
+//!   [rayon](https://crates.io/crates/rayon) (requires the `parallel` feature).
 //!
 //! ## Current state
 //!
@@ -145,6 +148,7 @@ pub mod csc;
 pub mod csr;
 pub mod factorization;
 pub mod ops;
+pub mod ordering;
 pub mod pattern;
 
 pub(crate) mod cs;
@@ -164,11 +168,15 @@ pub use self::csc::CscMatrix;
 pub use self::csr::CsrMatrix;
 
 /// Errors produced by functions that expect well-formed sparse format data.
+///
+/// The error implements [`std::error::Error`] and is `Send + Sync`, so it can be propagated
+/// with `?` into a `Box<dyn Error + Send + Sync>`. Its `Display` implementation states the
+/// [kind](SparseFormatErrorKind) of the error, followed by a more detailed message.
 #[derive(Debug)]
 pub struct SparseFormatError {
     kind: SparseFormatErrorKind,
     // Currently we only use an underlying error for generating the `Display` impl
-    error: Box<dyn Error>,
+    error: Box<dyn Error + Send + Sync>,
 }
 
 impl SparseFormatError {
@@ -178,13 +186,16 @@ impl SparseFormatError {
         &self.kind
     }
 
-    pub(crate) fn from_kind_and_error(kind: SparseFormatErrorKind, error: Box<dyn Error>) -> Self {
+    pub(crate) fn from_kind_and_error(
+        kind: SparseFormatErrorKind,
+        error: Box<dyn Error + Send + Sync>,
+    ) -> Self {
         Self { kind, error }
     }
 
     /// Helper functionality for more conveniently creating errors.
     pub(crate) fn from_kind_and_msg(kind: SparseFormatErrorKind, msg: &'static str) -> Self {
-        Self::from_kind_and_error(kind, Box::<dyn Error>::from(msg))
+        Self::from_kind_and_error(kind, Box::<dyn Error + Send + Sync>::from(msg))
     }
 }
 
@@ -207,9 +218,19 @@ pub enum SparseFormatErrorKind {
     InvalidStructure,
 }
 
+impl fmt::Display for SparseFormatErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SparseFormatErrorKind::IndexOutOfBounds => write!(f, "Index out of bounds"),
+            SparseFormatErrorKind::DuplicateEntry => write!(f, "Duplicate entry"),
+            SparseFormatErrorKind::InvalidStructure => write!(f, "Invalid structure"),
+        }
+    }
+}
+
 impl fmt::Display for SparseFormatError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.error)
+        write!(f, "{}: {}", self.kind, self.error)
     }
 }
 
@@ -0,0 +1,154 @@
+//! Fill-reducing and bandwidth-reducing orderings of sparse matrices.
+//!
+//! The orderings in this module operate purely on the [`SparsityPattern`] of a matrix. They
+//! return a permutation vector `perm` such that the `i`-th row (and column) of the reordered
+//! matrix is the `perm[i]`-th row (and column) of the original matrix. This is the convention
+//! used by [`Matrix::permute_rows`](nalgebra::Matrix::permute_rows) and
+//! [`Matrix::permute_columns`](nalgebra::Matrix::permute_columns).
+use crate::pattern::SparsityPattern;
+use std::collections::VecDeque;
+
+/// Computes the reverse Cuthill-McKee ordering of the given square sparsity pattern.
+///
+/// The pattern is interpreted as the adjacency structure of an undirected graph, in which there
+/// is an edge between `i` and `j` whenever `(i, j)` or `(j, i)` is explicitly stored. Hence
+/// non-symmetric patterns are treated as the pattern of `A + A^T`. Diagonal entries are ignored.
+///
+/// Each connected component of the graph is traversed breadth-first from a pseudo-peripheral
+/// node, visiting the neighbors of each node in order of increasing degree. Reversing the
+/// resulting order gives the returned permutation, which typically reduces the bandwidth and
+/// profile of the matrix when applied symmetrically to both its rows and columns.
+///
+/// The `i`-th row and column of the reordered matrix are the `perm[i]`-th row and column
+/// of the original matrix.
+///
+/// Panics
+/// ------
+///
+/// Panics if the pattern is not square.
+#[must_use]
+pub fn reverse_cuthill_mckee(pattern: &SparsityPattern) -> Vec<usize> {
+    assert_eq!(
+        pattern.major_dim(),
+        pattern.minor_dim(),
+        "Reverse Cuthill-McKee ordering requires a square pattern."
+    );
+
+    let graph = pattern.symmetrize();
+    let n = graph.major_dim();
+    let neighbors = |i: usize| graph.lane(i).iter().copied().filter(move |&j| j != i);
+    let degrees: Vec<usize> = (0..n).map(|i| neighbors(i).count()).collect();
+
+    let mut perm = Vec::with_capacity(n);
+    let mut visited = vec![false; n];
+    let mut level_structure = LevelStructure::new(n);
+    let mut sorted_neighbors = Vec::new();
+
+    for component_node in 0..n {
+        if visited[component_node] {
+            continue;
+        }
+
+        let root = level_structure.find_pseudo_peripheral_node(&graph, &degrees, component_node);
+
+        // Cuthill-McKee breadth-first traversal of the component
+        let component_begin = perm.len();
+        visited[root] = true;
+        perm.push(root);
+        let mut next = component_begin;
+        while next < perm.len() {
+            let i = perm[next];
+            next += 1;
+
+            sorted_neighbors.clear();
+            sorted_neighbors.extend(neighbors(i).filter(|&j| !visited[j]));
+            sorted_neighbors.sort_by_key(|&j| degrees[j]);
+            for &j in &sorted_neighbors {
+                visited[j] = true;
+                perm.push(j);
+            }
+        }
+    }
+
+    perm.reverse();
+    perm
+}
+
+/// Work buffers for computing rooted level structures, i.e. the levels of a breadth-first
+/// traversal of a graph.
+struct LevelStructure {
+    levels: Vec<usize>,
+    queue: VecDeque<usize>,
+    nodes: Vec<usize>,
+}
+
+impl LevelStructure {
+    fn new(n: usize) -> Self {
+        Self {
+            levels: vec![usize::MAX; n],
+            queue: VecDeque::new(),
+            nodes: Vec::new(),
+        }
+    }
+
+    /// Computes the level structure rooted at `root` and returns its depth.
+    ///
+    /// The nodes of the connected component of `root` are stored in `self.nodes` in order of
+    /// increasing level.
+    fn compute(&mut self, graph: &SparsityPattern, root: usize) -> usize {
+        for &i in &self.nodes {
+            self.levels[i] = usize::MAX;
+        }
+        self.nodes.clear();
+
+        self.levels[root] = 0;
+        self.queue.push_back(root);
+        while let Some(i) = self.queue.pop_front() {
+            self.nodes.push(i);
+            for &j in graph.lane(i) {
+                if self.levels[j] == usize::MAX {
+                    self.levels[j] = self.levels[i] + 1;
+                    self.queue.push_back(j);
+                }
+            }
+        }
+
+        let last = *self
+            .nodes
+            .last()
+            .expect("The root is always part of its component.");
+        self.levels[last]
+    }
+
+    /// Finds a pseudo-peripheral node in the connected component of `start`, i.e. a node whose
+    /// eccentricity is close to the diameter of the component, using the algorithm of
+    /// George and Liu.
+    fn find_pseudo_peripheral_node(
+        &mut self,
+        graph: &SparsityPattern,
+        degrees: &[usize],
+        start: usize,
+    ) -> usize {
+        let mut root = start;
+        let mut depth = self.compute(graph, root);
+
+        loop {
+            // Pick the node of minimum degree in the last level.
+            let candidate = self
+                .nodes
+                .iter()
+                .copied()
+                .filter(|&i| self.levels[i] == depth)
+                .min_by_key(|&i| degrees[i])
+                .expect("The last level is never empty.");
+
+            let candidate_depth = self.compute(graph, candidate);
+            if candidate_depth > depth {
+                root = candidate;
+                depth = candidate_depth;
+            } else {
+                return root;
+            }
+        }
+    }
+}
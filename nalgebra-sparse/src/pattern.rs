@@ -1,6 +1,8 @@
 //! Sparsity patterns for CSR and CSC matrices.
-use crate::cs::transpose_cs;
+use crate::cs::convert_counts_to_offsets;
+use crate::ops::serial::spadd_pattern;
 use crate::SparseFormatError;
+use std::cmp::Ordering;
 use std::error::Error;
 use std::fmt;
 
@@ -184,6 +186,33 @@ impl SparsityPattern {
         })
     }
 
+    /// Constructs a sparsity pattern from the given dimensions, major offsets and minor indices
+    /// without checking that the data conforms to the requirements.
+    ///
+    /// The caller must guarantee that the data would be accepted by
+    /// [`try_from_offsets_and_indices`](Self::try_from_offsets_and_indices). This is only
+    /// verified in debug builds.
+    pub(crate) fn from_offsets_and_indices_unchecked(
+        major_dim: usize,
+        minor_dim: usize,
+        major_offsets: Vec<usize>,
+        minor_indices: Vec<usize>,
+    ) -> Self {
+        debug_assert!(Self::try_from_offsets_and_indices(
+            major_dim,
+            minor_dim,
+            major_offsets.clone(),
+            minor_indices.clone()
+        )
+        .is_ok());
+
+        Self {
+            major_offsets,
+            minor_indices,
+            minor_dim,
+        }
+    }
+
     /// An iterator over the explicitly stored "non-zero" entries (i, j).
     ///
     /// The iteration happens in a lane-major fashion, meaning that the lane index i
@@ -232,29 +261,179 @@ impl SparsityPattern {
         (self.major_offsets, self.minor_indices)
     }
 
+    /// Returns the indices of the major lanes that do not contain any explicitly stored entry.
+    ///
+    /// For the sparsity pattern of a CSR matrix these are the empty rows, and for the sparsity
+    /// pattern of a CSC matrix the empty columns. A square matrix with an empty lane is
+    /// structurally singular. The lanes are found in `O(major_dim)` time from the offsets.
+    #[must_use]
+    pub fn find_empty_lanes(&self) -> Vec<usize> {
+        self.major_offsets
+            .windows(2)
+            .enumerate()
+            .filter(|(_, offsets)| offsets[0] == offsets[1])
+            .map(|(major_idx, _)| major_idx)
+            .collect()
+    }
+
     /// Computes the transpose of the sparsity pattern.
     ///
     /// This is analogous to matrix transposition, i.e. an entry `(i, j)` becomes `(j, i)` in the
     /// new pattern.
+    ///
+    /// The transpose is computed by a counting sort over the minor indices in `O(nnz + major_dim
+    /// + minor_dim)` time. Since the source lanes are visited in order, the minor indices of each
+    /// lane in the resulting pattern are sorted by construction.
     #[must_use]
     pub fn transpose(&self) -> Self {
-        // By using unit () values, we can use the same routines as for CSR/CSC matrices
-        let values = vec![(); self.nnz()];
-        let (new_offsets, new_indices, _) = transpose_cs(
+        // Count the number of entries in each minor lane, which become the major lanes of the
+        // transposed pattern
+        let mut counts = vec![0; self.minor_dim()];
+        for &minor_idx in self.minor_indices() {
+            counts[minor_idx] += 1;
+        }
+        convert_counts_to_offsets(&mut counts);
+        let mut new_offsets = counts;
+        new_offsets.push(self.nnz());
+
+        // Scatter the major indices into their target lanes, keeping track of how many entries
+        // have already been placed in each lane
+        let mut new_indices = vec![0; self.nnz()];
+        let mut next_in_lane = new_offsets[..self.minor_dim()].to_vec();
+        for major_idx in 0..self.major_dim() {
+            for &minor_idx in self.lane(major_idx) {
+                new_indices[next_in_lane[minor_idx]] = major_idx;
+                next_in_lane[minor_idx] += 1;
+            }
+        }
+
+        // The offsets are monotone by construction, and each new lane is filled in increasing
+        // order of the source major index, so the format invariants hold without validation.
+        Self {
+            major_offsets: new_offsets,
+            minor_indices: new_indices,
+            minor_dim: self.major_dim(),
+        }
+    }
+
+    /// Computes the symmetric completion of the sparsity pattern.
+    ///
+    /// The result is the union of the pattern and its transpose, i.e. it contains the entry
+    /// `(j, i)` whenever the pattern contains `(i, j)`. Diagonal entries appear only once.
+    ///
+    /// Panics
+    /// ------
+    ///
+    /// Panics if the pattern is not square.
+    #[must_use]
+    pub fn symmetrize(&self) -> Self {
+        assert_eq!(
             self.major_dim(),
             self.minor_dim(),
-            self.major_offsets(),
-            self.minor_indices(),
-            &values,
+            "Only square patterns can be symmetrized."
         );
-        // TODO: Skip checks
-        Self::try_from_offsets_and_indices(
-            self.minor_dim(),
+        spadd_pattern(self, &self.transpose())
+    }
+
+    /// Computes the union of two sparsity patterns.
+    ///
+    /// The result contains every entry that is explicitly stored in at least one of the patterns.
+    ///
+    /// Panics
+    /// ------
+    ///
+    /// Panics if the patterns do not have the same major and minor dimensions.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        spadd_pattern(self, other)
+    }
+
+    /// Computes the intersection of two sparsity patterns.
+    ///
+    /// The result contains every entry that is explicitly stored in both patterns.
+    ///
+    /// Panics
+    /// ------
+    ///
+    /// Panics if the patterns do not have the same major and minor dimensions.
+    #[must_use]
+    pub fn intersect(&self, other: &Self) -> Self {
+        self.merge_lanes(other, |lane_a, lane_b, indices| {
+            let (mut a, mut b) = (0, 0);
+            while a < lane_a.len() && b < lane_b.len() {
+                match lane_a[a].cmp(&lane_b[b]) {
+                    Ordering::Less => a += 1,
+                    Ordering::Greater => b += 1,
+                    Ordering::Equal => {
+                        indices.push(lane_a[a]);
+                        a += 1;
+                        b += 1;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Computes the difference of two sparsity patterns.
+    ///
+    /// The result contains every entry that is explicitly stored in `self` but not in `other`.
+    /// For example, the additional fill-in of a factorization is the difference between the
+    /// pattern of the factor and the pattern of the factored matrix.
+    ///
+    /// Panics
+    /// ------
+    ///
+    /// Panics if the patterns do not have the same major and minor dimensions.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        self.merge_lanes(other, |lane_a, lane_b, indices| {
+            let mut b = 0;
+            for &minor_idx in lane_a {
+                while b < lane_b.len() && lane_b[b] < minor_idx {
+                    b += 1;
+                }
+                if lane_b.get(b) != Some(&minor_idx) {
+                    indices.push(minor_idx);
+                }
+            }
+        })
+    }
+
+    /// Builds a new pattern lane by lane, by merging the corresponding lanes of `self` and
+    /// `other` with the provided function.
+    ///
+    /// The merge function must append sorted, unique minor indices taken from its input lanes.
+    fn merge_lanes(
+        &self,
+        other: &Self,
+        mut merge: impl FnMut(&[usize], &[usize], &mut Vec<usize>),
+    ) -> Self {
+        assert_eq!(
             self.major_dim(),
-            new_offsets,
-            new_indices,
-        )
-        .expect("Internal error: Transpose should never fail.")
+            other.major_dim(),
+            "Patterns must have identical major dimensions."
+        );
+        assert_eq!(
+            self.minor_dim(),
+            other.minor_dim(),
+            "Patterns must have identical minor dimensions."
+        );
+
+        let mut offsets = Vec::with_capacity(self.major_dim() + 1);
+        let mut indices = Vec::new();
+        offsets.push(0);
+        for lane_idx in 0..self.major_dim() {
+            merge(self.lane(lane_idx), other.lane(lane_idx), &mut indices);
+            offsets.push(indices.len());
+        }
+
+        // Each lane is a sorted subset of the lanes of the inputs, so the format invariants
+        // hold without validation.
+        Self {
+            major_offsets: offsets,
+            minor_indices: indices,
+            minor_dim: self.minor_dim(),
+        }
     }
 }
 
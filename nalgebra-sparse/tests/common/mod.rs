@@ -1,6 +1,7 @@
 use nalgebra_sparse::csc::CscMatrix;
 use nalgebra_sparse::csr::CsrMatrix;
-use nalgebra_sparse::proptest::{csc, csr};
+use nalgebra_sparse::pattern::SparsityPattern;
+use nalgebra_sparse::proptest::{csc, csr, sparsity_pattern};
 use proptest::strategy::Strategy;
 use std::convert::TryFrom;
 use std::fmt::Debug;
@@ -67,6 +68,14 @@ pub fn csr_strategy() -> impl Strategy<Value = CsrMatrix<i32>> {
     )
 }
 
+pub fn square_csr_strategy() -> impl Strategy<Value = CsrMatrix<i32>> {
+    PROPTEST_MATRIX_DIM.prop_flat_map(|n| csr(PROPTEST_I32_VALUE_STRATEGY, n, n, PROPTEST_MAX_NNZ))
+}
+
+pub fn sparsity_pattern_strategy() -> impl Strategy<Value = SparsityPattern> {
+    sparsity_pattern(PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ)
+}
+
 pub fn csc_strategy() -> impl Strategy<Value = CscMatrix<i32>> {
     csc(
         PROPTEST_I32_VALUE_STRATEGY,
@@ -3,7 +3,7 @@ use crate::common::{value_strategy, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ};
 use nalgebra_sparse::csc::CscMatrix;
 use nalgebra_sparse::factorization::{CscCholesky};
 use nalgebra_sparse::proptest::csc;
-use nalgebra::{Matrix5, Vector5, Cholesky, DMatrix};
+use nalgebra::{Matrix2, Matrix5, Vector5, Cholesky, DMatrix};
 use nalgebra::proptest::matrix;
 
 use proptest::prelude::*;
@@ -58,8 +58,38 @@ proptest! {
             let x = cholesky.solve(&rhs);
             prop_assert_matrix_eq!(&matrix * &x, rhs, comp=abs, tol=1e-12);
         }
+
+        // solve_with_residual
+        {
+            let (x, residual) = cholesky.solve_with_residual(&matrix, &rhs);
+            prop_assert_matrix_eq!(x, cholesky.solve(&rhs), comp=abs, tol=1e-12);
+            let expected_residual = (&matrix * &x - &rhs).norm();
+            prop_assert!((residual - expected_residual).abs() <= 1e-12);
+            prop_assert!(residual <= 1e-10 * (1.0 + rhs.norm()));
+        }
     }
 
+    #[test]
+    fn cholesky_inverse_positive_definite(matrix in positive_definite()) {
+        let inverse = matrix.cholesky_inverse(1.0e-12).unwrap();
+        let identity = DMatrix::<f64>::identity(matrix.nrows(), matrix.ncols());
+        prop_assert_matrix_eq!(&matrix * &inverse, identity, comp=abs, tol=1e-10);
+        prop_assert_matrix_eq!(inverse, CscCholesky::factor(&matrix).unwrap().inverse(), comp=abs, tol=1e-12);
+    }
+
+}
+
+#[test]
+fn cholesky_inverse_not_positive_definite() {
+    let indefinite = CscMatrix::from(&Matrix2::new(1.0, 2.0,
+                                                   2.0, 1.0));
+    assert!(indefinite.cholesky_inverse(0.0).is_none());
+
+    // The second pivot is 1e-10.
+    let nearly_singular = CscMatrix::from(&Matrix2::new(1.0, 1.0,
+                                                        1.0, 1.0 + 1.0e-10));
+    assert!(nearly_singular.cholesky_inverse(0.0).is_some());
+    assert!(nearly_singular.cholesky_inverse(1.0e-8).is_none());
 }
 
 // This is a test ported from nalgebra's "sparse" module, for the original CsCholesky impl
@@ -1,4 +1,6 @@
+use approx::{assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq, relative_eq};
 use nalgebra::DMatrix;
+use nalgebra_sparse::coo::CooMatrix;
 use nalgebra_sparse::csc::CscMatrix;
 use nalgebra_sparse::{SparseEntry, SparseEntryMut, SparseFormatErrorKind};
 
@@ -390,6 +392,60 @@ fn csc_matrix_get_index_entry() {
     }
 }
 
+#[test]
+fn csc_matrix_approx_eq() {
+    let dense = DMatrix::from_row_slice(2, 3, &[1.0, 0.0, 3.0, 0.0, 5.0, 0.0]);
+    let a = CscMatrix::from(&dense);
+
+    // Same values, with explicitly stored zeros
+    let mut coo = CooMatrix::new(2, 3);
+    coo.push(0, 0, 1.0 + 1.0e-12);
+    coo.push(0, 1, 0.0);
+    coo.push(0, 2, 3.0);
+    coo.push(1, 1, 5.0);
+    coo.push(1, 2, 1.0e-14);
+    let b = CscMatrix::from(&coo);
+
+    assert_ne!(a, b);
+    assert_relative_eq!(a, b, epsilon = 1.0e-10);
+    assert_relative_eq!(b, a, epsilon = 1.0e-10);
+    assert_abs_diff_eq!(a, b, epsilon = 1.0e-10);
+    assert_ulps_eq!(a, b, epsilon = 1.0e-10);
+    assert!(!relative_eq!(
+        a,
+        b,
+        epsilon = 1.0e-16,
+        max_relative = 1.0e-16
+    ));
+
+    // An entry that is only stored in one of the matrices is compared to an exact zero
+    let mut coo = CooMatrix::new(2, 3);
+    coo.push(1, 0, 1.0e-3);
+    let c = CscMatrix::from(&coo) + &a;
+    assert!(!relative_eq!(a, c, epsilon = 1.0e-10));
+    assert!(!relative_eq!(c, a, epsilon = 1.0e-10));
+    assert_relative_eq!(a, c, epsilon = 1.0e-2);
+}
+
+#[test]
+fn csc_matrix_get() {
+    let dense = DMatrix::from_row_slice(2, 3, &[1, 0, 3, 0, 5, 6]);
+    let mut csc = CscMatrix::from(&dense);
+
+    assert_eq!(csc.get(0, 0), Some(&1));
+    assert_eq!(csc.get(0, 1), None);
+    assert_eq!(csc.get(1, 2), Some(&6));
+    // Out of bounds
+    assert_eq!(csc.get(0, 3), None);
+    assert_eq!(csc.get(2, 0), None);
+
+    *csc.get_mut(1, 1).unwrap() = 7;
+    assert_eq!(csc.get(1, 1), Some(&7));
+    assert_eq!(csc.get_mut(1, 0), None);
+    assert_eq!(csc.get_mut(2, 2), None);
+    assert_eq!(csc.nnz(), 4);
+}
+
 #[test]
 fn csc_matrix_col_iter() {
     // Note: this is the transpose of the matrix used for the similar csr_matrix_row_iter test
@@ -542,6 +598,16 @@ proptest! {
         prop_assert_eq!(csc.nnz(), csc_transpose.nnz());
     }
 
+    #[test]
+    fn csc_map_agrees_with_dense(csc in csc_strategy()) {
+        let mapped = csc.map(|x| f64::from(x) * 0.5 - 1.0);
+        prop_assert_eq!(mapped.pattern(), csc.pattern());
+        for ((i, j, v), (i2, j2, v2)) in csc.triplet_iter().zip(mapped.triplet_iter()) {
+            prop_assert_eq!((i, j), (i2, j2));
+            prop_assert_eq!(*v2, f64::from(*v) * 0.5 - 1.0);
+        }
+    }
+
     #[test]
     fn csc_filter(
         (csc, triplet_subset)
@@ -583,6 +649,16 @@ proptest! {
         prop_assert!(csc_upper_triangle.nnz() <= csc.nnz());
     }
 
+    #[test]
+    fn csc_strictly_triangular_parts_agree_with_dense(csc in csc_strategy()) {
+        let dense = DMatrix::from(&csc);
+        let lower = csc.strictly_lower_triangle();
+        let upper = csc.strictly_upper_triangle();
+        prop_assert_eq!(DMatrix::from(&lower), dense.strictly_lower_triangle());
+        prop_assert_eq!(DMatrix::from(&upper), dense.strictly_upper_triangle());
+        prop_assert_eq!(lower.nnz() + upper.nnz() + csc.diagonal_as_csc().nnz(), csc.nnz());
+    }
+
     #[test]
     fn csc_diagonal_as_csc(csc in csc_strategy()) {
         let d = csc.diagonal_as_csc();
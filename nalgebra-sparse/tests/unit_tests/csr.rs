@@ -1,14 +1,24 @@
-use nalgebra::DMatrix;
+use approx::{
+    abs_diff_eq, assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq, relative_eq, RelativeEq,
+};
+use nalgebra::proptest::vector;
+use nalgebra::{Complex, DMatrix, DVector};
+use nalgebra_sparse::coo::CooMatrix;
 use nalgebra_sparse::csr::CsrMatrix;
+use nalgebra_sparse::proptest::csr;
 use nalgebra_sparse::{SparseEntry, SparseEntryMut, SparseFormatErrorKind};
 
 use proptest::prelude::*;
 use proptest::sample::subsequence;
 
 use crate::assert_panics;
-use crate::common::csr_strategy;
+use crate::common::{
+    csr_strategy, square_csr_strategy, PROPTEST_I32_VALUE_STRATEGY, PROPTEST_MATRIX_DIM,
+    PROPTEST_MAX_NNZ,
+};
 
 use std::collections::HashSet;
+use std::error::Error;
 
 #[test]
 fn csr_matrix_valid_data() {
@@ -280,6 +290,30 @@ fn csr_matrix_try_from_invalid_csr_data() {
     }
 }
 
+#[test]
+fn csr_matrix_format_error_display() {
+    fn try_build(
+        offsets: Vec<usize>,
+        indices: Vec<usize>,
+    ) -> Result<CsrMatrix<i32>, Box<dyn Error + Send + Sync>> {
+        let values = vec![0; indices.len()];
+        Ok(CsrMatrix::try_from_csr_data(
+            3, 6, offsets, indices, values,
+        )?)
+    }
+
+    assert!(try_build(vec![0, 2, 2, 5], vec![0, 5, 1, 2, 3]).is_ok());
+
+    let error = try_build(vec![0, 3, 2, 5], vec![0, 1, 2, 3, 4]).unwrap_err();
+    assert!(error.to_string().starts_with("Invalid structure: "));
+
+    let error = try_build(vec![0, 2, 2, 5], vec![0, 6, 1, 2, 3]).unwrap_err();
+    assert!(error.to_string().starts_with("Index out of bounds: "));
+
+    let error = try_build(vec![0, 2, 2, 5], vec![0, 5, 2, 2, 3]).unwrap_err();
+    assert!(error.to_string().starts_with("Duplicate entry: "));
+}
+
 #[test]
 fn csr_disassemble_avoids_clone_when_owned() {
     // Test that disassemble avoids cloning the sparsity pattern when it holds the sole reference
@@ -390,6 +424,65 @@ fn csr_matrix_get_index_entry() {
     }
 }
 
+#[test]
+fn csr_matrix_approx_eq() {
+    let dense = DMatrix::from_row_slice(2, 3, &[1.0, 0.0, 3.0, 0.0, 5.0, 0.0]);
+    let a = CsrMatrix::from(&dense);
+
+    // Same values, with explicitly stored zeros
+    let mut coo = CooMatrix::new(2, 3);
+    coo.push(0, 0, 1.0 + 1.0e-12);
+    coo.push(0, 1, 0.0);
+    coo.push(0, 2, 3.0);
+    coo.push(1, 1, 5.0);
+    coo.push(1, 2, 1.0e-14);
+    let b = CsrMatrix::from(&coo);
+
+    assert_ne!(a, b);
+    assert_relative_eq!(a, b, epsilon = 1.0e-10);
+    assert_relative_eq!(b, a, epsilon = 1.0e-10);
+    assert_abs_diff_eq!(a, b, epsilon = 1.0e-10);
+    assert_ulps_eq!(a, b, epsilon = 1.0e-10);
+    assert!(!relative_eq!(
+        a,
+        b,
+        epsilon = 1.0e-16,
+        max_relative = 1.0e-16
+    ));
+
+    // An entry that is only stored in one of the matrices is compared to an exact zero
+    let mut coo = CooMatrix::new(2, 3);
+    coo.push(1, 0, 1.0e-3);
+    let c = CsrMatrix::from(&coo) + &a;
+    assert!(!relative_eq!(a, c, epsilon = 1.0e-10));
+    assert!(!relative_eq!(c, a, epsilon = 1.0e-10));
+    assert_relative_eq!(a, c, epsilon = 1.0e-2);
+
+    // Different dimensions
+    let c = CsrMatrix::from(&DMatrix::from_row_slice(2, 2, &[1.0, 0.0, 0.0, 2.0]));
+    assert!(!a.relative_eq(&c, 1.0e-10, 1.0e-10));
+    assert!(!abs_diff_eq!(a, c, epsilon = 1.0e10));
+}
+
+#[test]
+fn csr_matrix_get() {
+    let dense = DMatrix::from_row_slice(2, 3, &[1, 0, 3, 0, 5, 6]);
+    let mut csr = CsrMatrix::from(&dense);
+
+    assert_eq!(csr.get(0, 0), Some(&1));
+    assert_eq!(csr.get(0, 1), None);
+    assert_eq!(csr.get(1, 2), Some(&6));
+    // Out of bounds
+    assert_eq!(csr.get(0, 3), None);
+    assert_eq!(csr.get(2, 0), None);
+
+    *csr.get_mut(1, 1).unwrap() = 7;
+    assert_eq!(csr.get(1, 1), Some(&7));
+    assert_eq!(csr.get_mut(1, 0), None);
+    assert_eq!(csr.get_mut(2, 2), None);
+    assert_eq!(csr.nnz(), 4);
+}
+
 #[test]
 fn csr_matrix_row_iter() {
     #[rustfmt::skip]
@@ -524,7 +617,251 @@ fn csr_matrix_row_iter() {
     }
 }
 
+#[test]
+#[cfg(feature = "parallel")]
+fn csr_par_mul_large_matrix() {
+    // Large enough for the rows to be distributed among several tasks
+    let n = 50_000;
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        coo.push(i, i, 2.0);
+        if i > 0 {
+            coo.push(i, i - 1, -1.0);
+        }
+        if i + 1 < n {
+            coo.push(i, i + 1, -1.0);
+        }
+    }
+    let csr = CsrMatrix::from(&coo);
+    let x = DVector::from_fn(n, |i, _| (i % 7) as f64);
+
+    assert_eq!(csr.par_mul(&x), &csr * &x);
+}
+
+#[test]
+fn csr_gemv_dimension_checks() {
+    let csr = CsrMatrix::<f64>::identity(3);
+    let x = DVector::zeros(3);
+    let mut y = DVector::zeros(3);
+
+    assert_panics!(csr.gemv(1.0, &DVector::zeros(2), 0.0, &mut y.clone()));
+    assert_panics!(csr.gemv(1.0, &x, 0.0, &mut DVector::zeros(4)));
+
+    // A zero `beta` overwrites `y` without reading it.
+    y.fill(f64::NAN);
+    csr.gemv(2.0, &DVector::from_element(3, 1.5), 0.0, &mut y);
+    assert_eq!(y, DVector::from_element(3, 3.0));
+
+    let empty = CsrMatrix::<f64>::zeros(0, 2);
+    let mut y = DVector::zeros(0);
+    empty.gemv(1.0, &DVector::zeros(2), 1.0, &mut y);
+    assert_eq!(y.len(), 0);
+}
+
+#[test]
+fn csr_permute_symmetric_panics_on_invalid_input() {
+    let csr = CsrMatrix::<i32>::identity(3);
+    assert_panics!(csr.permute_symmetric(&[0, 1]));
+    assert_panics!(csr.permute_symmetric(&[0, 1, 3]));
+    assert_panics!(csr.permute_symmetric(&[0, 2, 2]));
+    assert_panics!(CsrMatrix::<i32>::zeros(2, 3).permute_symmetric(&[0, 1]));
+
+    assert_eq!(csr.permute_symmetric(&[2, 0, 1]), csr);
+    let empty = CsrMatrix::<i32>::zeros(0, 0);
+    assert_eq!(empty.permute_symmetric(&[]), empty);
+}
+
+#[test]
+fn csr_block_diagonal_empty() {
+    let csr = CsrMatrix::<i32>::block_diagonal(&[]);
+    assert_eq!(csr.nrows(), 0);
+    assert_eq!(csr.ncols(), 0);
+    assert_eq!(csr.nnz(), 0);
+}
+
+#[test]
+fn csr_vstack_hstack() {
+    #[rustfmt::skip]
+    let a = DMatrix::from_row_slice(2, 3, &[
+        1, 0, 2,
+        0, 0, 3,
+    ]);
+    #[rustfmt::skip]
+    let b = DMatrix::from_row_slice(1, 3, &[
+        0, 4, 0,
+    ]);
+    #[rustfmt::skip]
+    let c = DMatrix::from_row_slice(2, 2, &[
+        5, 0,
+        0, 6,
+    ]);
+    let (a_csr, b_csr, c_csr) = (
+        CsrMatrix::from(&a),
+        CsrMatrix::from(&b),
+        CsrMatrix::from(&c),
+    );
+
+    let vstacked = CsrMatrix::vstack(&[&a_csr, &b_csr, &a_csr]);
+    assert_eq!(vstacked.row_offsets(), &[0, 2, 3, 4, 6, 7]);
+    assert_eq!(vstacked.col_indices(), &[0, 2, 2, 1, 0, 2, 2]);
+    assert_eq!(vstacked.values(), &[1, 2, 3, 4, 1, 2, 3]);
+    assert_eq!(vstacked.ncols(), 3);
+
+    let hstacked = CsrMatrix::hstack(&[&c_csr, &a_csr]);
+    assert_eq!(hstacked.row_offsets(), &[0, 3, 5]);
+    assert_eq!(hstacked.col_indices(), &[0, 2, 4, 1, 4]);
+    assert_eq!(hstacked.values(), &[5, 1, 2, 6, 3]);
+    assert_eq!(hstacked.ncols(), 5);
+
+    let empty = CsrMatrix::<i32>::vstack(&[]);
+    assert_eq!((empty.nrows(), empty.ncols(), empty.nnz()), (0, 0, 0));
+    let empty = CsrMatrix::<i32>::hstack(&[]);
+    assert_eq!((empty.nrows(), empty.ncols(), empty.nnz()), (0, 0, 0));
+
+    // Blocks without rows or columns are allowed.
+    let no_rows = CsrMatrix::<i32>::zeros(0, 3);
+    assert_eq!(CsrMatrix::vstack(&[&no_rows, &b_csr]), b_csr);
+    let no_cols = CsrMatrix::<i32>::zeros(2, 0);
+    assert_eq!(CsrMatrix::hstack(&[&c_csr, &no_cols]), c_csr);
+
+    assert_panics!(CsrMatrix::vstack(&[&a_csr, &c_csr]));
+    assert_panics!(CsrMatrix::hstack(&[&a_csr, &b_csr]));
+}
+
+#[test]
+fn csr_from_dense_with_threshold() {
+    #[rustfmt::skip]
+    let dense = DMatrix::from_row_slice(3, 3, &[
+        1.0, 1.0e-8, 0.0,
+        -1.0e-3, 0.0, 2.0,
+        0.0, -5.0, 1.0e-3,
+    ]);
+
+    let csr = CsrMatrix::from_dense_with_threshold(&dense, 1.0e-3);
+    assert_eq!(csr.row_offsets(), &[0, 1, 2, 3]);
+    assert_eq!(csr.col_indices(), &[0, 2, 1]);
+    assert_eq!(csr.values(), &[1.0, 2.0, -5.0]);
+
+    // A zero threshold drops only the zero entries.
+    let csr = CsrMatrix::from_dense_with_threshold(&dense, 0.0);
+    assert_eq!(csr.nnz(), 6);
+    assert_eq!(DMatrix::from(&csr), dense);
+
+    // Complex entries are compared by their magnitude.
+    let dense = DMatrix::from_row_slice(
+        1,
+        3,
+        &[
+            Complex::new(0.6, 0.8),
+            Complex::new(0.0, -2.0),
+            Complex::new(0.7, 0.7),
+        ],
+    );
+    let csr = CsrMatrix::from_dense_with_threshold(&dense, 1.0);
+    assert_eq!(csr.col_indices(), &[1]);
+    assert_eq!(csr.values(), &[Complex::new(0.0, -2.0)]);
+}
+
+#[test]
+fn csr_submatrix_invalid_ranges() {
+    let csr = CsrMatrix::<i32>::identity(3);
+    assert_panics!(csr.submatrix(0..4, 0..3));
+    assert_panics!(csr.submatrix(0..3, 1..4));
+    let (start, end) = (2, 1);
+    assert_panics!(csr.submatrix(start..end, 0..3));
+    assert_panics!(csr.submatrix(0..3, start..end));
+
+    let empty = csr.submatrix(3..3, 1..1);
+    assert_eq!((empty.nrows(), empty.ncols(), empty.nnz()), (0, 0, 0));
+}
+
 proptest! {
+    #[test]
+    fn csr_block_diagonal_agrees_with_dense(blocks in proptest::collection::vec(csr_strategy(), 0..4)) {
+        let block_refs: Vec<_> = blocks.iter().collect();
+        let dense_blocks: Vec<_> = blocks.iter().map(DMatrix::from).collect();
+        let dense_block_refs: Vec<_> = dense_blocks.iter().collect();
+
+        let csr = CsrMatrix::block_diagonal(&block_refs);
+        prop_assert_eq!(csr.nnz(), blocks.iter().map(|b| b.nnz()).sum::<usize>());
+        prop_assert_eq!(DMatrix::from(&csr), DMatrix::block_diagonal(&dense_block_refs));
+    }
+
+    #[test]
+    fn csr_vstack_agrees_with_dense(
+        blocks in PROPTEST_MATRIX_DIM.prop_flat_map(|n| {
+            let block = csr(PROPTEST_I32_VALUE_STRATEGY, PROPTEST_MATRIX_DIM, n, PROPTEST_MAX_NNZ);
+            proptest::collection::vec(block, 1..4)
+        })
+    ) {
+        let block_refs: Vec<_> = blocks.iter().collect();
+        let stacked = CsrMatrix::vstack(&block_refs);
+
+        let mut expected = DMatrix::zeros(stacked.nrows(), blocks[0].ncols());
+        let mut row_offset = 0;
+        for block in &blocks {
+            expected.rows_mut(row_offset, block.nrows()).copy_from(&DMatrix::from(block));
+            row_offset += block.nrows();
+        }
+
+        prop_assert_eq!(stacked.nnz(), blocks.iter().map(|b| b.nnz()).sum::<usize>());
+        prop_assert_eq!(DMatrix::from(&stacked), expected);
+    }
+
+    #[test]
+    fn csr_hstack_agrees_with_dense(
+        blocks in PROPTEST_MATRIX_DIM.prop_flat_map(|m| {
+            let block = csr(PROPTEST_I32_VALUE_STRATEGY, m, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ);
+            proptest::collection::vec(block, 1..4)
+        })
+    ) {
+        let block_refs: Vec<_> = blocks.iter().collect();
+        let stacked = CsrMatrix::hstack(&block_refs);
+
+        let mut expected = DMatrix::zeros(blocks[0].nrows(), stacked.ncols());
+        let mut col_offset = 0;
+        for block in &blocks {
+            expected.columns_mut(col_offset, block.ncols()).copy_from(&DMatrix::from(block));
+            col_offset += block.ncols();
+        }
+
+        prop_assert_eq!(stacked.nnz(), blocks.iter().map(|b| b.nnz()).sum::<usize>());
+        prop_assert_eq!(DMatrix::from(&stacked), expected);
+    }
+
+    #[test]
+    fn csr_from_dense_with_threshold_agrees_with_dense(csr in csr_strategy(), threshold in 0..5) {
+        let dense = DMatrix::from(&csr).map(f64::from);
+        let thresholded = CsrMatrix::from_dense_with_threshold(&dense, f64::from(threshold));
+
+        let expected = dense.map(|x| if x.abs() > f64::from(threshold) { x } else { 0.0 });
+        prop_assert_eq!(DMatrix::from(&thresholded), expected);
+        prop_assert!(thresholded.values().iter().all(|x| x.abs() > f64::from(threshold)));
+    }
+
+    #[test]
+    fn csr_submatrix_agrees_with_dense(
+        (csr, rows, cols) in csr_strategy()
+            .prop_flat_map(|csr| {
+                let (m, n) = (csr.nrows(), csr.ncols());
+                let rows = (0..=m).prop_flat_map(move |end| (0..=end, Just(end)));
+                let cols = (0..=n).prop_flat_map(move |end| (0..=end, Just(end)));
+                (Just(csr), rows, cols)
+            })
+    ) {
+        let (rows, cols) = (rows.0..rows.1, cols.0..cols.1);
+        let sub = csr.submatrix(rows.clone(), cols.clone());
+        let dense = DMatrix::from(&csr);
+        let expected = dense.slice_range(rows.clone(), cols.clone()).into_owned();
+
+        prop_assert_eq!(DMatrix::from(&sub), expected);
+        let expected_nnz = csr
+            .triplet_iter()
+            .filter(|(i, j, _)| rows.contains(i) && cols.contains(j))
+            .count();
+        prop_assert_eq!(sub.nnz(), expected_nnz);
+    }
+
     #[test]
     fn csr_double_transpose_is_identity(csr in csr_strategy()) {
         prop_assert_eq!(csr.transpose().transpose(), csr);
@@ -538,6 +875,85 @@ proptest! {
         prop_assert_eq!(csr.nnz(), csr_transpose.nnz());
     }
 
+    #[test]
+    fn csr_symmetrize_agrees_with_dense(csr in square_csr_strategy()) {
+        // Symmetrizing the lower triangle must give back L + L^T - diag(L)
+        let lower = csr.lower_triangle();
+        let dense_lower = DMatrix::from(&lower);
+        let dense_diagonal = DMatrix::from_diagonal(&dense_lower.diagonal());
+        let expected = &dense_lower + dense_lower.transpose() - dense_diagonal;
+
+        let symmetrized = lower.symmetrize();
+        prop_assert_eq!(DMatrix::from(&symmetrized), expected);
+        prop_assert_eq!(symmetrized.pattern(), &lower.pattern().symmetrize());
+
+        // Explicitly stored entries must keep their value
+        let symmetrized = csr.symmetrize();
+        for (i, j, v) in csr.triplet_iter() {
+            prop_assert_eq!(symmetrized.get_entry(i, j), Some(SparseEntry::NonZero(v)));
+        }
+    }
+
+    #[test]
+    fn csr_permute_symmetric_agrees_with_dense(
+        (csr, perm) in square_csr_strategy().prop_flat_map(|csr| {
+            let perm: Vec<usize> = (0..csr.nrows()).collect();
+            (Just(csr), Just(perm).prop_shuffle())
+        })
+    ) {
+        let permuted = csr.permute_symmetric(&perm);
+        let dense = DMatrix::from(&csr);
+        let expected = DMatrix::from_fn(csr.nrows(), csr.ncols(), |i, j| dense[(perm[i], perm[j])]);
+
+        prop_assert_eq!(DMatrix::from(&permuted), expected);
+        prop_assert_eq!(permuted.nnz(), csr.nnz());
+
+        // Applying the inverse permutation gives back the original matrix
+        let mut inv_perm = vec![0; perm.len()];
+        for (new, &old) in perm.iter().enumerate() {
+            inv_perm[old] = new;
+        }
+        prop_assert_eq!(permuted.permute_symmetric(&inv_perm), csr);
+    }
+
+    #[test]
+    fn csr_gemv_agrees_with_dense(
+        (csr, x, y) in csr_strategy()
+            .prop_flat_map(|csr| {
+                let x = vector(PROPTEST_I32_VALUE_STRATEGY, csr.ncols());
+                let y = vector(PROPTEST_I32_VALUE_STRATEGY, csr.nrows());
+                (Just(csr), x, y)
+            }),
+        alpha in PROPTEST_I32_VALUE_STRATEGY,
+        beta in PROPTEST_I32_VALUE_STRATEGY)
+    {
+        let mut y_gemv = y.clone();
+        csr.gemv(alpha, &x, beta, &mut y_gemv);
+        prop_assert_eq!(y_gemv, y * beta + (&csr * &x) * alpha);
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn csr_par_mul_agrees_with_serial_mul(
+        (csr, x) in csr_strategy()
+            .prop_flat_map(|csr| {
+                let x = vector(PROPTEST_I32_VALUE_STRATEGY, csr.ncols());
+                (Just(csr), x)
+            }))
+    {
+        prop_assert_eq!(csr.par_mul(&x), &csr * &x);
+    }
+
+    #[test]
+    fn csr_map_agrees_with_dense(csr in csr_strategy()) {
+        let mapped = csr.map(|x| f64::from(x) * 0.5 - 1.0);
+        prop_assert_eq!(mapped.pattern(), csr.pattern());
+        for ((i, j, v), (i2, j2, v2)) in csr.triplet_iter().zip(mapped.triplet_iter()) {
+            prop_assert_eq!((i, j), (i2, j2));
+            prop_assert_eq!(*v2, f64::from(*v) * 0.5 - 1.0);
+        }
+    }
+
     #[test]
     fn csr_filter(
         (csr, triplet_subset)
@@ -565,6 +981,18 @@ proptest! {
         prop_assert_eq!(filtered_triplets, triplet_subset);
     }
 
+    #[test]
+    fn csr_retain_agrees_with_filter(csr in csr_strategy(), modulus in 1 ..= 4i32) {
+        let predicate = |i: usize, j: usize, v: &i32| (i + j) as i32 % modulus != v % modulus;
+        let mut retained = csr.clone();
+        retained.retain(predicate);
+
+        // The rebuilt pattern is validated in debug builds, and equality with `filter` also
+        // ensures that the compacted storage is still sorted.
+        prop_assert_eq!(&retained, &csr.filter(predicate));
+        prop_assert!(retained.nnz() <= csr.nnz());
+    }
+
     #[test]
     fn csr_lower_triangle_agrees_with_dense(csr in csr_strategy()) {
         let csr_lower_triangle = csr.lower_triangle();
@@ -579,6 +1007,16 @@ proptest! {
         prop_assert!(csr_upper_triangle.nnz() <= csr.nnz());
     }
 
+    #[test]
+    fn csr_strictly_triangular_parts_agree_with_dense(csr in csr_strategy()) {
+        let dense = DMatrix::from(&csr);
+        let lower = csr.strictly_lower_triangle();
+        let upper = csr.strictly_upper_triangle();
+        prop_assert_eq!(DMatrix::from(&lower), dense.strictly_lower_triangle());
+        prop_assert_eq!(DMatrix::from(&upper), dense.strictly_upper_triangle());
+        prop_assert_eq!(lower.nnz() + upper.nnz() + csr.diagonal_as_csr().nnz(), csr.nnz());
+    }
+
     #[test]
     fn csr_diagonal_as_csr(csr in csr_strategy()) {
         let d = csr.diagonal_as_csr();
@@ -599,3 +1037,167 @@ proptest! {
         prop_assert_eq!(DMatrix::from(&csr), DMatrix::identity(n, n));
     }
 }
+
+#[test]
+fn csr_sparsity_stats() {
+    {
+        // 4x3 matrix with an empty last row and a fully populated diagonal
+        let offsets = vec![0, 2, 3, 6, 6];
+        let indices = vec![0, 2, 1, 0, 1, 2];
+        let values = vec![1, 2, 3, 4, 5, 6];
+        let csr = CsrMatrix::try_from_csr_data(4, 3, offsets, indices, values).unwrap();
+        let stats = csr.sparsity_stats();
+
+        assert_eq!(stats.nnz, 6);
+        assert_eq!(stats.density, 0.5);
+        assert_eq!(stats.min_row_nnz, 0);
+        assert_eq!(stats.max_row_nnz, 3);
+        assert_eq!(stats.mean_row_nnz, 1.5);
+        assert_eq!(stats.empty_rows, 1);
+        assert!(stats.full_diagonal);
+    }
+
+    {
+        // Explicitly stored zeros count as stored entries
+        let mut csr = CsrMatrix::<f64>::identity(3);
+        csr.values_mut()[1] = 0.0;
+        let stats = csr.sparsity_stats();
+        assert_eq!((stats.nnz, stats.empty_rows), (3, 0));
+        assert_eq!((stats.min_row_nnz, stats.max_row_nnz), (1, 1));
+        assert!(stats.full_diagonal);
+
+        let stats = CsrMatrix::<f64>::zeros(2, 3).sparsity_stats();
+        assert_eq!((stats.nnz, stats.density, stats.empty_rows), (0, 0.0, 2));
+        assert!(!stats.full_diagonal);
+    }
+
+    {
+        // Empty matrices
+        let stats = CsrMatrix::<f64>::zeros(0, 0).sparsity_stats();
+        assert_eq!(stats.nnz, 0);
+        assert_eq!((stats.density, stats.mean_row_nnz), (0.0, 0.0));
+        assert_eq!(
+            (stats.min_row_nnz, stats.max_row_nnz, stats.empty_rows),
+            (0, 0, 0)
+        );
+        assert!(stats.full_diagonal);
+
+        let stats = CsrMatrix::<f64>::zeros(3, 0).sparsity_stats();
+        assert_eq!((stats.density, stats.mean_row_nnz), (0.0, 0.0));
+        assert_eq!(stats.empty_rows, 3);
+        assert!(stats.full_diagonal);
+    }
+}
+
+proptest! {
+    #[test]
+    fn csr_sparsity_stats_agree_with_rows(csr in csr_strategy()) {
+        let stats = csr.sparsity_stats();
+        let row_nnz: Vec<_> = csr.row_iter().map(|row| row.nnz()).collect();
+
+        prop_assert_eq!(stats.nnz, csr.nnz());
+        prop_assert_eq!(stats.min_row_nnz, row_nnz.iter().copied().min().unwrap_or(0));
+        prop_assert_eq!(stats.max_row_nnz, row_nnz.iter().copied().max().unwrap_or(0));
+        prop_assert_eq!(stats.empty_rows, row_nnz.iter().filter(|&&n| n == 0).count());
+        prop_assert_eq!(stats.empty_rows, csr.pattern().find_empty_lanes().len());
+
+        let full_diagonal = (0..csr.nrows().min(csr.ncols()))
+            .all(|i| csr.get_entry(i, i) != Some(SparseEntry::Zero));
+        prop_assert_eq!(stats.full_diagonal, full_diagonal);
+    }
+}
+
+#[test]
+fn csr_kronecker_sum() {
+    // The diagonal entry of the second row of `a` is not stored, and the diagonal entry of the
+    // first row of `b` cancels out with that of `a`.
+    let a = DMatrix::from_row_slice(3, 3, &[1, 2, 0, 3, 0, 4, 0, 0, 5]);
+    let b = DMatrix::from_row_slice(2, 2, &[-1, 6, 0, 7]);
+    let (a_csr, b_csr) = (CsrMatrix::from(&a), CsrMatrix::from(&b));
+
+    let sum = a_csr.kronecker_sum(&b_csr);
+    assert_eq!(DMatrix::from(&sum), a.kronecker_sum(&b));
+    assert_eq!(sum.nnz(), 5 * 2 + 3 * 3 - 4);
+    assert_eq!(sum.get_entry(0, 0), Some(SparseEntry::NonZero(&0)));
+
+    // The sum with an empty matrix is empty
+    let empty = CsrMatrix::<i32>::zeros(0, 0);
+    assert_eq!(a_csr.kronecker_sum(&empty).nrows(), 0);
+    assert_eq!(empty.kronecker_sum(&b_csr).ncols(), 0);
+
+    let rect = CsrMatrix::<i32>::zeros(2, 3);
+    assert_panics!(a_csr.kronecker_sum(&rect));
+    assert_panics!(rect.kronecker_sum(&a_csr));
+}
+
+proptest! {
+    #[test]
+    fn csr_kronecker_sum_agrees_with_dense(
+        (a, b) in (0..6usize, 0..6usize).prop_flat_map(|(n, m)| {
+            (csr(PROPTEST_I32_VALUE_STRATEGY, n, n, n * n),
+             csr(PROPTEST_I32_VALUE_STRATEGY, m, m, m * m))
+        })
+    ) {
+        let sum = a.kronecker_sum(&b);
+        let expected = DMatrix::from(&a).kronecker_sum(&DMatrix::from(&b));
+        prop_assert_eq!(DMatrix::from(&sum), expected);
+
+        // The pattern is the union of the patterns of the two terms
+        let (n, m) = (a.nrows(), b.nrows());
+        let mut expected_pattern = HashSet::new();
+        for (i1, j1, _) in a.triplet_iter() {
+            expected_pattern.extend((0..m).map(|k| (i1 * m + k, j1 * m + k)));
+        }
+        for (i2, j2, _) in b.triplet_iter() {
+            expected_pattern.extend((0..n).map(|i1| (i1 * m + i2, i1 * m + j2)));
+        }
+        let pattern: HashSet<_> = sum.triplet_iter().map(|(i, j, _)| (i, j)).collect();
+        prop_assert_eq!(pattern, expected_pattern);
+    }
+}
+
+#[test]
+fn csr_prune() {
+    #[rustfmt::skip]
+    let dense = DMatrix::from_row_slice(3, 3, &[
+        1.0,    1.0e-14, 0.0,
+        0.0,    0.0,     0.0,
+        -1.0e-13, -2.0,  f64::NAN,
+    ]);
+    // Store every entry explicitly, including zeros and NaN.
+    let mut coo = CooMatrix::new(3, 3);
+    for (j, col) in dense.column_iter().enumerate() {
+        for (i, v) in col.iter().enumerate() {
+            coo.push(i, j, *v);
+        }
+    }
+    let mut csr = CsrMatrix::from(&coo);
+    assert_eq!(csr.nnz(), 9);
+
+    csr.prune(1.0e-12);
+    assert_eq!(csr.row_offsets(), &[0, 1, 1, 2]);
+    assert_eq!(csr.col_indices(), &[0, 1]);
+    assert_eq!(csr.values(), &[1.0, -2.0]);
+
+    csr.prune(10.0);
+    assert_eq!(csr.nnz(), 0);
+    assert_eq!(csr.row_offsets(), &[0, 0, 0, 0]);
+}
+
+#[test]
+fn csr_retain_leaves_matrix_unchanged_if_predicate_panics() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let mut coo = CooMatrix::new(2, 3);
+    coo.push(0, 0, 1);
+    coo.push(0, 2, 2);
+    coo.push(1, 1, 3);
+    let csr = CsrMatrix::from(&coo);
+
+    let mut retained = csr.clone();
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        retained.retain(|_, _, &v| if v == 3 { panic!() } else { v == 1 })
+    }));
+    assert!(result.is_err());
+    assert_eq!(retained, csr);
+}
@@ -0,0 +1,146 @@
+use crate::common::{value_strategy, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ};
+use nalgebra::proptest::matrix;
+use nalgebra::DMatrix;
+use nalgebra_sparse::coo::CooMatrix;
+use nalgebra_sparse::csc::CscMatrix;
+use nalgebra_sparse::factorization::{ic0, CholeskyError, CscCholesky, CscIc0};
+use nalgebra_sparse::proptest::csc;
+
+use matrixcompare::{assert_matrix_eq, prop_assert_matrix_eq};
+use proptest::prelude::*;
+
+fn diagonally_dominant_symmetric() -> impl Strategy<Value = CscMatrix<f64>> {
+    PROPTEST_MATRIX_DIM
+        .prop_flat_map(|n| csc(value_strategy::<f64>(), n, n, PROPTEST_MAX_NNZ))
+        .prop_map(|x| {
+            // Entries of x + x^T are bounded by 10 in magnitude, so this guarantees strict
+            // diagonal dominance, hence positive definiteness
+            let n = x.ncols();
+            let shift = 10.0 * n as f64 + 1.0;
+            &x + x.transpose() + CscMatrix::identity(n) * shift
+        })
+}
+
+proptest! {
+    #[test]
+    fn ic0_product_agrees_with_matrix_on_pattern(matrix in diagonally_dominant_symmetric()) {
+        let ic = CscIc0::factor(&matrix).unwrap();
+        let l = DMatrix::from(ic.l());
+        let llt = &l * l.transpose();
+
+        let lower = matrix.lower_triangle();
+        prop_assert_eq!(ic.l().pattern(), lower.pattern());
+        for (i, j, v) in matrix.triplet_iter() {
+            prop_assert!((llt[(i, j)] - v).abs() <= 1e-12);
+        }
+    }
+
+    #[test]
+    fn ic0_solve_agrees_with_factor(
+        (matrix, rhs) in diagonally_dominant_symmetric()
+            .prop_flat_map(|csc| {
+                let rhs = matrix(value_strategy::<f64>(), csc.nrows(), PROPTEST_MATRIX_DIM);
+                (Just(csc), rhs)
+            })
+    ) {
+        let ic = CscIc0::factor(&matrix).unwrap();
+        let l = DMatrix::from(ic.l());
+        let llt = &l * l.transpose();
+
+        // solve_mut
+        {
+            let mut x = rhs.clone();
+            ic.solve_mut(&mut x);
+            prop_assert_matrix_eq!(&llt * &x, rhs, comp = abs, tol = 1e-12);
+        }
+
+        // solve
+        {
+            let x = ic.solve(&rhs);
+            prop_assert_matrix_eq!(&llt * &x, rhs, comp = abs, tol = 1e-12);
+        }
+    }
+}
+
+#[test]
+fn ic0_is_exact_without_fill_in() {
+    // The Cholesky factor of a tridiagonal matrix has no fill-in, so IC(0) is exact.
+    let n = 6;
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        coo.push(i, i, 4.0);
+        if i > 0 {
+            coo.push(i, i - 1, -1.0);
+            coo.push(i - 1, i, -1.0);
+        }
+    }
+    let matrix = CscMatrix::from(&coo);
+
+    let ic = CscIc0::factor(&matrix).unwrap();
+    let cholesky = CscCholesky::factor(&matrix).unwrap();
+    assert_matrix_eq!(ic.l(), cholesky.l(), comp = abs, tol = 1e-12);
+    assert_eq!(ic0(&matrix).as_ref(), Some(ic.l()));
+}
+
+#[test]
+fn ic0_non_positive_pivot() {
+    let dense = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 2.0, 1.0]);
+    let matrix = CscMatrix::from(&dense);
+    assert_eq!(
+        CscIc0::factor(&matrix).unwrap_err(),
+        CholeskyError::NotPositiveDefinite
+    );
+
+    // Missing diagonal entry
+    let mut coo = CooMatrix::new(2, 2);
+    coo.push(0, 0, 1.0);
+    coo.push(0, 1, 1.0);
+    coo.push(1, 0, 1.0);
+    let matrix = CscMatrix::from(&coo);
+    assert_eq!(
+        CscIc0::factor(&matrix).unwrap_err(),
+        CholeskyError::NotPositiveDefinite
+    );
+    assert!(ic0(&matrix).is_none());
+
+    // NaN pivot
+    let dense = DMatrix::from_row_slice(2, 2, &[f64::NAN, 1.0, 1.0, 2.0]);
+    let matrix = CscMatrix::from(&dense);
+    assert_eq!(
+        CscIc0::factor(&matrix).unwrap_err(),
+        CholeskyError::NotPositiveDefinite
+    );
+    assert!(ic0(&matrix).is_none());
+}
+
+#[test]
+fn ic0_structurally_singular() {
+    let mut coo = CooMatrix::new(2, 2);
+    coo.push(0, 0, 1.0);
+    let matrix = CscMatrix::from(&coo);
+    assert_eq!(
+        CscIc0::factor(&matrix).unwrap_err(),
+        CholeskyError::EmptyColumn(1)
+    );
+    assert_eq!(
+        CscCholesky::factor(&matrix).unwrap_err(),
+        CholeskyError::EmptyColumn(1)
+    );
+}
+
+#[test]
+fn ic0_empty_row_without_empty_column() {
+    // Row 1 is empty, but column 1 is not: the missing diagonal entry is reported instead
+    let mut coo = CooMatrix::new(2, 2);
+    coo.push(0, 0, 1.0);
+    coo.push(0, 1, 1.0);
+    let matrix = CscMatrix::from(&coo);
+    assert_eq!(
+        CscIc0::factor(&matrix).unwrap_err(),
+        CholeskyError::NotPositiveDefinite
+    );
+    assert_eq!(
+        CscCholesky::factor(&matrix).unwrap_err(),
+        CholeskyError::NotPositiveDefinite
+    );
+}
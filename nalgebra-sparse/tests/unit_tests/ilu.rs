@@ -0,0 +1,128 @@
+use crate::common::{value_strategy, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ};
+use nalgebra::proptest::matrix;
+use nalgebra::DMatrix;
+use nalgebra_sparse::coo::CooMatrix;
+use nalgebra_sparse::csr::CsrMatrix;
+use nalgebra_sparse::factorization::{CsrIlu0, Ilu0Error};
+use nalgebra_sparse::proptest::csr;
+
+use matrixcompare::{assert_matrix_eq, prop_assert_matrix_eq};
+use proptest::prelude::*;
+
+fn diagonally_dominant() -> impl Strategy<Value = CsrMatrix<f64>> {
+    PROPTEST_MATRIX_DIM
+        .prop_flat_map(|n| csr(value_strategy::<f64>(), n, n, PROPTEST_MAX_NNZ))
+        .prop_map(|x| {
+            // Entries are bounded by 5 in magnitude, so this guarantees strict diagonal dominance
+            let n = x.ncols();
+            let shift = 5.0 * n as f64 + 1.0;
+            x + CsrMatrix::identity(n) * shift
+        })
+}
+
+proptest! {
+    #[test]
+    fn ilu0_product_agrees_with_matrix_on_pattern(matrix in diagonally_dominant()) {
+        let ilu = CsrIlu0::factor(&matrix).unwrap();
+        let l = DMatrix::from(ilu.l());
+        let u = DMatrix::from(ilu.u());
+        let lu = &l * &u;
+
+        prop_assert!(ilu.l().triplet_iter().all(|(i, j, _)| j <= i));
+        prop_assert!(ilu.u().triplet_iter().all(|(i, j, _)| i <= j));
+        prop_assert!((0..l.nrows()).all(|i| l[(i, i)] == 1.0));
+
+        for (i, j, v) in matrix.triplet_iter() {
+            prop_assert!((lu[(i, j)] - v).abs() <= 1e-12);
+        }
+    }
+
+    #[test]
+    fn ilu0_solve_agrees_with_factors(
+        (matrix, rhs) in diagonally_dominant()
+            .prop_flat_map(|csr| {
+                let rhs = matrix(value_strategy::<f64>(), csr.nrows(), PROPTEST_MATRIX_DIM);
+                (Just(csr), rhs)
+            })
+    ) {
+        let ilu = CsrIlu0::factor(&matrix).unwrap();
+        let lu = DMatrix::from(ilu.l()) * DMatrix::from(ilu.u());
+
+        // solve_mut
+        {
+            let mut x = rhs.clone();
+            ilu.solve_mut(&mut x);
+            prop_assert_matrix_eq!(&lu * &x, rhs, comp = abs, tol = 1e-12);
+        }
+
+        // solve
+        {
+            let x = ilu.solve(&rhs);
+            prop_assert_matrix_eq!(&lu * &x, rhs, comp = abs, tol = 1e-12);
+        }
+    }
+}
+
+#[test]
+fn ilu0_is_exact_without_fill_in() {
+    // The LU factors of a tridiagonal matrix have no fill-in, so ILU(0) is the exact LU.
+    let n = 6;
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        coo.push(i, i, 4.0);
+        if i > 0 {
+            coo.push(i, i - 1, -1.0);
+        }
+        if i + 1 < n {
+            coo.push(i, i + 1, -2.0);
+        }
+    }
+    let matrix = CsrMatrix::from(&coo);
+    let dense = DMatrix::from(&matrix);
+
+    let ilu = CsrIlu0::factor(&matrix).unwrap();
+    assert_matrix_eq!(ilu.l() * ilu.u(), dense, comp = abs, tol = 1e-12);
+
+    let b = DMatrix::from_fn(n, 2, |i, j| (i + j) as f64);
+    let x = ilu.solve(&b);
+    assert_matrix_eq!(&dense * x, b, comp = abs, tol = 1e-12);
+}
+
+#[test]
+fn ilu0_zero_pivot() {
+    // Missing diagonal entry
+    let mut coo = CooMatrix::new(2, 2);
+    coo.push(0, 0, 1.0);
+    coo.push(0, 1, 1.0);
+    coo.push(1, 0, 1.0);
+    let matrix = CsrMatrix::from(&coo);
+    assert_eq!(
+        CsrIlu0::factor(&matrix).unwrap_err(),
+        Ilu0Error::ZeroPivot(1)
+    );
+
+    // Diagonal entry becoming zero during the elimination
+    let dense = DMatrix::from_row_slice(2, 2, &[1.0, 1.0, 1.0, 1.0]);
+    let matrix = CsrMatrix::from(&dense);
+    assert_eq!(
+        CsrIlu0::factor(&matrix).unwrap_err(),
+        Ilu0Error::ZeroPivot(1)
+    );
+}
+
+#[test]
+fn ilu0_structurally_singular() {
+    let mut coo = CooMatrix::new(3, 3);
+    coo.push(0, 0, 1.0);
+    coo.push(2, 1, 1.0);
+    coo.push(2, 2, 1.0);
+    let matrix = CsrMatrix::from(&coo);
+    assert_eq!(
+        CsrIlu0::factor(&matrix).unwrap_err(),
+        Ilu0Error::EmptyRow(1)
+    );
+    assert_eq!(
+        CsrIlu0::factor(&matrix.transpose()).unwrap_err(),
+        Ilu0Error::EmptyColumn(1)
+    );
+}
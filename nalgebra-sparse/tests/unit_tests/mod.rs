@@ -3,6 +3,9 @@ mod convert_serial;
 mod coo;
 mod csc;
 mod csr;
+mod ic;
+mod ilu;
 mod ops;
+mod ordering;
 mod pattern;
 mod proptest;
@@ -1176,6 +1176,15 @@ proptest! {
         prop_assert_eq!(a.clone() * b.clone(), expected.clone());
     }
 
+    #[test]
+    fn csr_mul_dense_panics_on_dim_mismatch(a in csr_strategy(), b in dense_strategy()) {
+        prop_assume!(a.ncols() != b.nrows());
+
+        let result = catch_unwind(|| &a * &b);
+        prop_assert!(result.is_err(),
+            "The sparse-dense product succeeded despite mismatched inner dimensions");
+    }
+
     #[test]
     fn csc_mul_dense(
         // a and b have dimensions compatible for multiplication
@@ -0,0 +1,112 @@
+use crate::common::square_csr_strategy;
+use nalgebra::DMatrix;
+use nalgebra_sparse::coo::CooMatrix;
+use nalgebra_sparse::csr::CsrMatrix;
+use nalgebra_sparse::ordering::reverse_cuthill_mckee;
+
+use proptest::prelude::*;
+
+fn is_permutation(perm: &[usize], n: usize) -> bool {
+    let mut sorted = perm.to_vec();
+    sorted.sort_unstable();
+    sorted == (0..n).collect::<Vec<_>>()
+}
+
+fn bandwidth(matrix: &DMatrix<f64>) -> usize {
+    let mut bandwidth = 0;
+    for j in 0..matrix.ncols() {
+        for i in 0..matrix.nrows() {
+            if matrix[(i, j)] != 0.0 {
+                bandwidth = bandwidth.max(if i > j { i - j } else { j - i });
+            }
+        }
+    }
+    bandwidth
+}
+
+fn permute_symmetrically(csr: &CsrMatrix<f64>, perm: &[usize]) -> DMatrix<f64> {
+    let mut dense = DMatrix::from(csr);
+    dense.permute_rows(perm).unwrap();
+    dense.permute_columns(perm).unwrap();
+    dense
+}
+
+/// Laplacian of a `k x k` grid graph, whose nodes are labeled in a scrambled order.
+fn scrambled_grid_laplacian(k: usize) -> CsrMatrix<f64> {
+    let n = k * k;
+    // 37 is coprime with any n = k * k where k is not a multiple of 37
+    let label = |x: usize, y: usize| (37 * (x * k + y)) % n;
+
+    let mut coo = CooMatrix::new(n, n);
+    for x in 0..k {
+        for y in 0..k {
+            coo.push(label(x, y), label(x, y), 4.0);
+            if x + 1 < k {
+                coo.push(label(x, y), label(x + 1, y), -1.0);
+                coo.push(label(x + 1, y), label(x, y), -1.0);
+            }
+            if y + 1 < k {
+                coo.push(label(x, y), label(x, y + 1), -1.0);
+                coo.push(label(x, y + 1), label(x, y), -1.0);
+            }
+        }
+    }
+    CsrMatrix::from(&coo)
+}
+
+#[test]
+fn reverse_cuthill_mckee_path_graph() {
+    // A path graph 0 - 4 - 2 - 5 - 1 - 3, in which the labels are scrambled
+    let path = [0, 4, 2, 5, 1, 3];
+    let mut coo = CooMatrix::new(6, 6);
+    for w in path.windows(2) {
+        coo.push(w[0], w[1], 1.0);
+        coo.push(w[1], w[0], 1.0);
+    }
+    let csr = CsrMatrix::from(&coo);
+    assert_eq!(bandwidth(&DMatrix::from(&csr)), 4);
+
+    let perm = reverse_cuthill_mckee(csr.pattern());
+    assert!(is_permutation(&perm, 6));
+    assert_eq!(bandwidth(&permute_symmetrically(&csr, &perm)), 1);
+}
+
+#[test]
+fn reverse_cuthill_mckee_grid() {
+    let k = 10;
+    let csr = scrambled_grid_laplacian(k);
+    let perm = reverse_cuthill_mckee(csr.pattern());
+
+    assert!(is_permutation(&perm, k * k));
+    assert!(bandwidth(&DMatrix::from(&csr)) > 2 * k);
+    assert!(bandwidth(&permute_symmetrically(&csr, &perm)) <= k);
+}
+
+#[test]
+fn reverse_cuthill_mckee_disconnected_and_non_symmetric() {
+    // Two components {0, 3} and {1, 2, 4}, with an isolated node 5 and a non-symmetric pattern
+    let mut coo = CooMatrix::new(6, 6);
+    coo.push(0, 3, 1.0);
+    coo.push(1, 4, 1.0);
+    coo.push(2, 4, 1.0);
+    coo.push(2, 2, 1.0);
+    let csr = CsrMatrix::from(&coo);
+
+    let perm = reverse_cuthill_mckee(csr.pattern());
+    assert!(is_permutation(&perm, 6));
+    assert_eq!(bandwidth(&permute_symmetrically(&csr, &perm)), 1);
+}
+
+#[test]
+fn reverse_cuthill_mckee_empty() {
+    let csr = CsrMatrix::<f64>::zeros(0, 0);
+    assert!(reverse_cuthill_mckee(csr.pattern()).is_empty());
+}
+
+proptest! {
+    #[test]
+    fn reverse_cuthill_mckee_is_permutation(csr in square_csr_strategy()) {
+        let perm = reverse_cuthill_mckee(csr.pattern());
+        prop_assert!(is_permutation(&perm, csr.nrows()));
+    }
+}
@@ -1,4 +1,12 @@
 use nalgebra_sparse::pattern::{SparsityPattern, SparsityPatternFormatError};
+use nalgebra_sparse::proptest::sparsity_pattern;
+
+use proptest::prelude::*;
+
+use crate::assert_panics;
+use crate::common::{
+    sparsity_pattern_strategy, square_csr_strategy, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ,
+};
 
 #[test]
 fn sparsity_pattern_valid_data() {
@@ -152,3 +160,156 @@ fn sparsity_pattern_try_from_invalid_data() {
         assert_eq!(pattern, Err(SparsityPatternFormatError::DuplicateEntry));
     }
 }
+
+#[test]
+fn sparsity_pattern_find_empty_lanes() {
+    let offsets = vec![0, 2, 2, 5, 5];
+    let indices = vec![0, 5, 1, 2, 3];
+    let pattern = SparsityPattern::try_from_offsets_and_indices(4, 6, offsets, indices).unwrap();
+    assert_eq!(pattern.find_empty_lanes(), vec![1, 3]);
+    assert_eq!(pattern.transpose().find_empty_lanes(), vec![4]);
+
+    assert_eq!(
+        SparsityPattern::zeros(3, 2).find_empty_lanes(),
+        vec![0, 1, 2]
+    );
+    assert!(SparsityPattern::zeros(0, 2).find_empty_lanes().is_empty());
+}
+
+#[test]
+fn sparsity_pattern_transpose() {
+    let offsets = vec![0, 2, 2, 5];
+    let indices = vec![0, 5, 1, 2, 3];
+    let pattern = SparsityPattern::try_from_offsets_and_indices(3, 6, offsets, indices).unwrap();
+    let transposed = pattern.transpose();
+
+    assert_eq!(transposed.major_dim(), 6);
+    assert_eq!(transposed.minor_dim(), 3);
+    assert_eq!(transposed.major_offsets(), &[0, 1, 2, 3, 4, 4, 5]);
+    assert_eq!(transposed.minor_indices(), &[0, 2, 2, 2, 0]);
+}
+
+#[test]
+fn sparsity_pattern_symmetrize() {
+    let offsets = vec![0, 1, 3, 5];
+    let indices = vec![0, 0, 1, 0, 2];
+    let pattern = SparsityPattern::try_from_offsets_and_indices(3, 3, offsets, indices).unwrap();
+    let symmetrized = pattern.symmetrize();
+
+    assert_eq!(symmetrized.major_offsets(), &[0, 3, 5, 7]);
+    assert_eq!(symmetrized.minor_indices(), &[0, 1, 2, 0, 1, 0, 2]);
+
+    assert_panics!(SparsityPattern::zeros(3, 2).symmetrize());
+}
+
+#[test]
+fn sparsity_pattern_set_operations() {
+    let a =
+        SparsityPattern::try_from_offsets_and_indices(3, 4, vec![0, 2, 2, 5], vec![0, 3, 0, 1, 2])
+            .unwrap();
+    let b = SparsityPattern::try_from_offsets_and_indices(3, 4, vec![0, 1, 2, 4], vec![3, 1, 1, 3])
+        .unwrap();
+
+    let union = a.union(&b);
+    assert_eq!(union.major_offsets(), &[0, 2, 3, 7]);
+    assert_eq!(union.minor_indices(), &[0, 3, 1, 0, 1, 2, 3]);
+
+    let intersection = a.intersect(&b);
+    assert_eq!(intersection.major_offsets(), &[0, 1, 1, 2]);
+    assert_eq!(intersection.minor_indices(), &[3, 1]);
+
+    let difference = a.difference(&b);
+    assert_eq!(difference.major_offsets(), &[0, 1, 1, 3]);
+    assert_eq!(difference.minor_indices(), &[0, 0, 2]);
+    assert_eq!(b.difference(&a).minor_indices(), &[1, 3]);
+
+    let wrong_major = SparsityPattern::zeros(2, 4);
+    let wrong_minor = SparsityPattern::zeros(3, 5);
+    assert_panics!(a.union(&wrong_major));
+    assert_panics!(a.intersect(&wrong_major));
+    assert_panics!(a.intersect(&wrong_minor));
+    assert_panics!(a.difference(&wrong_major));
+    assert_panics!(a.difference(&wrong_minor));
+}
+
+/// Generates two patterns of identical dimensions.
+fn sparsity_pattern_pair_strategy() -> impl Strategy<Value = (SparsityPattern, SparsityPattern)> {
+    (PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM).prop_flat_map(|(major_dim, minor_dim)| {
+        let pattern = || sparsity_pattern(major_dim, minor_dim, PROPTEST_MAX_NNZ);
+        (pattern(), pattern())
+    })
+}
+
+proptest! {
+    #[test]
+    fn sparsity_pattern_transpose_agrees_with_entries(pattern in sparsity_pattern_strategy()) {
+        let transposed = pattern.transpose();
+
+        let mut expected: Vec<_> = pattern.entries().map(|(i, j)| (j, i)).collect();
+        expected.sort();
+        prop_assert_eq!(transposed.entries().collect::<Vec<_>>(), expected);
+
+        // The result must satisfy all the format invariants
+        let (offsets, indices) = transposed.clone().disassemble();
+        let validated = SparsityPattern::try_from_offsets_and_indices(
+            pattern.minor_dim(),
+            pattern.major_dim(),
+            offsets,
+            indices,
+        );
+        prop_assert_eq!(validated, Ok(transposed));
+    }
+
+    #[test]
+    fn sparsity_pattern_symmetrize_is_union_with_transpose(csr in square_csr_strategy()) {
+        let pattern = csr.pattern();
+
+        let mut expected: Vec<_> = pattern.entries()
+            .flat_map(|(i, j)| vec![(i, j), (j, i)])
+            .collect();
+        expected.sort();
+        expected.dedup();
+        prop_assert_eq!(pattern.symmetrize().entries().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn sparsity_pattern_set_operations_agree_with_entries((a, b) in sparsity_pattern_pair_strategy()) {
+        let entries_a: Vec<_> = a.entries().collect();
+        let entries_b: Vec<_> = b.entries().collect();
+
+        let mut expected_union: Vec<_> = entries_a.iter().chain(&entries_b).copied().collect();
+        expected_union.sort();
+        expected_union.dedup();
+        let expected_intersection: Vec<_> = entries_a.iter()
+            .filter(|entry| entries_b.contains(entry))
+            .copied()
+            .collect();
+        let expected_difference: Vec<_> = entries_a.iter()
+            .filter(|entry| !entries_b.contains(entry))
+            .copied()
+            .collect();
+
+        for (result, expected) in vec![
+            (a.union(&b), expected_union),
+            (a.intersect(&b), expected_intersection),
+            (a.difference(&b), expected_difference),
+        ] {
+            prop_assert_eq!(result.entries().collect::<Vec<_>>(), expected);
+
+            // The result must satisfy all the format invariants
+            let (offsets, indices) = result.clone().disassemble();
+            let validated = SparsityPattern::try_from_offsets_and_indices(
+                a.major_dim(),
+                a.minor_dim(),
+                offsets,
+                indices,
+            );
+            prop_assert_eq!(validated, Ok(result));
+        }
+    }
+
+    #[test]
+    fn sparsity_pattern_double_transpose_is_identity(pattern in sparsity_pattern_strategy()) {
+        prop_assert_eq!(pattern.transpose().transpose(), pattern);
+    }
+}
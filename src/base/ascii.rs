@@ -0,0 +1,139 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::base::{DMatrix, Scalar};
+
+/// An error returned when parsing a matrix from its textual representation fails.
+///
+/// The `row` and `column` are the 0-based indices of the matrix entry where parsing failed, and
+/// `line` is the 1-based number of the corresponding line of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseAsciiError {
+    /// An entry could not be parsed as a number, or is empty (e.g. between two consecutive
+    /// commas).
+    InvalidEntry {
+        /// The 1-based line number of the entry in the input.
+        line: usize,
+        /// The row index of the entry.
+        row: usize,
+        /// The column index of the entry.
+        column: usize,
+    },
+    /// A row does not have the same number of entries as the first row.
+    RaggedRow {
+        /// The 1-based line number of the row in the input.
+        line: usize,
+        /// The row index.
+        row: usize,
+        /// The number of entries of the first row.
+        expected_ncols: usize,
+        /// The number of entries found on this row.
+        found_ncols: usize,
+    },
+}
+
+impl fmt::Display for ParseAsciiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseAsciiError::InvalidEntry { line, row, column } => write!(
+                f,
+                "invalid matrix entry at row {}, column {} (line {})",
+                row, column, line
+            ),
+            ParseAsciiError::RaggedRow {
+                line,
+                row,
+                expected_ncols,
+                found_ncols,
+            } => write!(
+                f,
+                "row {} (line {}) has {} entries but the first row has {}",
+                row, line, found_ncols, expected_ncols
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseAsciiError {}
+
+impl<T: Scalar + FromStr> DMatrix<T> {
+    /// Parses a matrix from a string with one row per line.
+    ///
+    /// The entries of each row are separated by whitespaces and/or commas, and are parsed with
+    /// `T::from_str`. Blank lines are ignored, and the dimensions of the matrix are inferred from
+    /// the number of non-blank lines and the number of entries on the first of them. An empty
+    /// input yields a 0x0 matrix.
+    ///
+    /// This is meant for small hand-written matrices and test fixtures, not as a replacement
+    /// for a full CSV parser: quoting, headers and comments are not supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseAsciiError::InvalidEntry`] if an entry cannot be parsed or is empty, and
+    /// [`ParseAsciiError::RaggedRow`] if the rows do not all have the same number of entries.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, ParseAsciiError};
+    /// let m = DMatrix::<f64>::from_ascii("1.0, 2.0, 3.0
+    ///                                     4.0  5.0  6.0\n").unwrap();
+    /// assert_eq!(m, DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+    ///
+    /// let err = DMatrix::<f64>::from_ascii("1 2\n3 x").unwrap_err();
+    /// assert_eq!(err, ParseAsciiError::InvalidEntry { line: 2, row: 1, column: 1 });
+    /// ```
+    pub fn from_ascii(input: &str) -> Result<Self, ParseAsciiError> {
+        let mut data = Vec::new();
+        let mut nrows = 0;
+        let mut ncols = 0;
+
+        for (line_index, line) in input.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let line_number = line_index + 1;
+            let row_start = data.len();
+
+            for field in line.split(',') {
+                let mut tokens = field.split_whitespace().peekable();
+                if tokens.peek().is_none() {
+                    return Err(ParseAsciiError::InvalidEntry {
+                        line: line_number,
+                        row: nrows,
+                        column: data.len() - row_start,
+                    });
+                }
+
+                for token in tokens {
+                    let value = token.parse().map_err(|_| ParseAsciiError::InvalidEntry {
+                        line: line_number,
+                        row: nrows,
+                        column: data.len() - row_start,
+                    })?;
+                    data.push(value);
+                }
+            }
+
+            let found_ncols = data.len() - row_start;
+            if nrows == 0 {
+                ncols = found_ncols;
+            } else if found_ncols != ncols {
+                return Err(ParseAsciiError::RaggedRow {
+                    line: line_number,
+                    row: nrows,
+                    expected_ncols: ncols,
+                    found_ncols,
+                });
+            }
+
+            nrows += 1;
+        }
+
+        Ok(DMatrix::from_row_slice(nrows, ncols, &data))
+    }
+}
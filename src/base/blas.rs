@@ -5,6 +5,11 @@ use num::{One, Zero};
 use simba::scalar::{ClosedAdd, ClosedMul};
 #[cfg(feature = "std")]
 use std::mem;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use std::mem::size_of;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
 
 use crate::base::allocator::Allocator;
 use crate::base::constraint::{
@@ -12,6 +17,8 @@ use crate::base::constraint::{
 };
 use crate::base::dimension::{Const, Dim, Dynamic, U1, U2, U3, U4};
 use crate::base::storage::{Storage, StorageMut};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::SMatrix;
 use crate::base::{
     DVectorSlice, DefaultAllocator, Matrix, Scalar, SquareMatrix, Vector, VectorSlice,
 };
@@ -315,6 +322,22 @@ where
     }
 }
 
+/// The number of rows of the blocks of the left-hand side used by the generic `gemm`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+const GEMM_MC: usize = 64;
+/// The number of columns of the blocks of the left-hand side used by the generic `gemm`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+const GEMM_KC: usize = 256;
+/// The number of columns of the blocks of the right-hand side used by the generic `gemm`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+const GEMM_NC: usize = 512;
+/// The number of rows of the tiles accumulated in registers by the generic `gemm`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+const GEMM_MR: usize = 4;
+/// The number of columns of the tiles accumulated in registers by the generic `gemm`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+const GEMM_NR: usize = 2;
+
 /// # BLAS functions
 impl<T, D: Dim, S> Vector<T, D, S>
 where
@@ -828,6 +851,22 @@ where
     ///
     /// If `beta` is zero, `self` is never read.
     ///
+    /// # Performance
+    ///
+    /// With the `std` feature, if at least one of the matrices is dynamically-sized, the scalar
+    /// type is `f32` or `f64`, and the matrices have more than 5 rows and columns, the product is
+    /// computed by the cache-blocked and vectorized kernels of the `matrixmultiply` crate. These
+    /// kernels reorder the accumulation of the terms of each dot product, so their results may
+    /// differ by rounding errors from those of statically-sized matrices with the same entries.
+    ///
+    /// Otherwise, each component of `self` accumulates its terms in increasing order of the
+    /// inner index, the same way as [`gemv`](Self::gemv). With the `std` or `alloc` feature, if
+    /// `a` has more than 64 rows or 256 columns and the scalar type is at least 64 bits wide,
+    /// the product is computed block by block to make better use of the cache, and each tile of
+    /// `self` is accumulated in registers. The terms of each component are still added in the
+    /// same order, but without multiplying the partial sums by one in between, so for complex
+    /// numbers the sign of zero components may differ from that computed by `gemv`.
+    ///
     /// # Examples:
     ///
     /// ```
@@ -966,6 +1005,19 @@ where
             }
         }
 
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        {
+            let (nrows2, ncols2) = a.shape();
+
+            // Scalars narrower than 64 bits are faster with the vectorized column-by-column
+            // loop below. Threshold determined empirically.
+            if (nrows2 > GEMM_MC || ncols2 > GEMM_KC) && size_of::<T>() >= 8 {
+                // The matrices don't fit in the cache: multiply them block by block.
+                self.gemm_blocked(alpha, a, b, beta);
+                return;
+            }
+        }
+
         for j1 in 0..ncols1 {
             // TODO: avoid bound checks.
             self.column_mut(j1).gemv(
@@ -977,6 +1029,163 @@ where
         }
     }
 
+    /// Computes `self = alpha * a * b + beta * self` with a cache-blocked loop.
+    ///
+    /// The columns of `self` and `b` are split into blocks of `GEMM_NC` columns, the inner
+    /// dimension into blocks of `GEMM_KC` indices, and the rows of `self` and `a` into blocks of
+    /// `GEMM_MC` rows. Each block of `a` and `b` is copied into a contiguous buffer, where it is
+    /// reused for many tiles of `GEMM_MR × GEMM_NR` components of `self` accumulated in registers.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn gemm_blocked<R2: Dim, C2: Dim, R3: Dim, C3: Dim, SB, SC>(
+        &mut self,
+        alpha: T,
+        a: &Matrix<T, R2, C2, SB>,
+        b: &Matrix<T, R3, C3, SC>,
+        beta: T,
+    ) where
+        SB: Storage<T, R2, C2>,
+        SC: Storage<T, R3, C3>,
+    {
+        let (nrows1, ncols1) = self.shape();
+        let (nrows2, ncols2) = a.shape();
+        let (nrows3, ncols3) = b.shape();
+
+        assert_eq!(
+            ncols2, nrows3,
+            "gemm: dimensions mismatch for multiplication."
+        );
+        assert_eq!(
+            (nrows1, ncols1),
+            (nrows2, ncols3),
+            "gemm: dimensions mismatch for addition."
+        );
+
+        if ncols2 == 0 {
+            // NOTE: we can't just always multiply by beta
+            // because we documented the guaranty that `self` is
+            // never read if `beta` is zero.
+            if beta.is_zero() {
+                self.fill(T::zero());
+            } else {
+                *self *= beta;
+            }
+            return;
+        }
+
+        let mut packed_a = Vec::with_capacity(GEMM_MC * GEMM_KC);
+        let mut packed_b = Vec::with_capacity(GEMM_KC * GEMM_NC);
+
+        for jc in (0..ncols1).step_by(GEMM_NC) {
+            let nc = GEMM_NC.min(ncols1 - jc);
+
+            for pc in (0..ncols2).step_by(GEMM_KC) {
+                let kc = GEMM_KC.min(ncols2 - pc);
+
+                // Pack `b[pc..pc + kc, jc..jc + nc]` by panels of `GEMM_NR` columns, stored row
+                // by row and padded with zeros.
+                packed_b.clear();
+                for jr in (jc..jc + nc).step_by(GEMM_NR) {
+                    for p in pc..pc + kc {
+                        for j in jr..jr + GEMM_NR {
+                            if j < jc + nc {
+                                packed_b.push(b[(p, j)].inlined_clone());
+                            } else {
+                                packed_b.push(T::zero());
+                            }
+                        }
+                    }
+                }
+
+                for ic in (0..nrows1).step_by(GEMM_MC) {
+                    let mc = GEMM_MC.min(nrows1 - ic);
+
+                    // Pack `alpha * a[ic..ic + mc, pc..pc + kc]` by panels of `GEMM_MR` rows,
+                    // stored column by column and padded with zeros.
+                    packed_a.clear();
+                    for ir in (ic..ic + mc).step_by(GEMM_MR) {
+                        for p in pc..pc + kc {
+                            for i in ir..ir + GEMM_MR {
+                                if i < ic + mc {
+                                    packed_a
+                                        .push(alpha.inlined_clone() * a[(i, p)].inlined_clone());
+                                } else {
+                                    packed_a.push(T::zero());
+                                }
+                            }
+                        }
+                    }
+
+                    let b_panels = packed_b.chunks_exact(GEMM_NR * kc);
+                    for (jr, b_panel) in (jc..jc + nc).step_by(GEMM_NR).zip(b_panels) {
+                        let a_panels = packed_a.chunks_exact(GEMM_MR * kc);
+                        for (ir, a_panel) in (ic..ic + mc).step_by(GEMM_MR).zip(a_panels) {
+                            let shape = (GEMM_MR.min(ic + mc - ir), GEMM_NR.min(jc + nc - jr));
+                            let beta = if pc == 0 { Some(&beta) } else { None };
+                            self.gemm_tile(a_panel, b_panel, beta, (ir, jr), shape);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Adds the product of a packed panel of `alpha * a` and a packed panel of `b` to the tile of
+    /// `self` with the given start and shape.
+    ///
+    /// If `beta` is `Some`, the panels start at the first inner index, and the tile is scaled by
+    /// `beta` (and not read if `beta` is zero) the same way [`gemv`](Vector::gemv) does. The terms
+    /// of each component are accumulated in increasing order of the inner index.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[inline(always)]
+    fn gemm_tile(
+        &mut self,
+        a_panel: &[T],
+        b_panel: &[T],
+        beta: Option<&T>,
+        (ir, jr): (usize, usize),
+        (mr, nr): (usize, usize),
+    ) {
+        let mut a_cols = a_panel.chunks_exact(GEMM_MR);
+        let mut b_rows = b_panel.chunks_exact(GEMM_NR);
+        let in_tile = |i: usize, j: usize| i < mr && j < nr;
+
+        let mut acc = match beta {
+            Some(beta) => {
+                let (a_col, b_row) = (a_cols.next().unwrap(), b_rows.next().unwrap());
+                SMatrix::<T, GEMM_MR, GEMM_NR>::from_fn(|i, j| {
+                    let term = a_col[i].inlined_clone() * b_row[j].inlined_clone();
+
+                    if in_tile(i, j) && !beta.is_zero() {
+                        term + beta.inlined_clone() * self[(ir + i, jr + j)].inlined_clone()
+                    } else {
+                        term
+                    }
+                })
+            }
+            None => SMatrix::<T, GEMM_MR, GEMM_NR>::from_fn(|i, j| {
+                if in_tile(i, j) {
+                    self[(ir + i, jr + j)].inlined_clone()
+                } else {
+                    T::zero()
+                }
+            }),
+        };
+
+        for (a_col, b_row) in a_cols.zip(b_rows) {
+            for (acc_col, b) in acc.as_mut_slice().chunks_exact_mut(GEMM_MR).zip(b_row) {
+                for (acc, a) in acc_col.iter_mut().zip(a_col) {
+                    *acc += a.inlined_clone() * b.inlined_clone();
+                }
+            }
+        }
+
+        for j in 0..nr {
+            for i in 0..mr {
+                self[(ir + i, jr + j)] = acc[(i, j)].inlined_clone();
+            }
+        }
+    }
+
     /// Computes `self = alpha * a.transpose() * b + beta * self`, where `a, b, self` are matrices.
     /// `alpha` and `beta` are scalar.
     ///
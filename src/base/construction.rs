@@ -23,7 +23,7 @@ use crate::base::allocator::Allocator;
 use crate::base::dimension::{Dim, DimName, Dynamic, ToTypenum};
 use crate::base::storage::Storage;
 use crate::base::{
-    ArrayStorage, Const, DefaultAllocator, Matrix, OMatrix, OVector, Scalar, Unit, Vector,
+    ArrayStorage, Const, DefaultAllocator, Matrix, OMatrix, OVector, SMatrix, Scalar, Unit, Vector,
 };
 
 /// When "no_unsound_assume_init" is enabled, expands to `unimplemented!()` instead of `new_uninitialized_generic().assume_init()`.
@@ -56,6 +56,11 @@ where
     /// Creates a new uninitialized matrix.
     ///
     /// # Safety
+    /// The components of the returned matrix are uninitialized. Each of them must be written
+    /// exactly once, without reading or dropping its previous value (e.g. with
+    /// [`ptr::write`](core::ptr::write)), before calling `assume_init` on the result. See
+    /// [`new_uninitialized`](Self::new_uninitialized) for an example.
+    ///
     /// If the matrix has a compile-time dimension, this panics
     /// if `nrows != R::to_usize()` or `ncols != C::to_usize()`.
     #[inline]
@@ -130,6 +135,12 @@ where
 
     /// Creates a matrix filled with the results of a function applied to each of its component
     /// coordinates.
+    ///
+    /// The function is called exactly once per component, with its `(row, column)` index, in
+    /// column-major order: `f(0, 0)`, `f(1, 0)`, …, `f(nrows - 1, 0)`, `f(0, 1)`, … This is the
+    /// order of the matrix data storage, so a stateful closure (e.g. one drawing from a random
+    /// number generator) fills the matrix like
+    /// [`from_iterator_generic`](Self::from_iterator_generic) would.
     #[inline]
     pub fn from_fn_generic<F>(nrows: R, ncols: C, mut f: F) -> Self
     where
@@ -160,8 +171,22 @@ where
 
     /// Creates a new matrix with its diagonal filled with copies of `elt`.
     ///
-    /// If the matrix is not square, the largest square submatrix starting at index `(0, 0)` is set
-    /// to the identity matrix. All other entries are set to zero.
+    /// The matrix may be rectangular: the entries `(i, i)` for `i` in `0..min(nrows, ncols)` are
+    /// set to `elt`, and all the other entries are set to zero. This is for example the shape of
+    /// the `Σ` factor of a singular value decomposition.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Const, DMatrix, Dynamic, Matrix2x3};
+    /// let m = Matrix2x3::from_diagonal_element_generic(Const::<2>, Const::<3>, 5.0);
+    /// assert_eq!(m, Matrix2x3::new(5.0, 0.0, 0.0,
+    ///                              0.0, 5.0, 0.0));
+    ///
+    /// let dm = DMatrix::from_diagonal_element_generic(Dynamic::new(3), Dynamic::new(2), 5.0);
+    /// assert_eq!(dm, DMatrix::from_row_slice(3, 2, &[5.0, 0.0,
+    ///                                                0.0, 5.0,
+    ///                                                0.0, 0.0]));
+    /// ```
     #[inline]
     pub fn from_diagonal_element_generic(nrows: R, ncols: C, elt: T) -> Self
     where
@@ -378,6 +403,32 @@ where
 macro_rules! impl_constructors(
     ($($Dims: ty),*; $(=> $DimIdent: ident: $DimBound: ident),*; $($gargs: expr),*; $($args: ident),*) => {
         /// Creates a new uninitialized matrix or vector.
+        ///
+        /// This avoids initializing the components twice when they are all written right
+        /// after the allocation. Note that [`from_fn`](Self::from_fn) and
+        /// [`from_iterator`](Self::from_iterator) don't initialize the components twice either,
+        /// and should be preferred when they fit the use-case.
+        ///
+        /// # Safety
+        /// The components of the returned matrix are uninitialized. Each of them must be written
+        /// exactly once, without reading or dropping its previous value (e.g. with
+        /// [`ptr::write`](core::ptr::write)), before calling `assume_init` on the result. The
+        /// components are stored in column-major order.
+        ///
+        /// # Example
+        /// ```
+        /// # use nalgebra::DMatrix;
+        /// let m = unsafe {
+        ///     let mut m = DMatrix::<f64>::new_uninitialized(2, 3);
+        ///     let data = (*m.as_mut_ptr()).as_mut_ptr();
+        ///     for k in 0..6 {
+        ///         data.add(k).write(k as f64);
+        ///     }
+        ///     m.assume_init()
+        /// };
+        ///
+        /// assert_eq!(m, DMatrix::from_column_slice(2, 3, &[0.0, 1.0, 2.0, 3.0, 4.0, 5.0]));
+        /// ```
         #[inline]
         pub unsafe fn new_uninitialized($($args: usize),*) -> mem::MaybeUninit<Self> {
             Self::new_uninitialized_generic($($gargs),*)
@@ -463,7 +514,13 @@ macro_rules! impl_constructors(
 
         /// Creates a matrix or vector with all its elements filled by an iterator.
         ///
-        /// The output matrix is filled column-by-column.
+        /// The output matrix is filled column-by-column, i.e., in column-major order. Exactly
+        /// as many elements as the matrix contains are taken from the iterator: any remaining
+        /// element is left unconsumed. No intermediate `Vec` is allocated.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the iterator yields fewer elements than the matrix contains.
         ///
         /// # Example
         /// ```
@@ -483,6 +540,12 @@ macro_rules! impl_constructors(
         ///         m.m21 == 1 && m.m22 == 3 && m.m23 == 5);
         /// assert!(dm[(0, 0)] == 0 && dm[(0, 1)] == 2 && dm[(0, 2)] == 4 &&
         ///         dm[(1, 0)] == 1 && dm[(1, 1)] == 3 && dm[(1, 2)] == 5);
+        ///
+        /// // Only the required number of elements are consumed.
+        /// let mut naturals = 0..;
+        /// let dv = DVector::from_iterator(3, &mut naturals);
+        /// assert_eq!(dv, DVector::from_vec(vec![0, 1, 2]));
+        /// assert_eq!(naturals.next(), Some(3));
         /// ```
         #[inline]
         pub fn from_iterator<I>($($args: usize,)* iter: I) -> Self
@@ -493,6 +556,9 @@ macro_rules! impl_constructors(
         /// Creates a matrix or vector filled with the results of a function applied to each of its
         /// component coordinates.
         ///
+        /// The function is called once per component, in column-major order. See
+        /// [`from_fn_generic`](Self::from_fn_generic) for details.
+        ///
         /// # Example
         /// ```
         /// # use nalgebra::{Matrix2x3, Vector3, DVector, DMatrix};
@@ -625,6 +691,34 @@ where
     ); // Arguments for non-generic constructors.
 }
 
+/// # Constructors of statically-sized matrices from nested arrays
+impl<T: Scalar, const R: usize, const C: usize> SMatrix<T, R, C> {
+    /// Creates a matrix from an array of its rows.
+    ///
+    /// Each inner array is a row of the matrix, so a nested array literal reads exactly like the
+    /// matrix it creates. Note that this differs from the `From<[[T; R]; C]>` conversion, which
+    /// interprets each inner array as a column, following the column-major storage of the matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, SMatrix};
+    /// let m = SMatrix::from_row_arrays([
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ]);
+    ///
+    /// assert_eq!(m, Matrix2x3::new(1, 2, 3,
+    ///                              4, 5, 6));
+    ///
+    /// // The `From` conversion reads the same array as a list of columns instead.
+    /// assert_eq!(SMatrix::from([[1, 2, 3], [4, 5, 6]]), m.transpose());
+    /// ```
+    #[inline]
+    pub fn from_row_arrays(rows: [[T; C]; R]) -> Self {
+        SMatrix::<T, C, R>::from(rows).transpose()
+    }
+}
+
 /// # Constructors of matrices with a dynamic number of columns
 impl<T: Scalar, R: DimName> OMatrix<T, R, Dynamic>
 where
@@ -656,6 +750,45 @@ where
                    ;
                    Dynamic::new(nrows), Dynamic::new(ncols);
                    nrows, ncols);
+
+    /// Creates a block-diagonal matrix with the given blocks placed along its diagonal, and
+    /// zeros elsewhere.
+    ///
+    /// The blocks do not need to be square. The number of rows (resp. columns) of the result is
+    /// the sum of the number of rows (resp. columns) of all the blocks. If `blocks` is empty, a
+    /// `0x0` matrix is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let a = DMatrix::from_row_slice(2, 2, &[1, 2,
+    ///                                         3, 4]);
+    /// let b = DMatrix::from_row_slice(1, 3, &[5, 6, 7]);
+    /// let m = DMatrix::block_diagonal(&[&a, &b]);
+    ///
+    /// assert_eq!(m, DMatrix::from_row_slice(3, 5, &[1, 2, 0, 0, 0,
+    ///                                               3, 4, 0, 0, 0,
+    ///                                               0, 0, 5, 6, 7]));
+    /// ```
+    pub fn block_diagonal(blocks: &[&Self]) -> Self
+    where
+        T: Zero,
+    {
+        let nrows = blocks.iter().map(|b| b.nrows()).sum();
+        let ncols = blocks.iter().map(|b| b.ncols()).sum();
+        let mut res = Self::zeros(nrows, ncols);
+
+        let (mut i, mut j) = (0, 0);
+        for block in blocks {
+            let (block_nrows, block_ncols) = block.shape();
+            res.slice_mut((i, j), (block_nrows, block_ncols))
+                .copy_from(block);
+            i += block_nrows;
+            j += block_ncols;
+        }
+
+        res
+    }
 }
 
 /*
@@ -179,6 +179,10 @@ impl_from_into_asref_1D!(
     (U13, U1) => 13; (U14, U1) => 14; (U15, U1) => 15; (U16, U1) => 16;
 );
 
+/// Creates a matrix from an array of its columns.
+///
+/// Each inner array is a column of the matrix, matching its column-major storage. Use
+/// [`SMatrix::from_row_arrays`] to build a matrix from an array of its rows instead.
 impl<T: Scalar, const R: usize, const C: usize> From<[[T; R]; C]> for SMatrix<T, R, C> {
     #[inline]
     fn from(arr: [[T; R]; C]) -> Self {
@@ -89,15 +89,31 @@ impl<T: Scalar, C: Dim> Allocator<T, Dynamic, C> for DefaultAllocator {
         ncols: C,
         iter: I,
     ) -> Self::Buffer {
-        let it = iter.into_iter();
-        let res: Vec<T> = it.collect();
-        assert!(res.len() == nrows.value() * ncols.value(),
-                "Allocation from iterator error: the iterator did not yield the correct number of elements.");
-
+        let res = vec_from_iterator(nrows.value() * ncols.value(), iter);
         VecStorage::new(nrows, ncols, res)
     }
 }
 
+/// Collects exactly `length` elements of `iter` into a `Vec`.
+///
+/// Iterators that already have the exact length, like `vec::IntoIter`, are collected directly so
+/// that the standard library can reuse their allocation instead of copying them.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn vec_from_iterator<T, I: IntoIterator<Item = T>>(length: usize, iter: I) -> Vec<T> {
+    let it = iter.into_iter();
+    let res: Vec<T> = if it.size_hint() == (length, Some(length)) {
+        it.collect()
+    } else {
+        let mut res = Vec::with_capacity(length);
+        res.extend(it.take(length));
+        res
+    };
+    assert!(res.len() == length,
+            "Allocation from iterator error: the iterator did not yield the correct number of elements.");
+
+    res
+}
+
 // Static - Dynamic
 #[cfg(any(feature = "std", feature = "alloc"))]
 impl<T: Scalar, R: DimName> Allocator<T, R, Dynamic> for DefaultAllocator {
@@ -119,11 +135,7 @@ impl<T: Scalar, R: DimName> Allocator<T, R, Dynamic> for DefaultAllocator {
         ncols: Dynamic,
         iter: I,
     ) -> Self::Buffer {
-        let it = iter.into_iter();
-        let res: Vec<T> = it.collect();
-        assert!(res.len() == nrows.value() * ncols.value(),
-                "Allocation from iterator error: the iterator did not yield the correct number of elements.");
-
+        let res = vec_from_iterator(nrows.value() * ncols.value(), iter);
         VecStorage::new(nrows, ncols, res)
     }
 }
@@ -1,19 +1,98 @@
 use num::{One, Zero};
 use std::cmp;
+use std::fmt;
 #[cfg(any(feature = "std", feature = "alloc"))]
 use std::iter::ExactSizeIterator;
 #[cfg(any(feature = "std", feature = "alloc"))]
 use std::mem;
 use std::ptr;
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
 use crate::base::allocator::{Allocator, Reallocator};
 use crate::base::constraint::{DimEq, SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
 #[cfg(any(feature = "std", feature = "alloc"))]
 use crate::base::dimension::Dynamic;
 use crate::base::dimension::{Const, Dim, DimAdd, DimDiff, DimMin, DimMinimum, DimSub, DimSum, U1};
 use crate::base::storage::{ContiguousStorageMut, ReshapableStorage, Storage, StorageMut};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::OVector;
 use crate::base::{DefaultAllocator, Matrix, OMatrix, RowVector, Scalar, Vector};
 
+/// An error returned by [`Matrix::permute_rows`] and [`Matrix::permute_columns`] when the given
+/// indices are not a permutation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermutationError {
+    /// The permutation does not have as many indices as there are rows (or columns) to permute.
+    WrongLength {
+        /// The number of rows (or columns) to permute.
+        expected: usize,
+        /// The number of indices of the permutation.
+        found: usize,
+    },
+    /// An index is not smaller than the number of rows (or columns) to permute.
+    IndexOutOfBounds {
+        /// The offending index.
+        index: usize,
+        /// The number of rows (or columns) to permute.
+        len: usize,
+    },
+    /// An index appears more than once in the permutation.
+    DuplicateIndex {
+        /// The repeated index.
+        index: usize,
+    },
+}
+
+impl fmt::Display for PermutationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PermutationError::WrongLength { expected, found } => write!(
+                f,
+                "the permutation has {} indices but {} are expected",
+                found, expected
+            ),
+            PermutationError::IndexOutOfBounds { index, len } => write!(
+                f,
+                "the permutation index {} is out of bounds for length {}",
+                index, len
+            ),
+            PermutationError::DuplicateIndex { index } => {
+                write!(f, "the permutation index {} appears more than once", index)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PermutationError {}
+
+/// Checks that `perm` is a permutation of `0..n`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn check_permutation(perm: &[usize], n: usize) -> Result<(), PermutationError> {
+    if perm.len() != n {
+        return Err(PermutationError::WrongLength {
+            expected: n,
+            found: perm.len(),
+        });
+    }
+
+    let mut seen = Vec::new();
+    seen.resize(n, false);
+    for &index in perm {
+        if index >= n {
+            return Err(PermutationError::IndexOutOfBounds { index, len: n });
+        }
+        if seen[index] {
+            return Err(PermutationError::DuplicateIndex { index });
+        }
+        seen[index] = true;
+    }
+
+    Ok(())
+}
+
 /// # Rows and columns extraction
 impl<T: Scalar + Zero, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     /// Extracts the upper triangular part of this matrix (including the diagonal).
@@ -42,7 +121,91 @@ impl<T: Scalar + Zero, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         res
     }
 
+    /// Extracts the strictly upper triangular part of this matrix (excluding the diagonal).
+    ///
+    /// Together with [`diagonal_matrix`](Self::diagonal_matrix) and
+    /// [`strictly_lower_triangle`](Self::strictly_lower_triangle), this splits the matrix into
+    /// three parts adding up to `self`, as used by stationary iterative methods like Jacobi or
+    /// Gauss-Seidel. This also works for rectangular matrices.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    /// assert_eq!(m.strictly_upper_triangle(), Matrix2x3::new(0, 2, 3,
+    ///                                                        0, 0, 6));
+    /// assert_eq!(m.diagonal_matrix(), Matrix2x3::new(1, 0, 0,
+    ///                                                0, 5, 0));
+    /// assert_eq!(m.strictly_lower_triangle(), Matrix2x3::new(0, 0, 0,
+    ///                                                        4, 0, 0));
+    /// assert_eq!(
+    ///     m.strictly_lower_triangle() + m.diagonal_matrix() + m.strictly_upper_triangle(),
+    ///     m
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn strictly_upper_triangle(&self) -> OMatrix<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        let mut res = self.clone_owned();
+        res.fill_lower_triangle(T::zero(), 0);
+
+        res
+    }
+
+    /// Extracts the strictly lower triangular part of this matrix (excluding the diagonal).
+    ///
+    /// See [`strictly_upper_triangle`](Self::strictly_upper_triangle) for an example.
+    #[inline]
+    #[must_use]
+    pub fn strictly_lower_triangle(&self) -> OMatrix<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        let mut res = self.clone_owned();
+        res.fill_upper_triangle(T::zero(), 0);
+
+        res
+    }
+
+    /// Extracts the diagonal of this matrix as a matrix with the same shape as `self`, with
+    /// zeros everywhere else.
+    ///
+    /// Unlike `Matrix::from_diagonal`, this also works for rectangular matrices. See
+    /// [`strictly_upper_triangle`](Self::strictly_upper_triangle) for an example.
+    #[inline]
+    #[must_use]
+    pub fn diagonal_matrix(&self) -> OMatrix<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        let mut res = self.clone_owned();
+        res.fill_lower_triangle(T::zero(), 1);
+        res.fill_upper_triangle(T::zero(), 1);
+
+        res
+    }
+
     /// Creates a new matrix by extracting the given set of rows from `self`.
+    ///
+    /// The rows are copied in the order of `irows`, which may contain repeated indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the indices is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix3x2};
+    /// let m = Matrix3x2::new(1, 2,
+    ///                        3, 4,
+    ///                        5, 6);
+    /// let selected = m.select_rows(&[2, 0, 2]);
+    /// assert_eq!(selected, DMatrix::from_row_slice(3, 2, &[5, 6, 1, 2, 5, 6]));
+    /// ```
     #[cfg(any(feature = "std", feature = "alloc"))]
     #[must_use]
     pub fn select_rows<'a, I>(&self, irows: I) -> OMatrix<T, Dynamic, C>
@@ -80,6 +243,21 @@ impl<T: Scalar + Zero, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     }
 
     /// Creates a new matrix by extracting the given set of columns from `self`.
+    ///
+    /// The columns are copied in the order of `icols`, which may contain repeated indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the indices is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix2x3};
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    /// let selected = m.select_columns(&[1, 1, 0]);
+    /// assert_eq!(selected, DMatrix::from_row_slice(2, 3, &[2, 2, 1, 5, 5, 4]));
+    /// ```
     #[cfg(any(feature = "std", feature = "alloc"))]
     #[must_use]
     pub fn select_columns<'a, I>(&self, icols: I) -> OMatrix<T, R, Dynamic>
@@ -95,6 +273,7 @@ impl<T: Scalar + Zero, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         };
 
         for (destination, source) in icols.enumerate() {
+            assert!(*source < self.ncols(), "Column index out of bounds.");
             res.column_mut(destination).copy_from(&self.column(*source))
         }
 
@@ -105,6 +284,22 @@ impl<T: Scalar + Zero, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
 /// # Set rows, columns, and diagonal
 impl<T: Scalar, R: Dim, C: Dim, S: StorageMut<T, R, C>> Matrix<T, R, C, S> {
     /// Fills the diagonal of this matrix with the content of the given vector.
+    ///
+    /// The length of `diag` must be equal to the minimum of the number of rows and columns of
+    /// `self`. The off-diagonal elements are left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    /// let mut m = Matrix2x3::repeat(1);
+    /// m.set_diagonal(&Vector2::new(5, 6));
+    /// assert_eq!(m, Matrix2x3::new(5, 1, 1,
+    ///                              1, 6, 1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `diag` does not match the length of the diagonal of `self`.
     #[inline]
     pub fn set_diagonal<R2: Dim, S2>(&mut self, diag: &Vector<T, R2, S2>)
     where
@@ -178,6 +373,18 @@ impl<T: Scalar, R: Dim, C: Dim, S: StorageMut<T, R, C>> Matrix<T, R, C, S> {
     }
 
     /// Sets all the diagonal elements of this matrix to `val`.
+    ///
+    /// The off-diagonal elements are left unchanged. See also [`set_diagonal`](Self::set_diagonal)
+    /// for setting the diagonal elements from a vector.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let mut m = Matrix2x3::repeat(1);
+    /// m.fill_diagonal(0);
+    /// assert_eq!(m, Matrix2x3::new(0, 1, 1,
+    ///                              1, 0, 1));
+    /// ```
     #[inline]
     pub fn fill_diagonal(&mut self, val: T) {
         let (nrows, ncols) = self.shape();
@@ -303,6 +510,90 @@ impl<T: Scalar, R: Dim, C: Dim, S: StorageMut<T, R, C>> Matrix<T, R, C, S> {
         }
         // Otherwise do nothing.
     }
+
+    /// Permutes the rows of this matrix in-place, such that the `i`-th row of the result is the
+    /// `perm[i]`-th row of the original matrix.
+    ///
+    /// The permutation is applied by following its cycles, so no copy of the matrix is made.
+    /// Returns an error, and leaves the matrix unchanged, if `perm` is not a permutation of
+    /// `0..self.nrows()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix3x2, PermutationError};
+    /// let mut m = Matrix3x2::new(1, 2,
+    ///                            3, 4,
+    ///                            5, 6);
+    /// assert_eq!(m.permute_rows(&[2, 0, 1]), Ok(()));
+    /// assert_eq!(m, Matrix3x2::new(5, 6,
+    ///                              1, 2,
+    ///                              3, 4));
+    ///
+    /// // Not a permutation: the matrix is left untouched.
+    /// assert_eq!(
+    ///     m.permute_rows(&[0, 0, 1]),
+    ///     Err(PermutationError::DuplicateIndex { index: 0 })
+    /// );
+    /// assert_eq!(m, Matrix3x2::new(5, 6,
+    ///                              1, 2,
+    ///                              3, 4));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn permute_rows(&mut self, perm: &[usize]) -> Result<(), PermutationError> {
+        check_permutation(perm, self.nrows())?;
+        Self::apply_permutation(perm, |i, j| self.swap_rows(i, j));
+        Ok(())
+    }
+
+    /// Permutes the columns of this matrix in-place, such that the `i`-th column of the result
+    /// is the `perm[i]`-th column of the original matrix.
+    ///
+    /// The permutation is applied by following its cycles, so no copy of the matrix is made.
+    /// Returns an error, and leaves the matrix unchanged, if `perm` is not a permutation of
+    /// `0..self.ncols()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, PermutationError};
+    /// let mut m = Matrix2x3::new(1, 2, 3,
+    ///                            4, 5, 6);
+    /// assert_eq!(m.permute_columns(&[1, 2, 0]), Ok(()));
+    /// assert_eq!(m, Matrix2x3::new(2, 3, 1,
+    ///                              5, 6, 4));
+    ///
+    /// assert_eq!(
+    ///     m.permute_columns(&[1, 3, 0]),
+    ///     Err(PermutationError::IndexOutOfBounds { index: 3, len: 3 })
+    /// );
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn permute_columns(&mut self, perm: &[usize]) -> Result<(), PermutationError> {
+        check_permutation(perm, self.ncols())?;
+        Self::apply_permutation(perm, |i, j| self.swap_columns(i, j));
+        Ok(())
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn apply_permutation(perm: &[usize], mut swap: impl FnMut(usize, usize)) {
+        let n = perm.len();
+        let mut visited = Vec::new();
+        visited.resize(n, false);
+
+        // Follow each cycle `start -> perm[start] -> perm[perm[start]] -> ... -> start`.
+        for start in 0..n {
+            if visited[start] {
+                continue;
+            }
+
+            visited[start] = true;
+            let mut i = start;
+            while perm[i] != start {
+                swap(i, perm[i]);
+                i = perm[i];
+                visited[i] = true;
+            }
+        }
+    }
 }
 
 /*
@@ -318,6 +609,14 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
      *
      */
     /// Removes the `i`-th column from this matrix.
+    ///
+    /// The columns at indices `i + 1..` are shifted to the left. This consumes `self`; clone it
+    /// first to keep the original matrix. See [`remove_columns_at`](Self::remove_columns_at) to
+    /// remove several columns at arbitrary indices at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= self.ncols()`.
     #[inline]
     pub fn remove_column(self, i: usize) -> OMatrix<T, R, DimDiff<C, U1>>
     where
@@ -327,7 +626,20 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         self.remove_fixed_columns::<1>(i)
     }
 
-    /// Removes all columns in `indices`   
+    /// Removes all columns in `indices`.
+    ///
+    /// The remaining columns keep their relative order. `indices` does not need to be sorted,
+    /// and an index appearing several times only removes its column once.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::from_row_slice(2, 4, &[1, 2, 3, 4,
+    ///                                         5, 6, 7, 8]);
+    /// let expected = DMatrix::from_row_slice(2, 2, &[2, 4,
+    ///                                                6, 8]);
+    /// assert_eq!(m.remove_columns_at(&[2, 0]), expected);
+    /// ```
     #[cfg(any(feature = "std", feature = "alloc"))]
     pub fn remove_columns_at(self, indices: &[usize]) -> OMatrix<T, R, Dynamic>
     where
@@ -361,7 +673,22 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         }
     }
 
-    /// Removes all rows in `indices`   
+    /// Removes all rows in `indices`.
+    ///
+    /// The remaining rows keep their relative order. `indices` does not need to be sorted, and an
+    /// index appearing several times only removes its row once.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::from_row_slice(4, 2, &[1, 2,
+    ///                                         3, 4,
+    ///                                         5, 6,
+    ///                                         7, 8]);
+    /// let expected = DMatrix::from_row_slice(2, 2, &[3, 4,
+    ///                                                7, 8]);
+    /// assert_eq!(m.remove_rows_at(&[2, 0]), expected);
+    /// ```
     #[cfg(any(feature = "std", feature = "alloc"))]
     pub fn remove_rows_at(self, indices: &[usize]) -> OMatrix<T, Dynamic, C>
     where
@@ -370,6 +697,8 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     {
         let mut m = self.into_owned();
         let (nrows, ncols) = m.data.shape();
+        // Computed separately since the loop below does not run on matrices without columns.
+        let nremoved = (0..nrows.value()).filter(|i| indices.contains(i)).count();
         let mut offset: usize = 0;
         let mut target: usize = 0;
         while offset + target < nrows.value() * ncols.value() {
@@ -388,7 +717,7 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
 
         unsafe {
             Matrix::from_data(DefaultAllocator::reallocate_copy(
-                nrows.sub(Dynamic::from_usize(offset / ncols.value())),
+                nrows.sub(Dynamic::from_usize(nremoved)),
                 ncols,
                 m.data,
             ))
@@ -469,6 +798,14 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
      *
      */
     /// Removes the `i`-th row from this matrix.
+    ///
+    /// The rows at indices `i + 1..` are shifted up. This consumes `self`; clone it first to keep
+    /// the original matrix. See [`remove_rows_at`](Self::remove_rows_at) to remove several rows
+    /// at arbitrary indices at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= self.nrows()`.
     #[inline]
     pub fn remove_row(self, i: usize) -> OMatrix<T, DimDiff<R, U1>, C>
     where
@@ -547,6 +884,28 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
      *
      */
     /// Inserts a column filled with `val` at the `i-th` position.
+    ///
+    /// The columns at indices `i..` are shifted to the right, and `i == self.ncols()` appends the
+    /// new column. This consumes `self`; clone it first to keep the original matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i > self.ncols()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix2x3};
+    /// let m = DMatrix::from_row_slice(2, 2, &[1, 2,
+    ///                                         3, 4]);
+    /// let expected = DMatrix::from_row_slice(2, 3, &[1, 0, 2,
+    ///                                                3, 0, 4]);
+    /// assert_eq!(m.clone().insert_column(1, 0), expected);
+    ///
+    /// // The dimension of statically-sized matrices grows as well.
+    /// let m = m.fixed_slice::<2, 2>(0, 0).insert_column(2, 5);
+    /// assert_eq!(m, Matrix2x3::new(1, 2, 5,
+    ///                              3, 4, 5));
+    /// ```
     #[inline]
     pub fn insert_column(self, i: usize, val: T) -> OMatrix<T, R, DimSum<C, U1>>
     where
@@ -600,6 +959,8 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         C: DimAdd<D>,
         DefaultAllocator: Reallocator<T, R, C, R, DimSum<C, D>>,
     {
+        assert!(i <= self.ncols(), "Column insertion index out of range.");
+
         let m = self.into_owned();
         let (nrows, ncols) = m.data.shape();
         let mut res = Matrix::from_data(DefaultAllocator::reallocate_copy(
@@ -608,8 +969,6 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
             m.data,
         ));
 
-        assert!(i <= ncols.value(), "Column insertion index out of range.");
-
         if ninsert.value() != 0 && i != ncols.value() {
             let ptr_in = res.data.ptr().add(i * nrows.value());
             let ptr_out = res
@@ -629,6 +988,24 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
      *
      */
     /// Inserts a row filled with `val` at the `i-th` position.
+    ///
+    /// The rows at indices `i..` are shifted down, and `i == self.nrows()` appends the new row.
+    /// This consumes `self`; clone it first to keep the original matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i > self.nrows()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::from_row_slice(2, 2, &[1, 2,
+    ///                                         3, 4]);
+    /// let expected = DMatrix::from_row_slice(3, 2, &[1, 2,
+    ///                                                9, 9,
+    ///                                                3, 4]);
+    /// assert_eq!(m.insert_row(1, 9), expected);
+    /// ```
     #[inline]
     pub fn insert_row(self, i: usize, val: T) -> OMatrix<T, DimSum<R, U1>, C>
     where
@@ -684,6 +1061,8 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         R: DimAdd<D>,
         DefaultAllocator: Reallocator<T, R, C, DimSum<R, D>, C>,
     {
+        assert!(i <= self.nrows(), "Row insertion index out of range.");
+
         let m = self.into_owned();
         let (nrows, ncols) = m.data.shape();
         let mut res = Matrix::from_data(DefaultAllocator::reallocate_copy(
@@ -692,8 +1071,6 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
             m.data,
         ));
 
-        assert!(i <= nrows.value(), "Row insertion index out of range.");
-
         if ninsert.value() != 0 {
             extend_rows(
                 &mut res.data.as_mut_slice(),
@@ -897,6 +1274,57 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     }
 }
 
+/// # Reshaping to and from vectors
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar> OMatrix<T, Dynamic, Dynamic> {
+    /// Reinterprets this matrix as a column vector containing all its components in column-major
+    /// order.
+    ///
+    /// The components are neither copied nor moved. This is the inverse of
+    /// [`from_vector`](Self::from_vector).
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, DVector};
+    /// let m = DMatrix::from_row_slice(2, 3, &[1, 2, 3,
+    ///                                         4, 5, 6]);
+    /// let v = m.reshape_to_vector();
+    /// assert_eq!(v, DVector::from_vec(vec![1, 4, 2, 5, 3, 6]));
+    /// ```
+    #[must_use]
+    pub fn reshape_to_vector(self) -> OVector<T, Dynamic> {
+        let len = self.len();
+        self.reshape_generic(Dynamic::new(len), Const::<1>)
+    }
+
+    /// Reinterprets the components of the column vector `v` as a matrix with `nrows` rows and
+    /// `ncols` columns, filled in column-major order.
+    ///
+    /// The components are neither copied nor moved. This is the inverse of
+    /// [`reshape_to_vector`](Self::reshape_to_vector).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nrows * ncols` is not equal to the dimension of `v`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, DVector};
+    /// let v = DVector::from_vec(vec![1, 4, 2, 5, 3, 6]);
+    /// let m = DMatrix::from_vector(v, 2, 3);
+    /// assert_eq!(m, DMatrix::from_row_slice(2, 3, &[1, 2, 3,
+    ///                                               4, 5, 6]));
+    /// ```
+    pub fn from_vector(v: OVector<T, Dynamic>, nrows: usize, ncols: usize) -> Self {
+        assert_eq!(
+            nrows * ncols,
+            v.len(),
+            "Reshape error: the vector does not have the right number of elements."
+        );
+        v.reshape_generic(Dynamic::new(nrows), Dynamic::new(ncols))
+    }
+}
+
 /// # In-place resizing
 #[cfg(any(feature = "std", feature = "alloc"))]
 impl<T: Scalar> OMatrix<T, Dynamic, Dynamic> {
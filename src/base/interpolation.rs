@@ -1,35 +1,87 @@
-use crate::storage::Storage;
+use crate::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
+use crate::storage::{Storage, StorageMut};
 use crate::{
-    Allocator, DefaultAllocator, Dim, OVector, One, RealField, Scalar, Unit, Vector, Zero,
+    Allocator, DefaultAllocator, Dim, Matrix, Matrix3, OMatrix, OVector, One, RealField, Rotation3,
+    Scalar, Unit, Vector, Zero,
 };
 use simba::scalar::{ClosedAdd, ClosedMul, ClosedSub};
 
 /// # Interpolation
-impl<T: Scalar + Zero + One + ClosedAdd + ClosedSub + ClosedMul, D: Dim, S: Storage<T, D>>
-    Vector<T, D, S>
+impl<T, R: Dim, C: Dim, S> Matrix<T, R, C, S>
+where
+    T: Scalar + Zero + One + ClosedAdd + ClosedSub + ClosedMul,
+    S: Storage<T, R, C>,
 {
-    /// Returns `self * (1.0 - t) + rhs * t`, i.e., the linear blend of the vectors x and y using the scalar value a.
+    /// Returns `self * (1.0 - t) + rhs * t`, i.e., the component-wise linear blend of the
+    /// matrices (or vectors) x and y using the scalar value t.
     ///
-    /// The value for a is not restricted to the range `[0, 1]`.
+    /// The value for t is not restricted to the range `[0, 1]`.
     ///
     /// # Examples:
     ///
     /// ```
-    /// # use nalgebra::Vector3;
+    /// # use nalgebra::{Matrix2, Vector3};
     /// let x = Vector3::new(1.0, 2.0, 3.0);
     /// let y = Vector3::new(10.0, 20.0, 30.0);
     /// assert_eq!(x.lerp(&y, 0.1), Vector3::new(1.9, 3.8, 5.7));
+    ///
+    /// let a = Matrix2::new(0.0, 1.0, 2.0, 3.0);
+    /// let b = Matrix2::new(4.0, 5.0, 6.0, 7.0);
+    /// assert_eq!(a.lerp(&b, 0.5), Matrix2::new(2.0, 3.0, 4.0, 5.0));
     /// ```
     #[must_use]
-    pub fn lerp<S2: Storage<T, D>>(&self, rhs: &Vector<T, D, S2>, t: T) -> OVector<T, D>
+    pub fn lerp<S2: Storage<T, R, C>>(&self, rhs: &Matrix<T, R, C, S2>, t: T) -> OMatrix<T, R, C>
     where
-        DefaultAllocator: Allocator<T, D>,
+        DefaultAllocator: Allocator<T, R, C>,
     {
-        let mut res = self.clone_owned();
-        res.axpy(t.inlined_clone(), rhs, T::one() - t);
-        res
+        let one_minus_t = T::one() - t.inlined_clone();
+        self.zip_map(rhs, |a, b| {
+            a * one_minus_t.inlined_clone() + b * t.inlined_clone()
+        })
     }
 
+    /// Sets `self` to `self * (1.0 - t) + rhs * t` in place, i.e., the component-wise linear
+    /// blend of the matrices (or vectors) `self` and `rhs` using the scalar value t.
+    ///
+    /// This is the in-place version of [`lerp`](Self::lerp), which does not allocate. The value
+    /// for t is not restricted to the range `[0, 1]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` and `rhs` do not have the same dimensions.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{DVector, Vector3};
+    /// let mut x = Vector3::new(1.0, 2.0, 3.0);
+    /// x.lerp_mut(&Vector3::new(10.0, 20.0, 30.0), 0.1);
+    /// assert_eq!(x, Vector3::new(1.9, 3.8, 5.7));
+    ///
+    /// // Exponential moving average of a sequence of samples.
+    /// let mut average = DVector::zeros(2);
+    /// for sample in &[DVector::from_vec(vec![1.0, 2.0]), DVector::from_vec(vec![3.0, 4.0])] {
+    ///     average.lerp_mut(sample, 0.5);
+    /// }
+    /// assert_eq!(average, DVector::from_vec(vec![1.75, 2.5]));
+    /// ```
+    pub fn lerp_mut<R2: Dim, C2: Dim, S2>(&mut self, rhs: &Matrix<T, R2, C2, S2>, t: T)
+    where
+        S: StorageMut<T, R, C>,
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R, R2> + SameNumberOfColumns<C, C2>,
+    {
+        let one_minus_t = T::one() - t.inlined_clone();
+        self.zip_apply(rhs, |a, b| {
+            a * one_minus_t.inlined_clone() + b * t.inlined_clone()
+        })
+    }
+}
+
+/// # Interpolation
+impl<T: Scalar + Zero + One + ClosedAdd + ClosedSub + ClosedMul, D: Dim, S: Storage<T, D>>
+    Vector<T, D, S>
+{
     /// Computes the spherical linear interpolation between two non-zero vectors.
     ///
     /// The result is a unit vector.
@@ -125,3 +177,31 @@ impl<T: RealField, D: Dim, S: Storage<T, D>> Unit<Vector<T, D, S>> {
         }
     }
 }
+
+/// # Interpolation between two rotation matrices
+impl<T: RealField> Matrix3<T> {
+    /// Computes the spherical linear interpolation between two 3D rotation matrices.
+    ///
+    /// Both `self` and `other` are assumed to be rotation matrices. This interpolates along the
+    /// geodesic of SO(3), i.e., the result is `self * exp(t * log(self.transpose() * other))`,
+    /// which is always a valid rotation matrix. Use [`Rotation3::slerp`] directly if the
+    /// rotations are already wrapped into the `Rotation3` type.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let r1 = Rotation3::from_axis_angle(&Vector3::z_axis(), 0.2).into_inner();
+    /// let r2 = Rotation3::from_axis_angle(&Vector3::z_axis(), 1.0).into_inner();
+    /// let expected = Rotation3::from_axis_angle(&Vector3::z_axis(), 0.6).into_inner();
+    ///
+    /// assert_relative_eq!(r1.slerp_rotation(&r2, 0.5), expected, epsilon = 1.0e-6);
+    /// ```
+    #[must_use]
+    pub fn slerp_rotation(&self, other: &Self, t: T) -> Self {
+        let r1 = Rotation3::from_matrix_unchecked(*self);
+        let r2 = Rotation3::from_matrix_unchecked(*other);
+        r1.slerp(&r2, t).into_inner()
+    }
+}
@@ -3,10 +3,11 @@
 use std::iter::FusedIterator;
 use std::marker::PhantomData;
 use std::mem;
+use std::ops::Range;
 
-use crate::base::dimension::{Dim, U1};
+use crate::base::dimension::{Const, Dim, U1};
 use crate::base::storage::{Storage, StorageMut};
-use crate::base::{Matrix, MatrixSlice, MatrixSliceMut, Scalar};
+use crate::base::{Matrix, MatrixSlice, MatrixSliceMut, Scalar, SliceStorageMut};
 
 macro_rules! iterator {
     (struct $Name:ident for $Storage:ident.$ptr: ident -> $Ptr:ty, $Ref:ty, $SRef: ty) => {
@@ -296,12 +297,33 @@ impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<T, R, C>> ExactSizeIterat
 /// An iterator through the columns of a matrix.
 pub struct ColumnIter<'a, T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> {
     mat: &'a Matrix<T, R, C, S>,
-    curr: usize,
+    range: Range<usize>,
 }
 
 impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + Storage<T, R, C>> ColumnIter<'a, T, R, C, S> {
     pub(crate) fn new(mat: &'a Matrix<T, R, C, S>) -> Self {
-        ColumnIter { mat, curr: 0 }
+        ColumnIter {
+            mat,
+            range: 0..mat.ncols(),
+        }
+    }
+
+    /// Splits this iterator into two iterators over the columns `[start, start + index)` and
+    /// `[start + index, end)` of the remaining range.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.range.start + index;
+        assert!(mid <= self.range.end, "Column iterator split out of range.");
+
+        let left = ColumnIter {
+            mat: self.mat,
+            range: self.range.start..mid,
+        };
+        let right = ColumnIter {
+            mat: self.mat,
+            range: mid..self.range.end,
+        };
+        (left, right)
     }
 }
 
@@ -312,26 +334,28 @@ impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + Storage<T, R, C>> Iterator
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.curr < self.mat.ncols() {
-            let res = self.mat.column(self.curr);
-            self.curr += 1;
-            Some(res)
-        } else {
-            None
-        }
+        let j = self.range.next()?;
+        Some(self.mat.column(j))
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (
-            self.mat.ncols() - self.curr,
-            Some(self.mat.ncols() - self.curr),
-        )
+        (self.range.len(), Some(self.range.len()))
     }
 
     #[inline]
     fn count(self) -> usize {
-        self.mat.ncols() - self.curr
+        self.range.len()
+    }
+}
+
+impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + Storage<T, R, C>> DoubleEndedIterator
+    for ColumnIter<'a, T, R, C, S>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let j = self.range.next_back()?;
+        Some(self.mat.column(j))
     }
 }
 
@@ -340,28 +364,66 @@ impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + Storage<T, R, C>> ExactSizeIterator
 {
     #[inline]
     fn len(&self) -> usize {
-        self.mat.ncols() - self.curr
+        self.range.len()
     }
 }
 
 /// An iterator through the mutable columns of a matrix.
 pub struct ColumnIterMut<'a, T: Scalar, R: Dim, C: Dim, S: StorageMut<T, R, C>> {
-    mat: *mut Matrix<T, R, C, S>,
-    curr: usize,
+    ptr: *mut T,
+    nrows: R,
+    strides: (S::RStride, S::CStride),
+    range: Range<usize>,
     phantom: PhantomData<&'a mut Matrix<T, R, C, S>>,
 }
 
 impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<T, R, C>> ColumnIterMut<'a, T, R, C, S> {
     pub(crate) fn new(mat: &'a mut Matrix<T, R, C, S>) -> Self {
+        let (nrows, ncols) = mat.data.shape();
         ColumnIterMut {
-            mat,
-            curr: 0,
+            ptr: mat.data.ptr_mut(),
+            nrows,
+            strides: mat.data.strides(),
+            range: 0..ncols.value(),
             phantom: PhantomData,
         }
     }
 
-    fn ncols(&self) -> usize {
-        unsafe { (*self.mat).ncols() }
+    /// Splits this iterator into two iterators over the columns `[start, start + index)` and
+    /// `[start + index, end)` of the remaining range.
+    ///
+    /// Both iterators yield disjoint columns, so they can safely be used concurrently.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.range.start + index;
+        assert!(mid <= self.range.end, "Column iterator split out of range.");
+
+        let left = ColumnIterMut {
+            ptr: self.ptr,
+            nrows: self.nrows,
+            strides: self.strides,
+            range: self.range.start..mid,
+            phantom: PhantomData,
+        };
+        let right = ColumnIterMut {
+            ptr: self.ptr,
+            nrows: self.nrows,
+            strides: self.strides,
+            range: mid..self.range.end,
+            phantom: PhantomData,
+        };
+        (left, right)
+    }
+
+    /// Builds the view of the `j`-th column directly from the data pointer, so that no reference
+    /// to the whole matrix is ever created.
+    ///
+    /// The caller must ensure that `j` is in bounds and that this column is not yielded twice.
+    #[inline]
+    unsafe fn column(&self, j: usize) -> MatrixSliceMut<'a, T, R, U1, S::RStride, S::CStride> {
+        let ptr = self.ptr.add(j * self.strides.1.value());
+        let data = SliceStorageMut::from_raw_parts(ptr, (self.nrows, Const::<1>), self.strides);
+        Matrix::from_data_statically_unchecked(data)
     }
 }
 
@@ -372,23 +434,28 @@ impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<T, R, C>> Iterator
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.curr < self.ncols() {
-            let res = unsafe { (*self.mat).column_mut(self.curr) };
-            self.curr += 1;
-            Some(res)
-        } else {
-            None
-        }
+        let j = self.range.next()?;
+        Some(unsafe { self.column(j) })
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.ncols() - self.curr, Some(self.ncols() - self.curr))
+        (self.range.len(), Some(self.range.len()))
     }
 
     #[inline]
     fn count(self) -> usize {
-        self.ncols() - self.curr
+        self.range.len()
+    }
+}
+
+impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<T, R, C>> DoubleEndedIterator
+    for ColumnIterMut<'a, T, R, C, S>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let j = self.range.next_back()?;
+        Some(unsafe { self.column(j) })
     }
 }
 
@@ -397,6 +464,15 @@ impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<T, R, C>> ExactSizeIterat
 {
     #[inline]
     fn len(&self) -> usize {
-        self.ncols() - self.curr
+        self.range.len()
     }
 }
+
+// The iterator only holds a raw pointer into the matrix data and never touches the matrix itself.
+// Every column is built from that pointer and yielded at most once, and distinct columns never
+// overlap, so sending the iterator to another thread is like sending a `&mut [T]`.
+#[cfg(feature = "rayon")]
+unsafe impl<'a, T: Scalar + Send, R: Dim, C: Dim, S: 'a + StorageMut<T, R, C>> Send
+    for ColumnIterMut<'a, T, R, C, S>
+{
+}
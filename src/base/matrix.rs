@@ -21,18 +21,22 @@ use simba::simd::SimdPartialOrd;
 
 use crate::base::allocator::{Allocator, SameShapeAllocator, SameShapeC, SameShapeR};
 use crate::base::constraint::{DimEq, SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
-use crate::base::dimension::{Dim, DimAdd, DimSum, IsNotStaticOne, U1, U2, U3};
+use crate::base::dimension::{
+    Dim, DimAdd, DimMin, DimMinimum, DimSum, Dynamic, IsNotStaticOne, U1, U2, U3,
+};
 use crate::base::iter::{
     ColumnIter, ColumnIterMut, MatrixIter, MatrixIterMut, RowIter, RowIterMut,
 };
 use crate::base::storage::{
     ContiguousStorage, ContiguousStorageMut, Owned, SameShapeStorage, Storage, StorageMut,
 };
-use crate::base::{Const, DefaultAllocator, OMatrix, OVector, Scalar, Unit};
-use crate::{ArrayStorage, SMatrix, SimdComplexField};
+use crate::base::{
+    Const, DefaultAllocator, OMatrix, OVector, Scalar, SliceStorageMut, Unit, VectorSliceMut,
+};
+use crate::{ArrayStorage, ComplexField, RealField, SMatrix, SimdComplexField};
 
 #[cfg(any(feature = "std", feature = "alloc"))]
-use crate::{DMatrix, DVector, Dynamic, VecStorage};
+use crate::{DMatrix, DVector, VecStorage};
 
 /// A square matrix.
 pub type SquareMatrix<T, D, S> = Matrix<T, D, D, S>;
@@ -763,6 +767,27 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
 
     /// Returns a matrix containing the result of `f` applied to each of its entries. Unlike `map`,
     /// `f` also gets passed the row and column index, i.e. `f(row, col, value)`.
+    ///
+    /// To build a matrix whose entries only depend on their position, use
+    /// [`from_fn`](Self::from_fn) instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix2x3};
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// let scaled = m.map_with_location(|i, j, e| e * (10 * i + j) as f64);
+    /// assert_eq!(scaled, Matrix2x3::new(0.0, 2.0, 6.0,
+    ///                                   40.0, 55.0, 72.0));
+    ///
+    /// // Gaussian kernel matrix, with a bandwidth depending on each row.
+    /// let points = [0.0, 1.0, 3.0];
+    /// let bandwidths = [1.0, 2.0, 0.5];
+    /// let kernel = DMatrix::from_fn(3, 3, |i, _| bandwidths[i])
+    ///     .map_with_location(|i, j, h: f64| (-((points[i] - points[j]) / h).powi(2)).exp());
+    /// assert_eq!(kernel[(0, 0)], 1.0);
+    /// assert_eq!(kernel[(1, 0)], (-0.25f64).exp());
+    /// ```
     #[inline]
     #[must_use]
     pub fn map_with_location<T2: Scalar, F: FnMut(usize, usize, T) -> T2>(
@@ -1526,6 +1551,53 @@ impl<T: Scalar, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
     }
 }
 
+impl<T: Scalar, R: DimMin<C>, C: Dim, S: StorageMut<T, R, C>> Matrix<T, R, C, S> {
+    /// A mutable view of the diagonal of this matrix.
+    ///
+    /// The diagonal of a rectangular matrix contains its `min(nrows, ncols)` components with
+    /// equal row and column indices. The returned vector is a strided view into this matrix, so
+    /// it can be used with all the vector operations, and modifying it modifies this matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Matrix3};
+    /// let mut m = Matrix3::new(1.0, 2.0, 3.0,
+    ///                          4.0, 5.0, 6.0,
+    ///                          7.0, 8.0, 9.0);
+    ///
+    /// // Add a regularization term to the diagonal.
+    /// m.diagonal_mut().add_scalar_mut(0.5);
+    /// assert_eq!(m, Matrix3::new(1.5, 2.0, 3.0,
+    ///                            4.0, 5.5, 6.0,
+    ///                            7.0, 8.0, 9.5));
+    ///
+    /// let mut r = Matrix2x3::zeros();
+    /// for d in r.diagonal_mut().iter_mut() {
+    ///     *d = 1.0;
+    /// }
+    /// assert_eq!(r, Matrix2x3::new(1.0, 0.0, 0.0,
+    ///                              0.0, 1.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn diagonal_mut(&mut self) -> VectorSliceMut<'_, T, DimMinimum<R, C>, Dynamic, Dynamic> {
+        let (nrows, ncols) = self.data.shape();
+        let (rstride, cstride) = self.data.strides();
+        let len = nrows.min(ncols);
+
+        // Two consecutive diagonal elements are one row and one column apart.
+        let stride = Dynamic::new(rstride.value() + cstride.value());
+
+        unsafe {
+            let data = SliceStorageMut::from_raw_parts(
+                self.data.ptr_mut(),
+                (len, Const::<1>),
+                (stride, stride),
+            );
+            Matrix::from_data_statically_unchecked(data)
+        }
+    }
+}
+
 impl<T: SimdComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
     /// The symmetric part of `self`, i.e., `0.5 * (self + self.transpose())`.
     #[inline]
@@ -1926,6 +1998,10 @@ impl<T: Scalar + ClosedAdd + ClosedSub + ClosedMul, R: Dim, C: Dim, S: Storage<T
     Matrix<T, R, C, S>
 {
     /// The perpendicular product between two 2D column vectors, i.e. `a.x * b.y - a.y * b.x`.
+    ///
+    /// This is the scalar 2D cross product, i.e., the `z` component of the 3D cross product of
+    /// the two vectors extended with a zero `z` component. It is also equal to
+    /// `a.perp_vector().dot(&b)`.
     #[inline]
     #[must_use]
     pub fn perp<R2, C2, SB>(&self, b: &Matrix<T, R2, C2, SB>) -> T
@@ -2028,6 +2104,29 @@ impl<T: Scalar + ClosedAdd + ClosedSub + ClosedMul, R: Dim, C: Dim, S: Storage<T
     }
 }
 
+impl<T: Scalar + Field, S: Storage<T, U2>> Vector<T, U2, S> {
+    /// Computes the vector perpendicular to this 2D vector, obtained by rotating it by 90 degrees
+    /// counterclockwise, i.e., `(-self.y, self.x)`.
+    ///
+    /// This is the 2D analogue of the 3D [`cross_matrix`](Matrix::cross_matrix): for all vector
+    /// `v` we have `self.perp(&v) == self.perp_vector().dot(&v)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector2;
+    /// let v = Vector2::new(1.0, 2.0);
+    /// let w = Vector2::new(3.0, -1.0);
+    ///
+    /// assert_eq!(v.perp_vector(), Vector2::new(-2.0, 1.0));
+    /// assert_eq!(v.perp(&w), v.perp_vector().dot(&w));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn perp_vector(&self) -> OVector<T, U2> {
+        OVector::<T, U2>::new(-self[1].inlined_clone(), self[0].inlined_clone())
+    }
+}
+
 impl<T: Scalar + Field, S: Storage<T, U3>> Vector<T, U3, S> {
     /// Computes the matrix `M` such that for all vector `v` we have `M * v == self.cross(&v)`.
     #[inline]
@@ -2049,6 +2148,20 @@ impl<T: Scalar + Field, S: Storage<T, U3>> Vector<T, U3, S> {
 
 impl<T: SimdComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     /// The smallest angle between two vectors.
+    ///
+    /// The result lies in `[0, pi]`. The cosine of the angle is clamped to `[-1, 1]` before
+    /// computing its arc-cosine, so rounding errors on nearly collinear vectors never produce
+    /// `NaN`. If one of the vectors has a zero norm, the angle is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Vector3;
+    /// let v = Vector3::new(0.1, 0.2, 0.3);
+    /// assert_relative_eq!(v.angle(&(v * 3.0)), 0.0, epsilon = 1.0e-7);
+    /// assert_relative_eq!(v.angle(&-v), std::f64::consts::PI);
+    /// assert_eq!(v.angle(&Vector3::zeros()), 0.0);
+    /// ```
     #[inline]
     #[must_use]
     pub fn angle<R2: Dim, C2: Dim, SB>(&self, other: &Matrix<T, R2, C2, SB>) -> T::SimdRealField
@@ -2070,6 +2183,174 @@ impl<T: SimdComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S
     }
 }
 
+/// # Signed angles
+impl<T: RealField, S: Storage<T, U2>> Vector<T, U2, S> {
+    /// The signed angle from `self` to `other`, in `[-pi, pi]`.
+    ///
+    /// The angle is positive if `other` is obtained by rotating `self` counterclockwise, and
+    /// negative otherwise. Its absolute value is equal to [`angle`](Self::angle), but it is
+    /// computed with `atan2` from the dot product and the [`perp`](Self::perp) product, which is
+    /// accurate even for nearly collinear vectors. If one of the vectors has a zero norm, the
+    /// angle is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Vector2;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let x = Vector2::new(2.0, 0.0);
+    /// let y = Vector2::new(0.0, 0.5);
+    /// assert_relative_eq!(x.signed_angle(&y), FRAC_PI_2);
+    /// assert_relative_eq!(y.signed_angle(&x), -FRAC_PI_2);
+    /// assert_eq!(x.signed_angle(&Vector2::zeros()), 0.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn signed_angle<SB: Storage<T, U2>>(&self, other: &Vector<T, U2, SB>) -> T {
+        if self.norm_squared().is_zero() || other.norm_squared().is_zero() {
+            T::zero()
+        } else {
+            self.perp(other).atan2(self.dot(other))
+        }
+    }
+}
+
+impl<T: RealField, S: Storage<T, U3>> Vector<T, U3, S> {
+    /// The signed angle from `self` to `other` around the given `axis`, in `[-pi, pi]`.
+    ///
+    /// The absolute value of the result is equal to [`angle`](Self::angle). The angle is
+    /// positive if the rotation from `self` to `other` is counterclockwise when seen from the
+    /// tip of `axis`, i.e., if `self.cross(other).dot(axis)` is positive, and negative otherwise.
+    /// The norm of `axis` is irrelevant, and `axis` does not need to be orthogonal to the two
+    /// vectors. The angle is computed with `atan2`, which is accurate even for nearly collinear
+    /// vectors. If one of the vectors has a zero norm, the angle is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Vector3;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// let x = Vector3::<f64>::x();
+    /// let y = Vector3::y();
+    /// assert_relative_eq!(x.signed_angle(&y, &Vector3::z()), FRAC_PI_2);
+    /// assert_relative_eq!(x.signed_angle(&y, &-Vector3::z()), -FRAC_PI_2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn signed_angle<SB, SC>(&self, other: &Vector<T, U3, SB>, axis: &Vector<T, U3, SC>) -> T
+    where
+        SB: Storage<T, U3>,
+        SC: Storage<T, U3>,
+    {
+        if self.norm_squared().is_zero() || other.norm_squared().is_zero() {
+            return T::zero();
+        }
+
+        let cross = self.cross(other);
+        let sin = cross.norm();
+        let angle = sin.atan2(self.dot(other));
+
+        if cross.dot(axis) < T::zero() {
+            -angle
+        } else {
+            angle
+        }
+    }
+}
+
+/// # Projection and rejection
+impl<T: ComplexField, D: Dim, S: Storage<T, D>> Vector<T, D, S> {
+    /// Computes the orthogonal projection of `self` onto the direction of `other`, i.e.,
+    /// `(other.dotc(self) / other.norm_squared()) * other`.
+    ///
+    /// Returns the zero vector if `other` is zero. See
+    /// [`try_project_onto`](Self::try_project_onto) for a variant that reports this case.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let a = Vector3::new(1.0, 2.0, 3.0);
+    /// let b = Vector3::new(0.0, 2.0, 0.0);
+    /// assert_eq!(a.project_onto(&b), Vector3::new(0.0, 2.0, 0.0));
+    /// assert_eq!(a.project_onto(&Vector3::zeros()), Vector3::zeros());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn project_onto<S2>(&self, other: &Vector<T, D, S2>) -> OVector<T, D>
+    where
+        S2: Storage<T, D>,
+        DefaultAllocator: Allocator<T, D>,
+    {
+        self.try_project_onto(other, T::RealField::zero())
+            .unwrap_or_else(|| OVector::zeros_generic(other.data.shape().0, Const::<1>))
+    }
+
+    /// Computes the orthogonal projection of `self` onto the direction of `other`.
+    ///
+    /// Returns `None` if the norm of `other` is smaller or equal to `min_norm`.
+    #[inline]
+    #[must_use]
+    pub fn try_project_onto<S2>(
+        &self,
+        other: &Vector<T, D, S2>,
+        min_norm: T::RealField,
+    ) -> Option<OVector<T, D>>
+    where
+        S2: Storage<T, D>,
+        DefaultAllocator: Allocator<T, D>,
+    {
+        let sq_norm = other.norm_squared();
+
+        if sq_norm > min_norm * min_norm {
+            Some(other * other.dotc(self).unscale(sq_norm))
+        } else {
+            None
+        }
+    }
+
+    /// Computes the rejection of `self` from `other`, i.e., the component of `self` orthogonal
+    /// to `other`, equal to `self - self.project_onto(other)`.
+    ///
+    /// Returns a copy of `self` if `other` is zero. See
+    /// [`try_reject_from`](Self::try_reject_from) for a variant that reports this case.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let a = Vector3::new(1.0, 2.0, 3.0);
+    /// let b = Vector3::new(0.0, 2.0, 0.0);
+    /// assert_eq!(a.reject_from(&b), Vector3::new(1.0, 0.0, 3.0));
+    /// assert_eq!(a.reject_from(&b).dot(&b), 0.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn reject_from<S2>(&self, other: &Vector<T, D, S2>) -> OVector<T, D>
+    where
+        S2: Storage<T, D>,
+        DefaultAllocator: Allocator<T, D>,
+    {
+        self - self.project_onto(other)
+    }
+
+    /// Computes the rejection of `self` from `other`.
+    ///
+    /// Returns `None` if the norm of `other` is smaller or equal to `min_norm`.
+    #[inline]
+    #[must_use]
+    pub fn try_reject_from<S2>(
+        &self,
+        other: &Vector<T, D, S2>,
+        min_norm: T::RealField,
+    ) -> Option<OVector<T, D>>
+    where
+        S2: Storage<T, D>,
+        DefaultAllocator: Allocator<T, D>,
+    {
+        self.try_project_onto(other, min_norm)
+            .map(|proj| self - proj)
+    }
+}
+
 impl<T, R: Dim, C: Dim, S> AbsDiffEq for Unit<Matrix<T, R, C, S>>
 where
     T: Scalar + AbsDiffEq,
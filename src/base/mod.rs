@@ -8,11 +8,15 @@ pub mod default_allocator;
 pub mod dimension;
 pub mod iter;
 mod ops;
+#[cfg(feature = "rayon")]
+pub mod par_iter;
 pub mod storage;
 
 mod alias;
 mod alias_slice;
 mod array_storage;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod ascii;
 mod cg;
 mod componentwise;
 #[macro_use]
@@ -25,6 +29,8 @@ mod matrix;
 mod matrix_simba;
 mod matrix_slice;
 mod norm;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod packed_symmetric;
 mod properties;
 mod scalar;
 mod statistics;
@@ -45,10 +51,15 @@ pub use self::unit::*;
 
 pub use self::default_allocator::*;
 pub use self::dimension::*;
+pub use self::edition::PermutationError;
 
 pub use self::alias::*;
 pub use self::alias_slice::*;
 pub use self::array_storage::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::ascii::*;
 pub use self::matrix_slice::*;
 #[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::packed_symmetric::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
 pub use self::vec_storage::*;
@@ -5,7 +5,9 @@ use num::Zero;
 use std::ops::Neg;
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, Dim, DimName, Matrix, Normed, OMatrix, OVector};
+use crate::base::{
+    Const, DefaultAllocator, Dim, DimName, Matrix, Normed, OMatrix, OVector, RowOVector, U1,
+};
 use crate::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
 use crate::storage::{Storage, StorageMut};
 use crate::{ComplexField, Scalar, SimdComplexField, Unit};
@@ -175,6 +177,9 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
 
     /// The L2 norm of this matrix.
     ///
+    /// For matrices that are not vectors, this is the Frobenius norm, i.e., the square root of
+    /// the sum of the squared moduli of all the components, not the induced (spectral) 2-norm.
+    ///
     /// Use `.apply_norm` to apply a custom norm.
     #[inline]
     #[must_use]
@@ -185,6 +190,58 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         self.norm_squared().simd_sqrt()
     }
 
+    /// The L2 norms of each column of this matrix, returned as a row vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, RowVector3};
+    /// let m = Matrix2x3::new(3.0, 0.0, 1.0,
+    ///                        4.0, 2.0, 0.0);
+    /// assert_eq!(m.column_norms(), RowVector3::new(5.0, 2.0, 1.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_norms(&self) -> RowOVector<T::SimdRealField, C>
+    where
+        T: SimdComplexField,
+        DefaultAllocator: Allocator<T::SimdRealField, U1, C>,
+    {
+        let ncols = self.data.shape().1;
+        RowOVector::from_fn_generic(Const::<1>, ncols, |_, j| self.column(j).norm())
+    }
+
+    /// The L2 norms of each row of this matrix, returned as a column vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    /// let m = Matrix2x3::new(3.0, 0.0, 4.0,
+    ///                        0.0, 2.0, 0.0);
+    /// assert_eq!(m.row_norms(), Vector2::new(5.0, 2.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_norms(&self) -> OVector<T::SimdRealField, R>
+    where
+        T: SimdComplexField,
+        DefaultAllocator: Allocator<T::SimdRealField, R>,
+    {
+        let nrows = self.data.shape().0;
+        let mut res = OVector::zeros_generic(nrows, Const::<1>);
+
+        // Accumulate column by column to follow the storage order.
+        for j in 0..self.ncols() {
+            for i in 0..self.nrows() {
+                res[i] += unsafe { self.get_unchecked((i, j)).simd_modulus_squared() };
+            }
+        }
+
+        res.apply(|e| e.simd_sqrt());
+        res
+    }
+
     /// Compute the distance between `self` and `rhs` using the metric induced by the euclidean norm.
     ///
     /// Use `.apply_metric_distance` to apply a custom norm.
@@ -293,6 +350,8 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     }
 
     /// Returns a normalized version of this matrix.
+    ///
+    /// The result has a unit Frobenius norm, see [`norm`](Self::norm).
     #[inline]
     #[must_use = "Did you mean to use normalize_mut()?"]
     pub fn normalize(&self) -> OMatrix<T, R, C>
@@ -379,9 +438,26 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         scaled.select(use_scaled, self.clone_owned())
     }
 
-    /// Returns a normalized version of this matrix unless its norm as smaller or equal to `eps`.
+    /// Returns a normalized version of this matrix unless its norm is smaller or equal to
+    /// `min_norm`.
+    ///
+    /// The norm used is the Frobenius norm, see [`norm`](Self::norm).
     ///
     /// The components of this matrix cannot be SIMD types (see `simd_try_normalize`) instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix2;
+    /// let m = Matrix2::new(1.0, 1.0,
+    ///                      1.0, 1.0);
+    /// let normalized = m.try_normalize(1.0e-6).unwrap();
+    /// assert_relative_eq!(normalized, m / 2.0);
+    /// assert_relative_eq!(normalized.norm(), 1.0);
+    ///
+    /// assert_eq!(Matrix2::<f64>::zeros().try_normalize(1.0e-6), None);
+    /// ```
     #[inline]
     #[must_use = "Did you mean to use try_normalize_mut()?"]
     pub fn try_normalize(&self, min_norm: T::RealField) -> Option<OMatrix<T, R, C>>
@@ -403,6 +479,8 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
 impl<T: Scalar, R: Dim, C: Dim, S: StorageMut<T, R, C>> Matrix<T, R, C, S> {
     /// Normalizes this matrix in-place and returns its norm.
     ///
+    /// The norm used is the Frobenius norm, see [`norm`](Matrix::norm).
+    ///
     /// The components of the matrix cannot be SIMD types (see `simd_try_normalize_mut` instead).
     #[inline]
     pub fn normalize_mut(&mut self) -> T::SimdRealField
@@ -435,9 +513,11 @@ impl<T: Scalar, R: Dim, C: Dim, S: StorageMut<T, R, C>> Matrix<T, R, C, S> {
         SimdOption::new(n, le)
     }
 
-    /// Normalizes this matrix in-place or does nothing if its norm is smaller or equal to `eps`.
+    /// Normalizes this matrix in-place or does nothing if its norm is smaller or equal to
+    /// `min_norm`.
     ///
-    /// If the normalization succeeded, returns the old norm of this matrix.
+    /// The norm used is the Frobenius norm, see [`norm`](Matrix::norm). If the normalization
+    /// succeeded, returns the old norm of this matrix.
     #[inline]
     pub fn try_normalize_mut(&mut self, min_norm: T::RealField) -> Option<T::RealField>
     where
@@ -452,6 +532,78 @@ impl<T: Scalar, R: Dim, C: Dim, S: StorageMut<T, R, C>> Matrix<T, R, C, S> {
             Some(n)
         }
     }
+
+    /// Normalizes each column of this matrix in-place.
+    ///
+    /// Columns with a zero norm are left untouched.
+    #[inline]
+    pub fn normalize_columns_mut(&mut self)
+    where
+        T: ComplexField,
+    {
+        for j in 0..self.ncols() {
+            let mut col = self.column_mut(j);
+            let n = col.norm();
+
+            if !n.is_zero() {
+                col.unscale_mut(n);
+            }
+        }
+    }
+
+    /// Orthonormalizes the columns of this matrix in-place using the modified Gram-Schmidt
+    /// process, and returns the number of linearly independent columns found.
+    ///
+    /// Each column is orthogonalized against the orthonormal columns found so far. If the norm
+    /// of the result is smaller or equal to `min_norm`, the column is considered to be linearly
+    /// dependent on the previous ones and is dropped. The `rank` orthonormal columns found are
+    /// moved, in order, to the first `rank` columns of this matrix, and all the remaining
+    /// columns are set to zero.
+    ///
+    /// Unlike a QR decomposition, this operates in-place and reveals the numerical rank of the
+    /// family of columns.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2, Matrix3};
+    /// // The second column is twice the first one.
+    /// let mut m = Matrix3::new(1.0, 2.0, 0.0,
+    ///                          1.0, 2.0, 1.0,
+    ///                          0.0, 0.0, 1.0);
+    /// let rank = m.orthonormalize_columns_mut(1.0e-10);
+    ///
+    /// assert_eq!(rank, 2);
+    /// let basis = m.fixed_columns::<2>(0);
+    /// assert_relative_eq!(basis.tr_mul(&basis), Matrix2::identity(), epsilon = 1.0e-12);
+    /// assert_eq!(m.column(2).norm(), 0.0);
+    /// ```
+    pub fn orthonormalize_columns_mut(&mut self, min_norm: T::RealField) -> usize
+    where
+        T: ComplexField,
+    {
+        let mut rank = 0;
+
+        for j in 0..self.ncols() {
+            // Move the candidate right after the orthonormal columns found so far.
+            if j != rank {
+                self.swap_columns(rank, j);
+            }
+
+            for k in 0..rank {
+                let (basis_elt, mut candidate) = self.columns_range_pair_mut(k, rank);
+                let proj = basis_elt.dotc(&candidate);
+                candidate.axpy(-proj, &basis_elt, T::one());
+            }
+
+            if self.column_mut(rank).try_normalize_mut(min_norm).is_some() {
+                rank += 1;
+            }
+        }
+
+        self.columns_range_mut(rank..).fill(T::zero());
+        rank
+    }
 }
 
 impl<T: SimdComplexField, R: Dim, C: Dim> Normed for OMatrix<T, R, C>
@@ -12,7 +12,7 @@ use crate::base::constraint::{
 };
 use crate::base::dimension::{Dim, DimMul, DimName, DimProd, Dynamic};
 use crate::base::storage::{ContiguousStorageMut, Storage, StorageMut};
-use crate::base::{DefaultAllocator, Matrix, MatrixSum, OMatrix, Scalar, VectorSlice};
+use crate::base::{DefaultAllocator, Matrix, MatrixSum, OMatrix, Scalar, Vector, VectorSlice};
 use crate::SimdComplexField;
 
 /*
@@ -702,6 +702,149 @@ where
         res
     }
 
+    /// Computes the Gram matrix `self.adjoint() * self`.
+    ///
+    /// The result is Hermitian, so only its lower triangle is actually computed, and then
+    /// mirrored to the upper triangle. This requires about half of the operations of
+    /// `self.ad_mul(self)` and guarantees that the result is exactly Hermitian.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2, Matrix3x2};
+    /// let a = Matrix3x2::new(1.0, 2.0,
+    ///                        3.0, 4.0,
+    ///                        5.0, 6.0);
+    ///
+    /// assert_eq!(a.gram(), Matrix2::new(35.0, 44.0,
+    ///                                   44.0, 56.0));
+    /// assert_eq!(a.gram(), a.transpose() * a);
+    /// ```
+    #[must_use]
+    pub fn gram(&self) -> OMatrix<T, C1, C1>
+    where
+        T: SimdComplexField,
+        DefaultAllocator: Allocator<T, C1, C1>,
+    {
+        let ncols = self.data.shape().1;
+        let mut res = OMatrix::zeros_generic(ncols, ncols);
+
+        for j in 0..ncols.value() {
+            let col_j = self.column(j);
+
+            for i in j..ncols.value() {
+                let val = self.column(i).dotc(&col_j);
+                res[(j, i)] = val.inlined_clone().simd_conjugate();
+                res[(i, j)] = val;
+            }
+        }
+
+        res
+    }
+
+    /// Computes `(self * rhs).trace()` without computing the product `self * rhs`.
+    ///
+    /// This requires `O(n * m)` operations instead of the `O(n² * m)` operations for computing
+    /// the product first, where `self` is a `n x m` matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Matrix3x2};
+    /// let a = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    /// let b = Matrix3x2::new(7, 8,
+    ///                        9, 10,
+    ///                        11, 12);
+    ///
+    /// assert_eq!(a.trace_of_product(&b), (a * b).trace());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self * rhs` is not a well-defined square matrix, i.e., if `rhs` does not have
+    /// the shape of `self.transpose()`.
+    #[inline]
+    #[must_use]
+    pub fn trace_of_product<R2: Dim, C2: Dim, SB>(&self, rhs: &Matrix<T, R2, C2, SB>) -> T
+    where
+        SB: Storage<T, R2, C2>,
+        ShapeConstraint: DimEq<C1, R2> + DimEq<R1, C2>,
+    {
+        let (nrows1, ncols1) = self.shape();
+        let (nrows2, ncols2) = rhs.shape();
+
+        assert!(
+            ncols1 == nrows2 && nrows1 == ncols2,
+            "Trace of product: the product of {:?} and {:?} matrices is not square.",
+            self.shape(),
+            rhs.shape()
+        );
+
+        let mut res = T::zero();
+
+        for k in 0..ncols1 {
+            for i in 0..nrows1 {
+                unsafe {
+                    res += self.get_unchecked((i, k)).inlined_clone()
+                        * rhs.get_unchecked((k, i)).inlined_clone();
+                }
+            }
+        }
+
+        res
+    }
+
+    /// Computes `(Diag(d) * self).trace()`, i.e., `∑ dᵢ * selfᵢᵢ`, without building the diagonal
+    /// matrix `Diag(d)`.
+    ///
+    /// This only reads the diagonal of `self`, and is also equal to `(self * Diag(d)).trace()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// let a = Matrix3::new(1, 2, 3,
+    ///                      4, 5, 6,
+    ///                      7, 8, 9);
+    /// let d = Vector3::new(10, 20, 30);
+    ///
+    /// assert_eq!(a.weighted_trace(&d), 10 + 5 * 20 + 9 * 30);
+    /// assert_eq!(a.weighted_trace(&d), (Matrix3::from_diagonal(&d) * a).trace());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not square, or if the dimension of `d` does not match its dimension.
+    #[inline]
+    #[must_use]
+    pub fn weighted_trace<D2: Dim, SB>(&self, d: &Vector<T, D2, SB>) -> T
+    where
+        SB: Storage<T, D2>,
+        ShapeConstraint: DimEq<R1, D2> + DimEq<C1, D2>,
+    {
+        assert!(
+            self.is_square(),
+            "Cannot compute the trace of non-square matrix."
+        );
+        assert_eq!(
+            self.nrows(),
+            d.len(),
+            "Weighted trace: the dimension of the weights does not match the matrix dimension."
+        );
+
+        let mut res = T::zero();
+
+        for i in 0..d.len() {
+            unsafe {
+                res += d.vget_unchecked(i).inlined_clone()
+                    * self.get_unchecked((i, i)).inlined_clone();
+            }
+        }
+
+        res
+    }
+
     #[inline(always)]
     fn xx_mul_to<R2: Dim, C2: Dim, SB, R3: Dim, C3: Dim, SC>(
         &self,
@@ -837,6 +980,159 @@ where
 
         res
     }
+
+    /// The kronecker sum `self ⊗ Iₘ + Iₙ ⊗ rhs` of two square matrices, where `n` and `m` are
+    /// the dimensions of `self` and `rhs`.
+    ///
+    /// The kronecker sum of two discretized one-dimensional operators is the separable
+    /// two-dimensional operator acting on the tensor product of their domains, e.g., the 2D
+    /// finite-difference Laplacian is the kronecker sum of two 1D Laplacians.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` or `rhs` is not square.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix2, Matrix3};
+    /// let a = Matrix2::new(1, 2,
+    ///                      3, 4);
+    /// let b = Matrix3::new(5, 6, 7,
+    ///                      8, 9, 10,
+    ///                      11, 12, 13);
+    ///
+    /// let expected = a.kronecker(&Matrix3::identity()) + Matrix2::identity().kronecker(&b);
+    /// assert_eq!(a.kronecker_sum(&b), expected);
+    /// ```
+    #[must_use]
+    pub fn kronecker_sum<R2: Dim, C2: Dim, SB>(
+        &self,
+        rhs: &Matrix<T, R2, C2, SB>,
+    ) -> OMatrix<T, DimProd<R1, R2>, DimProd<C1, C2>>
+    where
+        R1: DimMul<R2>,
+        C1: DimMul<C2>,
+        SB: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, DimProd<R1, R2>, DimProd<C1, C2>>,
+    {
+        assert!(
+            self.is_square() && rhs.is_square(),
+            "Unable to compute the kronecker sum of non-square matrices."
+        );
+
+        let (nrows1, ncols1) = self.data.shape();
+        let (nrows2, ncols2) = rhs.data.shape();
+        let (n, m) = (nrows1.value(), nrows2.value());
+
+        let mut res = OMatrix::zeros_generic(nrows1.mul(nrows2), ncols1.mul(ncols2));
+
+        for j1 in 0..n {
+            for i1 in 0..n {
+                let coeff = unsafe { self.get_unchecked((i1, j1)).inlined_clone() };
+                for k in 0..m {
+                    res[(i1 * m + k, j1 * m + k)] = coeff.inlined_clone();
+                }
+            }
+        }
+
+        for i1 in 0..n {
+            for j2 in 0..m {
+                for i2 in 0..m {
+                    let coeff = unsafe { rhs.get_unchecked((i2, j2)).inlined_clone() };
+                    res[(i1 * m + i2, i1 * m + j2)] += coeff;
+                }
+            }
+        }
+
+        res
+    }
+}
+
+/// # Special multiplications.
+impl<T, D: Dim, S> Vector<T, D, S>
+where
+    T: Scalar + ClosedMul,
+    S: Storage<T, D>,
+{
+    #[inline(always)]
+    fn outer_with<D2: Dim, SB>(
+        &self,
+        rhs: &Vector<T, D2, SB>,
+        f: impl Fn(T) -> T,
+    ) -> OMatrix<T, D, D2>
+    where
+        SB: Storage<T, D2>,
+        DefaultAllocator: Allocator<T, D, D2>,
+    {
+        let (nrows, _) = self.data.shape();
+        let (ncols, _) = rhs.data.shape();
+
+        let mut res = unsafe { crate::unimplemented_or_uninitialized_generic!(nrows, ncols) };
+
+        for j in 0..ncols.value() {
+            let coeff = f(unsafe { rhs.vget_unchecked(j).inlined_clone() });
+
+            for i in 0..nrows.value() {
+                unsafe {
+                    *res.get_unchecked_mut((i, j)) =
+                        self.vget_unchecked(i).inlined_clone() * coeff.inlined_clone();
+                }
+            }
+        }
+
+        res
+    }
+
+    /// The outer product `self * rhs.transpose()` of two vectors.
+    ///
+    /// The result has as many rows as `self` has components, and as many columns as `rhs`
+    /// has components. This does not allocate the intermediate transposed vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2, Vector3};
+    /// let x = Vector2::new(1.0, 2.0);
+    /// let y = Vector3::new(3.0, 4.0, 5.0);
+    ///
+    /// assert_eq!(x.outer(&y), Matrix2x3::new(3.0, 4.0, 5.0,
+    ///                                        6.0, 8.0, 10.0));
+    /// assert_eq!(x.outer(&y), x * y.transpose());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn outer<D2: Dim, SB>(&self, rhs: &Vector<T, D2, SB>) -> OMatrix<T, D, D2>
+    where
+        SB: Storage<T, D2>,
+        DefaultAllocator: Allocator<T, D, D2>,
+    {
+        self.outer_with(rhs, |e| e)
+    }
+
+    /// The outer product `self * rhs.adjoint()` of two vectors.
+    ///
+    /// This is the same as [`outer`](Self::outer), except that the components of `rhs` are
+    /// conjugated. For real scalars, both methods compute the same result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Complex, Matrix2, Vector2};
+    /// let x = Vector2::new(Complex::new(1.0, 2.0), Complex::new(3.0, 4.0));
+    /// let y = Vector2::new(Complex::new(5.0, 6.0), Complex::new(7.0, 8.0));
+    ///
+    /// assert_eq!(x.outer_conjugate(&y), x * y.adjoint());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn outer_conjugate<D2: Dim, SB>(&self, rhs: &Vector<T, D2, SB>) -> OMatrix<T, D, D2>
+    where
+        T: SimdComplexField,
+        SB: Storage<T, D2>,
+        DefaultAllocator: Allocator<T, D, D2>,
+    {
+        self.outer_with(rhs, |e| e.simd_conjugate())
+    }
 }
 
 impl<T, D: DimName> iter::Product for OMatrix<T, D, D>
@@ -0,0 +1,281 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use std::ops::{Index, IndexMut, Mul};
+
+use num::Zero;
+use simba::scalar::{ClosedAdd, ClosedMul};
+
+use crate::base::allocator::Allocator;
+use crate::base::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::base::default_allocator::DefaultAllocator;
+use crate::base::dimension::{Const, Dim, Dynamic};
+use crate::base::storage::Storage;
+use crate::base::{Matrix, OMatrix, OVector, Scalar, Vector};
+
+/// A symmetric square matrix that only stores its lower-triangular part.
+///
+/// The lower triangle is stored column-by-column in a single array of `n * (n + 1) / 2`
+/// elements: the entries `(j, j), (j + 1, j), …, (n - 1, j)` of the column `j` are stored
+/// contiguously, followed by those of the column `j + 1`. This is the packed storage used by
+/// LAPACK with `UPLO = 'L'`, and it requires about half the memory of a full matrix.
+///
+/// Indexing with `(i, j)` or `(j, i)` refers to the same stored element, so modifying one entry
+/// through `IndexMut` also modifies its symmetric counterpart.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SymmetricMatrix<T, D: Dim> {
+    dim: D,
+    data: Vec<T>,
+}
+
+/// The index of the entry `(i, j)`, with `i >= j`, in the packed lower triangle of a matrix with
+/// `n` rows.
+#[inline]
+pub(crate) fn packed_lower_index(n: usize, i: usize, j: usize) -> usize {
+    j * (2 * n - j - 1) / 2 + i
+}
+
+impl<T, D: Dim> SymmetricMatrix<T, D> {
+    /// Creates a symmetric matrix from its lower triangle packed column-by-column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` does not contain exactly `n * (n + 1) / 2` elements, where `n` is the
+    /// dimension of the matrix.
+    #[inline]
+    pub fn from_packed_vec_generic(dim: D, data: Vec<T>) -> Self {
+        let n = dim.value();
+        assert_eq!(
+            data.len(),
+            n * (n + 1) / 2,
+            "The packed lower triangle does not have the correct number of elements."
+        );
+
+        SymmetricMatrix { dim, data }
+    }
+
+    /// Creates a symmetric matrix by evaluating `f(i, j)` for each entry `(i, j)` of its lower
+    /// triangle, i.e., with `i >= j`.
+    ///
+    /// The full matrix is never allocated, so this is the preferred way of building large
+    /// symmetric matrices.
+    #[inline]
+    pub fn from_fn_generic<F>(dim: D, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let n = dim.value();
+        let mut data = Vec::with_capacity(n * (n + 1) / 2);
+
+        for j in 0..n {
+            for i in j..n {
+                data.push(f(i, j));
+            }
+        }
+
+        SymmetricMatrix { dim, data }
+    }
+
+    /// The number of rows (and columns) of this matrix.
+    #[inline]
+    #[must_use]
+    pub fn dim(&self) -> usize {
+        self.dim.value()
+    }
+
+    #[inline]
+    pub(crate) fn dim_generic(&self) -> D {
+        self.dim
+    }
+
+    /// The packed lower triangle of this matrix.
+    #[inline]
+    #[must_use]
+    pub fn as_packed_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// The packed lower triangle of this matrix, as a mutable slice.
+    #[inline]
+    pub fn as_packed_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Retrieves the packed lower triangle of this matrix.
+    #[inline]
+    pub fn into_packed_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    #[inline]
+    fn packed_index(&self, (i, j): (usize, usize)) -> usize {
+        let n = self.dim();
+        let (i, j) = if i >= j { (i, j) } else { (j, i) };
+        assert!(i < n, "Matrix index out of bounds.");
+        packed_lower_index(n, i, j)
+    }
+}
+
+impl<T: Scalar, D: Dim> SymmetricMatrix<T, D> {
+    /// Creates a symmetric matrix from the lower-triangular part of a square matrix.
+    ///
+    /// The strictly upper-triangular part of `m` is ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is not square.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix3, SymmetricMatrix};
+    /// let m = Matrix3::new(1.0, 2.0, 4.0,
+    ///                      2.0, 3.0, 5.0,
+    ///                      4.0, 5.0, 6.0);
+    /// let sym = SymmetricMatrix::from_lower_triangle(&m);
+    ///
+    /// assert_eq!(sym.as_packed_slice(), &[1.0, 2.0, 4.0, 3.0, 5.0, 6.0]);
+    /// assert_eq!(sym[(0, 2)], sym[(2, 0)]);
+    /// assert_eq!(sym.to_matrix(), m);
+    /// ```
+    pub fn from_lower_triangle<S: Storage<T, D, D>>(m: &Matrix<T, D, D, S>) -> Self {
+        assert!(
+            m.is_square(),
+            "Unable to build a symmetric matrix from a non-square matrix."
+        );
+
+        Self::from_fn_generic(m.data.shape().0, |i, j| unsafe {
+            m.get_unchecked((i, j)).inlined_clone()
+        })
+    }
+
+    /// Builds the full symmetric matrix, with both of its triangles filled.
+    #[must_use]
+    pub fn to_matrix(&self) -> OMatrix<T, D, D>
+    where
+        DefaultAllocator: Allocator<T, D, D>,
+    {
+        OMatrix::from_fn_generic(self.dim, self.dim, |i, j| self[(i, j)].inlined_clone())
+    }
+}
+
+impl<T> SymmetricMatrix<T, Dynamic> {
+    /// Creates a dynamically-sized symmetric matrix from its lower triangle packed
+    /// column-by-column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` does not contain exactly `n * (n + 1) / 2` elements.
+    #[inline]
+    pub fn from_packed_vec(n: usize, data: Vec<T>) -> Self {
+        Self::from_packed_vec_generic(Dynamic::new(n), data)
+    }
+
+    /// Creates a dynamically-sized symmetric matrix by evaluating `f(i, j)` for each entry
+    /// `(i, j)` of its lower triangle, i.e., with `i >= j`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, SymmetricMatrix};
+    /// let sym = SymmetricMatrix::from_fn(3, |i, j| i * 10 + j);
+    /// assert_eq!(sym.as_packed_slice().len(), 6);
+    /// assert_eq!(sym.to_matrix(), DMatrix::from_fn(3, 3, |i, j| i.max(j) * 10 + i.min(j)));
+    /// ```
+    #[inline]
+    pub fn from_fn<F>(n: usize, f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Self::from_fn_generic(Dynamic::new(n), f)
+    }
+}
+
+impl<T, D: Dim> SymmetricMatrix<T, D>
+where
+    T: Scalar + Zero + ClosedAdd + ClosedMul,
+{
+    /// Computes the matrix-vector product `self * x`, reading each stored entry only once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of `x` does not match the dimension of this matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix3, SymmetricMatrix, Vector3};
+    /// let m = Matrix3::new(1.0, 2.0, 4.0,
+    ///                      2.0, 3.0, 5.0,
+    ///                      4.0, 5.0, 6.0);
+    /// let sym = SymmetricMatrix::from_lower_triangle(&m);
+    /// let x = Vector3::new(1.0, -1.0, 2.0);
+    ///
+    /// assert_eq!(sym.mul_vector(&x), m * x);
+    /// assert_eq!(&sym * &x, m * x);
+    /// ```
+    #[must_use]
+    pub fn mul_vector<D2: Dim, S2>(&self, x: &Vector<T, D2, S2>) -> OVector<T, D>
+    where
+        S2: Storage<T, D2>,
+        ShapeConstraint: SameNumberOfRows<D, D2>,
+        DefaultAllocator: Allocator<T, D>,
+    {
+        let n = self.dim();
+        assert_eq!(
+            x.nrows(),
+            n,
+            "Symmetric matrix-vector multiplication: dimensions mismatch."
+        );
+
+        let mut res = OVector::zeros_generic(self.dim, Const::<1>);
+        let mut entries = self.data.iter();
+
+        for j in 0..n {
+            let x_j = x[j].inlined_clone();
+            let mut res_j = T::zero();
+
+            for i in j..n {
+                let a_ij = entries.next().unwrap().inlined_clone();
+                res[i] += a_ij.inlined_clone() * x_j.inlined_clone();
+
+                if i != j {
+                    res_j += a_ij * x[i].inlined_clone();
+                }
+            }
+
+            res[j] += res_j;
+        }
+
+        res
+    }
+}
+
+impl<T, D: Dim, D2: Dim, S2> Mul<&Vector<T, D2, S2>> for &SymmetricMatrix<T, D>
+where
+    T: Scalar + Zero + ClosedAdd + ClosedMul,
+    S2: Storage<T, D2>,
+    ShapeConstraint: SameNumberOfRows<D, D2>,
+    DefaultAllocator: Allocator<T, D>,
+{
+    type Output = OVector<T, D>;
+
+    #[inline]
+    fn mul(self, rhs: &Vector<T, D2, S2>) -> Self::Output {
+        self.mul_vector(rhs)
+    }
+}
+
+impl<T, D: Dim> Index<(usize, usize)> for SymmetricMatrix<T, D> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, ij: (usize, usize)) -> &T {
+        &self.data[self.packed_index(ij)]
+    }
+}
+
+impl<T, D: Dim> IndexMut<(usize, usize)> for SymmetricMatrix<T, D> {
+    #[inline]
+    fn index_mut(&mut self, ij: (usize, usize)) -> &mut T {
+        let k = self.packed_index(ij);
+        &mut self.data[k]
+    }
+}
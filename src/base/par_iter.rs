@@ -0,0 +1,208 @@
+//! Parallel iterators for matrices, available with the `rayon` feature.
+
+use crate::base::dimension::{Dim, U1};
+use crate::base::iter::{ColumnIter, ColumnIterMut};
+use crate::base::storage::{Storage, StorageMut};
+use crate::base::{Matrix, MatrixSlice, MatrixSliceMut, Scalar};
+
+use rayon::iter::plumbing::{bridge, Producer};
+use rayon::prelude::*;
+
+/// A parallel iterator through the columns of a matrix.
+///
+/// Created by [`Matrix::par_column_iter`].
+pub struct ParColumnIter<'a, T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> {
+    mat: &'a Matrix<T, R, C, S>,
+}
+
+impl<'a, T, R, C, S> ParallelIterator for ParColumnIter<'a, T, R, C, S>
+where
+    T: Scalar + Sync + Send,
+    R: Dim,
+    C: Dim,
+    S: Storage<T, R, C> + Sync,
+{
+    type Item = MatrixSlice<'a, T, R, U1, S::RStride, S::CStride>;
+
+    fn drive_unindexed<Consumer>(self, consumer: Consumer) -> Consumer::Result
+    where
+        Consumer: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.mat.ncols())
+    }
+}
+
+impl<'a, T, R, C, S> IndexedParallelIterator for ParColumnIter<'a, T, R, C, S>
+where
+    T: Scalar + Sync + Send,
+    R: Dim,
+    C: Dim,
+    S: Storage<T, R, C> + Sync,
+{
+    fn len(&self) -> usize {
+        self.mat.ncols()
+    }
+
+    fn drive<Consumer: rayon::iter::plumbing::Consumer<Self::Item>>(
+        self,
+        consumer: Consumer,
+    ) -> Consumer::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: rayon::iter::plumbing::ProducerCallback<Self::Item>>(
+        self,
+        callback: CB,
+    ) -> CB::Output {
+        callback.callback(ColumnIter::new(self.mat))
+    }
+}
+
+impl<'a, T, R, C, S> Producer for ColumnIter<'a, T, R, C, S>
+where
+    T: Scalar + Sync + Send,
+    R: Dim,
+    C: Dim,
+    S: Storage<T, R, C> + Sync,
+{
+    type Item = MatrixSlice<'a, T, R, U1, S::RStride, S::CStride>;
+    type IntoIter = ColumnIter<'a, T, R, C, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        ColumnIter::split_at(self, index)
+    }
+}
+
+/// A parallel iterator through the mutable columns of a matrix.
+///
+/// Created by [`Matrix::par_column_iter_mut`].
+pub struct ParColumnIterMut<'a, T: Scalar, R: Dim, C: Dim, S: StorageMut<T, R, C>> {
+    mat: &'a mut Matrix<T, R, C, S>,
+}
+
+impl<'a, T, R, C, S> ParallelIterator for ParColumnIterMut<'a, T, R, C, S>
+where
+    T: Scalar + Sync + Send,
+    R: Dim,
+    C: Dim,
+    S: StorageMut<T, R, C> + Send + Sync,
+{
+    type Item = MatrixSliceMut<'a, T, R, U1, S::RStride, S::CStride>;
+
+    fn drive_unindexed<Consumer>(self, consumer: Consumer) -> Consumer::Result
+    where
+        Consumer: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.mat.ncols())
+    }
+}
+
+impl<'a, T, R, C, S> IndexedParallelIterator for ParColumnIterMut<'a, T, R, C, S>
+where
+    T: Scalar + Sync + Send,
+    R: Dim,
+    C: Dim,
+    S: StorageMut<T, R, C> + Send + Sync,
+{
+    fn len(&self) -> usize {
+        self.mat.ncols()
+    }
+
+    fn drive<Consumer: rayon::iter::plumbing::Consumer<Self::Item>>(
+        self,
+        consumer: Consumer,
+    ) -> Consumer::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: rayon::iter::plumbing::ProducerCallback<Self::Item>>(
+        self,
+        callback: CB,
+    ) -> CB::Output {
+        callback.callback(ColumnIterMut::new(self.mat))
+    }
+}
+
+impl<'a, T, R, C, S> Producer for ColumnIterMut<'a, T, R, C, S>
+where
+    T: Scalar + Sync + Send,
+    R: Dim,
+    C: Dim,
+    S: StorageMut<T, R, C> + Send + Sync,
+{
+    type Item = MatrixSliceMut<'a, T, R, U1, S::RStride, S::CStride>;
+    type IntoIter = ColumnIterMut<'a, T, R, C, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        ColumnIterMut::split_at(self, index)
+    }
+}
+
+/// # Parallel iteration on columns
+///
+/// These methods are only available with the `rayon` feature enabled.
+impl<T: Scalar + Send + Sync, R: Dim, C: Dim, S: Storage<T, R, C> + Sync> Matrix<T, R, C, S> {
+    /// Iterates in parallel through the columns of this matrix, using rayon.
+    ///
+    /// This is the parallel counterpart of [`column_iter`](Self::column_iter). The returned
+    /// iterator is indexed, so it can be combined with `enumerate`, `zip`, etc.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// use rayon::prelude::*;
+    ///
+    /// let m = DMatrix::from_fn(4, 100, |i, j| (i + j) as f64);
+    /// let norms: Vec<f64> = m.par_column_iter().map(|col| col.norm()).collect();
+    ///
+    /// for (j, norm) in norms.iter().enumerate() {
+    ///     assert_eq!(*norm, m.column(j).norm());
+    /// }
+    /// ```
+    #[inline]
+    pub fn par_column_iter(&self) -> ParColumnIter<'_, T, R, C, S> {
+        ParColumnIter { mat: self }
+    }
+
+    /// Mutably iterates in parallel through the columns of this matrix, using rayon.
+    ///
+    /// This is the parallel counterpart of [`column_iter_mut`](Self::column_iter_mut). Every
+    /// column is yielded exactly once and the mutable views never overlap, so they can be
+    /// modified independently from different threads.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut m = DMatrix::from_fn(4, 100, |i, j| (i + j) as f64);
+    /// m.par_column_iter_mut()
+    ///     .enumerate()
+    ///     .for_each(|(j, mut col)| col *= j as f64);
+    ///
+    /// assert_eq!(m, DMatrix::from_fn(4, 100, |i, j| ((i + j) * j) as f64));
+    /// ```
+    #[inline]
+    pub fn par_column_iter_mut(&mut self) -> ParColumnIterMut<'_, T, R, C, S>
+    where
+        S: StorageMut<T, R, C> + Send,
+    {
+        ParColumnIterMut { mat: self }
+    }
+}
@@ -8,6 +8,7 @@ use crate::base::allocator::Allocator;
 use crate::base::dimension::{Dim, DimMin};
 use crate::base::storage::Storage;
 use crate::base::{DefaultAllocator, Matrix, Scalar, SquareMatrix};
+use crate::linalg::{Cholesky, PivotedCholesky};
 
 impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     /// The total number of elements of this matrix.
@@ -108,6 +109,111 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
 
         true
     }
+
+    /// Indicates if this matrix is diagonal, i.e., if all its off-diagonal elements are zero
+    /// within a relative error of `eps`.
+    ///
+    /// Rectangular matrices are supported: every element at coordinates `(i, j)` with `i != j`
+    /// must be zero.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2, Matrix2x3};
+    /// assert!(Matrix2x3::new(1.0, 0.0, 0.0,
+    ///                        0.0, 2.0, 0.0).is_diagonal(1.0e-7));
+    /// assert!(!Matrix2::new(1.0, 0.1,
+    ///                       0.0, 2.0).is_diagonal(1.0e-7));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_diagonal(&self, eps: T::Epsilon) -> bool
+    where
+        T: Zero + RelativeEq,
+        T::Epsilon: Copy,
+    {
+        self.is_upper_triangular(eps) && self.is_lower_triangular(eps)
+    }
+
+    /// Indicates if this matrix is upper-triangular, i.e., if all its elements below the
+    /// diagonal are zero within a relative error of `eps`.
+    ///
+    /// Rectangular matrices are supported: every element at coordinates `(i, j)` with `i > j`
+    /// must be zero.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// let m = Matrix3::new(1.0, 2.0, 3.0,
+    ///                      0.0, 4.0, 5.0,
+    ///                      0.0, 0.0, 6.0);
+    /// assert!(m.is_upper_triangular(1.0e-7));
+    /// assert!(!m.transpose().is_upper_triangular(1.0e-7));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_upper_triangular(&self, eps: T::Epsilon) -> bool
+    where
+        T: Zero + RelativeEq,
+        T::Epsilon: Copy,
+    {
+        let (nrows, ncols) = self.shape();
+
+        for j in 0..ncols {
+            for i in j + 1..nrows {
+                if !relative_eq!(
+                    unsafe { self.get_unchecked((i, j)) },
+                    &T::zero(),
+                    epsilon = eps
+                ) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Indicates if this matrix is lower-triangular, i.e., if all its elements above the
+    /// diagonal are zero within a relative error of `eps`.
+    ///
+    /// Rectangular matrices are supported: every element at coordinates `(i, j)` with `i < j`
+    /// must be zero.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// let m = Matrix3::new(1.0, 0.0, 0.0,
+    ///                      2.0, 3.0, 0.0,
+    ///                      4.0, 5.0, 6.0);
+    /// assert!(m.is_lower_triangular(1.0e-7));
+    /// assert!(!m.transpose().is_lower_triangular(1.0e-7));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_lower_triangular(&self, eps: T::Epsilon) -> bool
+    where
+        T: Zero + RelativeEq,
+        T::Epsilon: Copy,
+    {
+        let (nrows, ncols) = self.shape();
+
+        for j in 1..ncols {
+            for i in 0..j.min(nrows) {
+                if !relative_eq!(
+                    unsafe { self.get_unchecked((i, j)) },
+                    &T::zero(),
+                    epsilon = eps
+                ) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
 }
 
 impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
@@ -126,6 +232,107 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     {
         (self.ad_mul(self)).is_identity(eps)
     }
+
+    /// Checks that this matrix is hermitian, i.e., that `M ≈ Mᴴ`.
+    ///
+    /// Each element `M[(i, j)] - conj(M[(j, i)])` must have both its real and imaginary parts
+    /// smaller than `eps` in absolute value. In particular, the imaginary parts of the diagonal
+    /// elements must be approximately zero. Returns `false` for non-square matrices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Complex, Matrix2};
+    /// let m = Matrix2::new(Complex::new(1.0, 0.0), Complex::new(2.0, -3.0),
+    ///                      Complex::new(2.0, 3.0), Complex::new(4.0, 0.0));
+    /// assert!(m.is_hermitian(1.0e-7));
+    /// assert!(!(m * Complex::i()).is_hermitian(1.0e-7));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_hermitian(&self, eps: T::RealField) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        for j in 0..self.ncols() {
+            for i in 0..=j {
+                let diff = self[(i, j)] - self[(j, i)].conjugate();
+
+                if diff.real().abs() > eps || diff.imaginary().abs() > eps {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Indicates if any component of this matrix is NaN.
+    ///
+    /// For complex scalars, a component is NaN if either its real or its imaginary part is NaN.
+    /// The scan stops at the first NaN component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Complex, Vector2};
+    /// assert!(!Vector2::new(1.0, f64::INFINITY).has_nan());
+    /// assert!(Vector2::new(1.0, f64::NAN).has_nan());
+    /// assert!(Vector2::new(Complex::new(1.0, f64::NAN), Complex::new(0.0, 0.0)).has_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    #[allow(clippy::eq_op)]
+    pub fn has_nan(&self) -> bool {
+        self.iter().any(|e| {
+            let (re, im) = (e.real(), e.imaginary());
+            re != re || im != im
+        })
+    }
+
+    /// Indicates if any component of this matrix is infinite.
+    ///
+    /// For complex scalars, a component is infinite if either its real or its imaginary part is
+    /// infinite. The scan stops at the first infinite component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Complex, Vector2};
+    /// assert!(!Vector2::new(1.0, f64::NAN).has_infinite());
+    /// assert!(Vector2::new(1.0, f64::NEG_INFINITY).has_infinite());
+    /// assert!(Vector2::new(Complex::new(f64::INFINITY, 0.0), Complex::new(0.0, 0.0)).has_infinite());
+    /// ```
+    #[inline]
+    #[must_use]
+    #[allow(clippy::eq_op)]
+    pub fn has_infinite(&self) -> bool {
+        // A part that is neither finite nor NaN is infinite.
+        let is_infinite = |x: T::RealField| !x.is_finite() && x == x;
+        self.iter()
+            .any(|e| is_infinite(e.real()) || is_infinite(e.imaginary()))
+    }
+
+    /// Indicates if all the components of this matrix are finite, i.e., neither NaN nor
+    /// infinite.
+    ///
+    /// For complex scalars, both the real and imaginary parts of each component must be finite.
+    /// The scan stops at the first non-finite component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// assert!(Matrix2::new(1.0, 2.0, 3.0, 4.0).is_finite());
+    /// assert!(!Matrix2::new(1.0, 2.0, f64::NAN, 4.0).is_finite());
+    /// assert!(!Matrix2::new(1.0, f64::INFINITY, 3.0, 4.0).is_finite());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        self.iter().all(|e| e.is_finite())
+    }
 }
 
 impl<T: RealField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S>
@@ -151,3 +358,77 @@ where
         self.clone_owned().try_inverse().is_some()
     }
 }
+
+impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S>
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    /// Checks that this matrix is hermitian positive-definite by attempting its Cholesky
+    /// decomposition.
+    ///
+    /// The matrix is assumed to be hermitian: like [`Cholesky::new`], this only reads its
+    /// lower-triangular part, so the upper-triangular part of the matrix is not checked. Combine
+    /// this with [`is_hermitian`](Self::is_hermitian) to validate matrices that may not be
+    /// hermitian. Returns `false` for non-square matrices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let covariance = Matrix2::new(2.0, 1.0,
+    ///                               1.0, 2.0);
+    /// assert!(covariance.is_positive_definite());
+    ///
+    /// let indefinite = Matrix2::new(1.0, 2.0,
+    ///                               2.0, 1.0);
+    /// assert!(!indefinite.is_positive_definite());
+    /// ```
+    #[must_use]
+    pub fn is_positive_definite(&self) -> bool {
+        self.is_square() && Cholesky::new(self.clone_owned()).is_some()
+    }
+
+    /// Checks that this matrix is hermitian positive-semidefinite, up to the tolerance `eps`.
+    ///
+    /// This computes the Cholesky decomposition with symmetric pivoting `P * M * Pᵀ = L * Lᴴ` of
+    /// the matrix (see [`PivotedCholesky`]), which stops when every remaining pivot is smaller
+    /// than or equal to `eps`. The matrix is considered positive-semidefinite if every entry of
+    /// the residual `M - Pᵀ * L * Lᴴ * P` has a modulus smaller than or equal to `eps`. In
+    /// particular, matrices with an eigenvalue smaller than `-eps` are rejected.
+    ///
+    /// The matrix is assumed to be hermitian and only its lower-triangular part is read. Combine
+    /// this with [`is_hermitian`](Self::is_hermitian) to validate matrices that may not be
+    /// hermitian. Returns `false` for non-square matrices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// // A rank-1 covariance matrix is semidefinite, but not definite.
+    /// let u = Vector3::new(1.0, -2.0, 0.5);
+    /// let covariance = u * u.transpose();
+    /// assert!(covariance.is_positive_semidefinite(1.0e-12));
+    /// assert!(!covariance.is_positive_definite());
+    ///
+    /// assert!(!(-covariance).is_positive_semidefinite(1.0e-12));
+    /// ```
+    #[must_use]
+    pub fn is_positive_semidefinite(&self, eps: T::RealField) -> bool
+    where
+        DefaultAllocator: Allocator<(usize, usize), D>,
+    {
+        if !self.is_square() {
+            return false;
+        }
+
+        let chol = PivotedCholesky::new(self.clone_owned(), eps);
+        let l = chol.l();
+        let mut reconstructed = &l * l.adjoint();
+        chol.p().inv_permute_rows(&mut reconstructed);
+        chol.p().inv_permute_columns(&mut reconstructed);
+
+        // This also rejects matrices with NaN entries.
+        let n = self.nrows();
+        (0..n).all(|j| (j..n).all(|i| (self[(i, j)] - reconstructed[(i, j)]).modulus() <= eps))
+    }
+}
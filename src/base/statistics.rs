@@ -1,8 +1,12 @@
 use crate::allocator::Allocator;
-use crate::storage::Storage;
-use crate::{Const, DefaultAllocator, Dim, Matrix, OVector, RowOVector, Scalar, VectorSlice, U1};
+use crate::base::constraint::{DimEq, ShapeConstraint};
+use crate::storage::{Storage, StorageMut};
+use crate::{
+    Const, DefaultAllocator, Dim, Matrix, OMatrix, OVector, RowOVector, Scalar, Vector,
+    VectorSlice, U1,
+};
 use num::Zero;
-use simba::scalar::{ClosedAdd, Field, SupersetOf};
+use simba::scalar::{ClosedAdd, Field, RealField, SupersetOf};
 
 /// # Folding on columns and rows
 impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
@@ -398,3 +402,113 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         })
     }
 }
+
+/// # Standardization
+impl<T: RealField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// Standardizes each column of this matrix to zero mean and unit variance.
+    ///
+    /// Returns the standardized matrix, together with the mean and the standard deviation of
+    /// each column, so that the same transformation `(x - mean) / std_dev` can be applied to
+    /// other data. As for [`variance`](Self::variance), the standard deviations are computed
+    /// by dividing by the number of rows.
+    ///
+    /// Columns with a zero variance (up to rounding errors relative to their mean) are only
+    /// centered. Their standard deviation is reported as one, so that reapplying the
+    /// transformation leaves them unscaled as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3x2, RowVector2};
+    ///
+    /// let m = Matrix3x2::new(1.0, 5.0,
+    ///                        2.0, 5.0,
+    ///                        3.0, 5.0);
+    /// let (standardized, means, std_devs) = m.standardize_columns();
+    ///
+    /// let s = (2.0f64 / 3.0).sqrt();
+    /// assert_relative_eq!(standardized, Matrix3x2::new(-1.0 / s, 0.0,
+    ///                                                   0.0,     0.0,
+    ///                                                   1.0 / s, 0.0), epsilon = 1.0e-12);
+    /// assert_eq!(means, RowVector2::new(2.0, 5.0));
+    /// assert_relative_eq!(std_devs, RowVector2::new(s, 1.0), epsilon = 1.0e-12);
+    /// ```
+    #[must_use]
+    pub fn standardize_columns(&self) -> (OMatrix<T, R, C>, RowOVector<T, C>, RowOVector<T, C>)
+    where
+        DefaultAllocator: Allocator<T, R, C> + Allocator<T, U1, C>,
+    {
+        let mut standardized = self.clone_owned();
+        let means = self.row_mean();
+        let mut std_devs = means.clone();
+        let nrows: T = crate::convert(self.nrows() as f64);
+
+        for (j, mut col) in standardized.column_iter_mut().enumerate() {
+            let mean = means[j];
+            col.add_scalar_mut(-mean);
+
+            let std_dev = if col.is_empty() {
+                T::zero()
+            } else {
+                (col.norm_squared() / nrows).sqrt()
+            };
+
+            if std_dev <= T::default_epsilon() * mean.abs() {
+                std_devs[j] = T::one();
+            } else {
+                col /= std_dev;
+                std_devs[j] = std_dev;
+            }
+        }
+
+        (standardized, means, std_devs)
+    }
+}
+
+/// # Online statistics
+impl<T: RealField, R: Dim, C: Dim, S: StorageMut<T, R, C>> Matrix<T, R, C, S> {
+    /// Updates this covariance matrix in-place with exponential forgetting, i.e., computes
+    /// `self = (1 - alpha) * self + alpha * x * x.transpose()`.
+    ///
+    /// The sample `x` is usually centered with a running mean, which can be updated with the
+    /// same forgetting factor as `mean = (1 - alpha) * mean + alpha * sample`. No outer product
+    /// is allocated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` is not in `[0, 1]`, or if `self` is not a square matrix with as many
+    /// rows as `x`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2, Vector2};
+    /// let alpha = 0.1;
+    /// let mut mean = Vector2::zeros();
+    /// let mut covariance = Matrix2::identity();
+    ///
+    /// for sample in &[Vector2::new(1.0, 2.0), Vector2::new(-1.0, 0.5)] {
+    ///     mean.axpy(alpha, sample, 1.0 - alpha);
+    ///     covariance.ewma_covariance_update(&(sample - mean), alpha);
+    /// }
+    ///
+    /// let x1 = Vector2::new(0.9, 1.8);
+    /// let x2 = Vector2::new(-0.99, 0.27);
+    /// let expected = (Matrix2::identity() * 0.9 + x1 * x1.transpose() * 0.1) * 0.9
+    ///     + x2 * x2.transpose() * 0.1;
+    /// assert_relative_eq!(covariance, expected, epsilon = 1.0e-12);
+    /// ```
+    pub fn ewma_covariance_update<D2: Dim, S2>(&mut self, x: &Vector<T, D2, S2>, alpha: T)
+    where
+        S2: Storage<T, D2>,
+        ShapeConstraint: DimEq<R, D2> + DimEq<C, D2>,
+    {
+        assert!(
+            alpha >= T::zero() && alpha <= T::one(),
+            "The forgetting factor of an exponentially-weighted update must be in [0, 1]."
+        );
+        self.ger(alpha, x, x, T::one() - alpha);
+    }
+}
@@ -166,6 +166,30 @@ impl<T: Normed> Unit<T> {
         Self::try_new_and_get(value, min_norm).map(|res| res.0)
     }
 
+    /// Attempts to normalize the given vector and return it wrapped on a `Unit` structure,
+    /// or returns `fallback` if the norm was smaller or equal to `min_norm`.
+    ///
+    /// This is useful to map degenerate inputs, like a zero-length direction, to a canonical
+    /// direction instead of failing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Unit, Vector3};
+    /// let dir = Unit::try_new_or(Vector3::new(0.0, 3.0, 0.0), 1.0e-6, Vector3::z_axis());
+    /// assert_eq!(dir, Vector3::y_axis());
+    ///
+    /// let dir = Unit::try_new_or(Vector3::<f64>::zeros(), 1.0e-6, Vector3::z_axis());
+    /// assert_eq!(dir, Vector3::z_axis());
+    /// ```
+    #[inline]
+    pub fn try_new_or(value: T, min_norm: T::Norm, fallback: Self) -> Self
+    where
+        T::Norm: RealField,
+    {
+        Self::try_new(value, min_norm).unwrap_or(fallback)
+    }
+
     /// Normalize the given vector and return it wrapped on a `Unit` structure and its norm.
     #[inline]
     pub fn new_and_get(mut value: T) -> (Self, T::Norm) {
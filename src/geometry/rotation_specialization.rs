@@ -20,6 +20,7 @@ use crate::base::storage::Storage;
 use crate::base::{Matrix2, Matrix3, SMatrix, SVector, Unit, Vector, Vector1, Vector2, Vector3};
 
 use crate::geometry::{Rotation2, Rotation3, UnitComplex, UnitQuaternion};
+use crate::linalg::SVD;
 
 /*
  *
@@ -745,6 +746,51 @@ where
         Self::from_matrix_unchecked(rot)
     }
 
+    /// Builds the rotation matrix closest to `m` in the Frobenius norm.
+    ///
+    /// This projects `m` onto the set of rotations using its singular value decomposition
+    /// `m = U Σ Vᵀ`. If `m` is a reflection, i.e., `U Vᵀ` has a negative determinant, the
+    /// singular vector associated to the smallest singular value is flipped so that the
+    /// result is still a proper rotation. Unlike `.from_matrix`, this is not sensitive to the
+    /// choice of an initial guess.
+    ///
+    /// Returns `None` if the singular value decomposition did not converge.
+    ///
+    /// # Parameters
+    ///
+    /// * `m`: the matrix from which the rotational part is to be extracted.
+    /// * `eps`: the tolerance used to determine when a value converged to 0 during the SVD.
+    /// * `max_iter`: the maximum number of iterations of the SVD. Loops indefinitely until
+    ///   convergence if set to `0`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Rotation3, Vector3};
+    /// let rot = Rotation3::from_axis_angle(&Vector3::z_axis(), 0.3);
+    /// let noisy = rot.matrix() + Matrix3::repeat(1.0e-3);
+    ///
+    /// let projected = Rotation3::from_matrix_svd(&noisy, 1.0e-15, 0).unwrap();
+    /// assert_relative_eq!(projected.matrix().determinant(), 1.0, epsilon = 1.0e-12);
+    /// assert_relative_eq!(projected, rot, epsilon = 1.0e-2);
+    /// ```
+    pub fn from_matrix_svd(m: &Matrix3<T>, eps: T, max_iter: usize) -> Option<Self>
+    where
+        T: RealField,
+    {
+        let svd = SVD::try_new(*m, true, true, eps, max_iter)?;
+        let mut u = svd.u?;
+        let v_t = svd.v_t?;
+
+        // The singular values are sorted in decreasing order, so the last column of `u`
+        // corresponds to the smallest one.
+        if (u * v_t).determinant() < T::zero() {
+            u.column_mut(2).neg_mut();
+        }
+
+        Some(Self::from_matrix_unchecked(u * v_t))
+    }
+
     /// Ensure this rotation is an orthonormal rotation matrix. This is useful when repeated
     /// computations might cause the matrix from progressively not being orthonormal anymore.
     #[inline]
@@ -5,12 +5,18 @@ use std::ops::{DivAssign, MulAssign};
 
 use crate::allocator::Allocator;
 use crate::base::dimension::Dim;
-use crate::base::storage::Storage;
-use crate::base::{Const, DefaultAllocator, OMatrix, OVector};
+use crate::base::storage::{Storage, StorageMut};
+use crate::base::{Const, DefaultAllocator, Matrix, OMatrix, OVector};
 
 /// Applies in-place a modified Parlett and Reinsch matrix balancing with 2-norm to the matrix and returns
 /// the corresponding diagonal transformation.
 ///
+/// If `A` is the input matrix and `d` the returned vector, the balanced matrix is
+/// `B = D.inverse() * A * D` where `D` is the matrix with diagonal `d`. Since `B` is similar to
+/// `A`, they have the same eigenvalues, and every eigenvector `y` of `B` yields the eigenvector
+/// `x = D * y` of `A` (see [`unbalance_eigenvectors`]). The original matrix can be recovered with
+/// [`unbalance`].
+///
 /// See https://arxiv.org/pdf/1401.5766.pdf
 pub fn balance_parlett_reinsch<T: RealField, D: Dim>(matrix: &mut OMatrix<T, D, D>) -> OVector<T, D>
 where
@@ -66,6 +72,21 @@ where
     d
 }
 
+/// Applies a modified Parlett and Reinsch matrix balancing with 2-norm to an owned matrix and
+/// returns the balanced matrix together with the corresponding diagonal transformation.
+///
+/// This consumes `matrix` and is otherwise identical to [`balance_parlett_reinsch`], which
+/// balances a matrix in-place and only returns the diagonal transformation.
+pub fn balance_parlett_reinsch_owned<T: RealField, D: Dim>(
+    mut matrix: OMatrix<T, D, D>,
+) -> (OMatrix<T, D, D>, OVector<T, D>)
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+{
+    let d = balance_parlett_reinsch(&mut matrix);
+    (matrix, d)
+}
+
 /// Computes in-place `D * m * D.inverse()`, where `D` is the matrix with diagonal `d`.
 pub fn unbalance<T: RealField, D: Dim>(m: &mut OMatrix<T, D, D>, d: &OVector<T, D>)
 where
@@ -83,3 +104,27 @@ where
         }
     }
 }
+
+/// Computes in-place `D * vectors`, where `D` is the matrix with diagonal `d`.
+///
+/// If the columns of `vectors` are eigenvectors of a matrix balanced by
+/// [`balance_parlett_reinsch`], this transforms them into the eigenvectors of the original
+/// matrix. The resulting vectors are not normalized.
+pub fn unbalance_eigenvectors<T: RealField, D: Dim, C: Dim, S>(
+    vectors: &mut Matrix<T, D, C, S>,
+    d: &OVector<T, D>,
+) where
+    S: StorageMut<T, D, C>,
+    DefaultAllocator: Allocator<T, D>,
+{
+    assert_eq!(
+        vectors.nrows(),
+        d.len(),
+        "Unbalancing: mismatched dimensions."
+    );
+
+    for j in 0..vectors.ncols() {
+        let mut col = vectors.column_mut(j);
+        col.component_mul_assign(d);
+    }
+}
@@ -1,14 +1,16 @@
 #[cfg(feature = "serde-serialize-no-std")]
 use serde::{Deserialize, Serialize};
 
-use num::One;
+use num::{One, Zero};
+#[cfg(feature = "rand")]
+use rand::{distributions::Distribution, Rng};
 use simba::scalar::ComplexField;
 use simba::simd::SimdComplexField;
 
 use crate::allocator::Allocator;
-use crate::base::{Const, DefaultAllocator, Matrix, OMatrix, Vector};
+use crate::base::{Const, DefaultAllocator, Matrix, OMatrix, OVector, Vector};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
-use crate::dimension::{Dim, DimAdd, DimDiff, DimSub, DimSum, U1};
+use crate::dimension::{Dim, DimAdd, DimDiff, DimMin, DimSub, DimSum, U1};
 use crate::storage::{Storage, StorageMut};
 
 /// The Cholesky decomposition of a symmetric-definite-positive matrix.
@@ -134,12 +136,27 @@ where
     }
 
     /// Computes the inverse of the decomposed matrix.
+    ///
+    /// The inverse is computed as `L⁻ᴴ * L⁻¹`. Since it is Hermitian, only its lower triangle is
+    /// actually computed, and then mirrored to the upper triangle. Hence, the result is exactly
+    /// Hermitian. This is cheaper and more accurate than using a general-purpose inverse.
     #[must_use]
     pub fn inverse(&self) -> OMatrix<T, D, D> {
         let shape = self.chol.data.shape();
-        let mut res = OMatrix::identity_generic(shape.0, shape.1);
+        let n = self.chol.nrows();
+        let mut l_inv = OMatrix::identity_generic(shape.0, shape.1);
+        self.chol.solve_lower_triangular_unchecked_mut(&mut l_inv);
+
+        let mut res = OMatrix::zeros_generic(shape.0, shape.1);
+        for j in 0..n {
+            for i in j..n {
+                // L⁻¹ is lower-triangular, so only its rows `i..` contribute.
+                let val = l_inv.slice_range(i.., i).dotc(&l_inv.slice_range(i.., j));
+                res[(j, i)] = val.simd_conjugate();
+                res[(i, j)] = val;
+            }
+        }
 
-        self.solve_mut(&mut res);
         res
     }
 
@@ -153,6 +170,101 @@ where
         }
         prod_diag.simd_modulus_squared()
     }
+
+    /// Computes the natural logarithm of the determinant of the decomposed matrix.
+    ///
+    /// This method is more robust than `.determinant()` to very small or very
+    /// large determinants since it computes `2 * sum(ln(|L_ii|))` instead of
+    /// the product of the diagonal entries of `L`.
+    #[must_use]
+    pub fn ln_determinant(&self) -> T::SimdRealField {
+        let dim = self.chol.nrows();
+        let mut sum_diag = T::SimdRealField::zero();
+        for i in 0..dim {
+            sum_diag += unsafe {
+                self.chol
+                    .get_unchecked((i, i))
+                    .simd_modulus_squared()
+                    .simd_ln()
+            };
+        }
+        sum_diag
+    }
+
+    /// Computes `L * z` where `L` is the lower-triangular Cholesky factor.
+    ///
+    /// If the decomposed matrix is the covariance matrix `Σ = L * L.adjoint()` of a multivariate
+    /// normal distribution, and `z` is a column vector of independent standard normal samples,
+    /// then `mean + self.transform_standard_normal(&z)` is a sample of the normal distribution
+    /// with the given `mean` and covariance `Σ`. Samples are column vectors, i.e., this is not
+    /// the same as the row-vector convention `z.transpose() * L.transpose()`.
+    #[must_use]
+    pub fn transform_standard_normal<S2>(&self, z: &Vector<T, D, S2>) -> OVector<T, D>
+    where
+        S2: Storage<T, D>,
+        DefaultAllocator: Allocator<T, D>,
+    {
+        let dim = self.chol.nrows();
+        assert_eq!(
+            z.nrows(),
+            dim,
+            "Cholesky transform: dimension mismatch between the factor and the vector."
+        );
+
+        let mut res = OVector::zeros_generic(self.chol.data.shape().0, Const::<1>);
+
+        // Only the lower-triangular part of `self.chol` is read.
+        for j in 0..dim {
+            let z_j = unsafe { *z.vget_unchecked(j) };
+            res.rows_range_mut(j..)
+                .axpy(z_j, &self.chol.slice_range(j.., j), T::one());
+        }
+
+        res
+    }
+
+    /// Computes the squared Mahalanobis distance `deltaᴴ * Σ⁻¹ * delta`, where `Σ = L * L.adjoint()`
+    /// is the decomposed matrix.
+    ///
+    /// The decomposed matrix must be the covariance matrix `Σ` (not its inverse, the precision
+    /// matrix), and `delta` is typically the difference between a sample and the mean of the
+    /// distribution. Since `deltaᴴ * Σ⁻¹ * delta = ‖L⁻¹ * delta‖²`, this only performs a single
+    /// triangular solve and never forms `Σ⁻¹`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of `delta` does not match the dimension of the decomposed matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2, Vector2};
+    /// let covariance = Matrix2::new(4.0, 1.0,
+    ///                               1.0, 2.0);
+    /// let chol = covariance.cholesky().unwrap();
+    /// let delta = Vector2::new(1.0, -1.0);
+    ///
+    /// let expected = delta.dot(&(covariance.try_inverse().unwrap() * delta));
+    /// assert_relative_eq!(chol.mahalanobis_squared(&delta), expected, epsilon = 1.0e-12);
+    /// ```
+    #[must_use]
+    pub fn mahalanobis_squared<R2: Dim, S2>(&self, delta: &Vector<T, R2, S2>) -> T::SimdRealField
+    where
+        S2: Storage<T, R2>,
+        DefaultAllocator: Allocator<T, R2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            delta.nrows(),
+            self.chol.nrows(),
+            "Cholesky Mahalanobis distance: dimension mismatch between the factor and the vector."
+        );
+
+        let mut l_inv_delta = delta.clone_owned();
+        self.chol
+            .solve_lower_triangular_unchecked_mut(&mut l_inv_delta);
+        l_inv_delta.norm_squared()
+    }
 }
 
 impl<T: ComplexField, D: Dim> Cholesky<T, D>
@@ -162,8 +274,32 @@ where
     /// Attempts to compute the Cholesky decomposition of `matrix`.
     ///
     /// Returns `None` if the input matrix is not definite-positive. The input matrix is assumed
-    /// to be symmetric and only the lower-triangular part is read.
-    pub fn new(mut matrix: OMatrix<T, D, D>) -> Option<Self> {
+    /// to be symmetric and only the lower-triangular part is read. Use
+    /// [`new_with_info`](Self::new_with_info) to find out where the decomposition failed.
+    pub fn new(matrix: OMatrix<T, D, D>) -> Option<Self> {
+        Self::new_with_info(matrix).ok()
+    }
+
+    /// Attempts to compute the Cholesky decomposition of `matrix`, reporting the index of the
+    /// failing pivot.
+    ///
+    /// Returns `Err(j)` if the input matrix is not definite-positive, where `j` is the index of
+    /// the first diagonal element whose pivot is zero or has no square root (e.g. is negative).
+    /// In other words, `j + 1` is the size of the smallest leading principal submatrix that is not
+    /// definite-positive. The input matrix is assumed to be symmetric and only the
+    /// lower-triangular part is read.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Cholesky, Matrix3};
+    /// let m = Matrix3::new(4.0, 2.0, 0.0,
+    ///                      2.0, 1.0, 0.0,
+    ///                      0.0, 0.0, 3.0);
+    /// // The leading 2x2 block is singular.
+    /// assert_eq!(Cholesky::new_with_info(m).unwrap_err(), 1);
+    /// assert!(Cholesky::new_with_info(Matrix3::<f64>::identity()).is_ok());
+    /// ```
+    pub fn new_with_info(mut matrix: OMatrix<T, D, D>) -> Result<Self, usize> {
         assert!(matrix.is_square(), "The input matrix must be square.");
 
         let n = matrix.nrows();
@@ -194,10 +330,144 @@ where
 
             // The diagonal element is either zero or its square root could not
             // be taken (e.g. for negative real numbers).
-            return None;
+            return Err(j);
         }
 
-        Some(Cholesky { chol: matrix })
+        Ok(Cholesky { chol: matrix })
+    }
+
+    /// Solves the system `(A + U * C * V.adjoint()) * x = b` where `A` is the decomposed matrix,
+    /// using the Sherman-Morrison-Woodbury identity.
+    ///
+    /// Here `u` and `v` are `n x k` matrices and `c` is a small `k x k` matrix. This only
+    /// requires solving a `k x k` system, without refactoring the updated matrix.
+    ///
+    /// Returns `None` if the `k x k` capacitance matrix `I + C * V.adjoint() * A⁻¹ * U` is not
+    /// invertible.
+    #[must_use]
+    pub fn woodbury_solve<K, C2: Dim, SU, SC, SV, S2>(
+        &self,
+        u: &Matrix<T, D, K, SU>,
+        c: &Matrix<T, K, K, SC>,
+        v: &Matrix<T, D, K, SV>,
+        b: &Matrix<T, D, C2, S2>,
+    ) -> Option<OMatrix<T, D, C2>>
+    where
+        K: DimMin<K, Output = K>,
+        SU: Storage<T, D, K>,
+        SC: Storage<T, K, K>,
+        SV: Storage<T, D, K>,
+        S2: Storage<T, D, C2>,
+        DefaultAllocator: Allocator<T, D, K>
+            + Allocator<T, K, K>
+            + Allocator<T, K, C2>
+            + Allocator<T, D, C2>
+            + Allocator<(usize, usize), K>,
+    {
+        // A⁻¹ * b and A⁻¹ * U
+        let mut res = self.solve(b);
+        let a_inv_u = self.solve(u);
+
+        // capacitance = I + C * V^* * A⁻¹ * U
+        let k = c.data.shape().0;
+        let mut capacitance = OMatrix::identity_generic(k, k);
+        capacitance.gemm(T::one(), c, &v.ad_mul(&a_inv_u), T::one());
+
+        // x = A⁻¹ * b - A⁻¹ * U * capacitance⁻¹ * C * V^* * A⁻¹ * b
+        let rhs = c * v.ad_mul(&res);
+        let correction = capacitance.lu().solve(&rhs)?;
+        res.gemm(-T::one(), &a_inv_u, &correction, T::one());
+
+        Some(res)
+    }
+
+    /// Solves the weighted least squares problem `min (A * x - b)ᴴ * W * (A * x - b)`, where `W`
+    /// is the decomposed matrix.
+    ///
+    /// With `W = L * Lᴴ`, this is the ordinary least squares problem `min |Lᴴ * (A * x - b)|`.
+    /// It is solved with a QR decomposition of `Lᴴ * A`, which is more accurate than forming
+    /// the weighted normal equations. Because the weight is already decomposed, it can be reused
+    /// for several problems without being factorized again.
+    ///
+    /// Here `a` is a `m x n` matrix with `m >= n`, and each column of `b` is an independent
+    /// right-hand side.
+    ///
+    /// Returns `None` if `Lᴴ * A` is rank-deficient, i.e., if the upper-triangular factor of its
+    /// QR decomposition has a zero diagonal element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` has more columns than rows.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Matrix3x2, Vector2, Vector3};
+    /// // Fit a line through three points, trusting the last one four times more.
+    /// let a = Matrix3x2::new(1.0, 0.0,
+    ///                        1.0, 1.0,
+    ///                        1.0, 2.0);
+    /// let b = Vector3::new(0.0, 1.0, 4.0);
+    /// let w = Matrix3::from_diagonal(&Vector3::new(1.0, 1.0, 4.0)).cholesky().unwrap();
+    ///
+    /// let x = w.weighted_least_squares(&a, &b).unwrap();
+    /// assert_relative_eq!(x, Vector2::new(-8.0 / 21.0, 15.0 / 7.0), epsilon = 1.0e-12);
+    /// ```
+    #[must_use]
+    pub fn weighted_least_squares<C: Dim, C2: Dim, SA, SB>(
+        &self,
+        a: &Matrix<T, D, C, SA>,
+        b: &Matrix<T, D, C2, SB>,
+    ) -> Option<OMatrix<T, C, C2>>
+    where
+        D: DimMin<C, Output = C>,
+        SA: Storage<T, D, C>,
+        SB: Storage<T, D, C2>,
+        DefaultAllocator: Allocator<T, D>
+            + Allocator<T, D, C>
+            + Allocator<T, D, C2>
+            + Allocator<T, C>
+            + Allocator<T, C, C>
+            + Allocator<T, C, C2>,
+    {
+        assert!(
+            a.nrows() >= a.ncols(),
+            "Weighted least squares: the system must not be underdetermined."
+        );
+
+        let l = self.l();
+        let qr = l.ad_mul(a).qr();
+        let mut rhs = l.ad_mul(b);
+        qr.q_tr_mul(&mut rhs);
+
+        let ncols = a.data.shape().1;
+        let nrhs = rhs.data.shape().1;
+        qr.r()
+            .solve_upper_triangular(&rhs.generic_slice((0, 0), (ncols, nrhs)))
+    }
+
+    /// Draws a sample of the multivariate normal distribution with the given `mean` and the
+    /// decomposed matrix as covariance.
+    ///
+    /// The sample is the column vector `mean + L * z` where `z` is a vector of independent
+    /// standard normal samples. See [`transform_standard_normal`](Self::transform_standard_normal)
+    /// for details on the convention.
+    #[cfg(feature = "rand")]
+    #[must_use]
+    pub fn sample_gaussian<S2, G>(&self, mean: &Vector<T, D, S2>, rng: &mut G) -> OVector<T, D>
+    where
+        S2: Storage<T, D>,
+        G: Rng + ?Sized,
+        rand_distr::StandardNormal: Distribution<T>,
+        DefaultAllocator: Allocator<T, D>,
+    {
+        let z = OVector::from_distribution_generic(
+            self.chol.data.shape().0,
+            Const::<1>,
+            &rand_distr::StandardNormal,
+            rng,
+        );
+        self.transform_standard_normal(&z) + mean
     }
 
     /// Given the Cholesky decomposition of a matrix `M`, a scalar `sigma` and a vector `v`,
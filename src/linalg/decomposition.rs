@@ -1,8 +1,8 @@
 use crate::storage::Storage;
 use crate::{
     Allocator, Bidiagonal, Cholesky, ColPivQR, ComplexField, DefaultAllocator, Dim, DimDiff,
-    DimMin, DimMinimum, DimSub, FullPivLU, Hessenberg, Matrix, RealField, Schur, SymmetricEigen,
-    SymmetricTridiagonal, LU, QR, SVD, U1, UDU,
+    DimMin, DimMinimum, DimSub, FullPivLU, Hessenberg, Matrix, OMatrix, PivotedCholesky, RealField,
+    Schur, SymmetricEigen, SymmetricTridiagonal, LU, QR, SVD, U1, UDU,
 };
 
 /// # Rectangular matrix decomposition
@@ -17,6 +17,10 @@ use crate::{
 /// | LU with partial pivoting | `P⁻¹ * L * U`       | `L` is lower-triangular with a diagonal filled with `1` and `U` is upper-triangular. `P` is a permutation matrix. |
 /// | LU with full pivoting    | `P⁻¹ * L * U * Q⁻¹` | `L` is lower-triangular with a diagonal filled with `1` and `U` is upper-triangular. `P` and `Q` are permutation matrices. |
 /// | SVD                      | `U * Σ * Vᵀ`        | `U` and `V` are two orthogonal matrices and `Σ` is a diagonal matrix containing the singular values. |
+///
+/// These methods accept matrices with any storage, including non-contiguous slices of larger
+/// matrices (e.g. obtained with `.fixed_slice(...)` or `.rows_with_step(...)`). Such inputs are
+/// copied into owned storage before being decomposed.
 impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     /// Computes the bidiagonalization using householder reflections.
     pub fn bidiagonalize(self) -> Bidiagonal<T, R, C>
@@ -134,10 +138,14 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
 /// | -------------------------|---------------------------|--------------|
 /// | Hessenberg               | `Q * H * Qᵀ`             | `Q` is a unitary matrix and `H` an upper-Hessenberg matrix. |
 /// | Cholesky                 | `L * Lᵀ`                 | `L` is a lower-triangular matrix. |
+/// | Pivoted Cholesky         | `Pᵀ * L * Lᵀ * P`        | `L` is a lower-triangular matrix and `P` a permutation matrix. |
 /// | UDU                      | `U * D * Uᵀ`             | `U` is a upper-triangular matrix, and `D` a diagonal matrix. |
 /// | Schur decomposition      | `Q * T * Qᵀ`             | `Q` is an unitary matrix and `T` a quasi-upper-triangular matrix. |
 /// | Symmetric eigendecomposition | `Q ~ Λ ~ Qᵀ`   | `Q` is an unitary matrix, and `Λ` is a real diagonal matrix. |
 /// | Symmetric tridiagonalization | `Q ~ T ~ Qᵀ`   | `Q` is an unitary matrix, and `T` is a tridiagonal matrix. |
+///
+/// As for rectangular matrix decompositions, the input matrix may be a non-contiguous slice of a
+/// larger matrix, in which case it is copied into owned storage before being decomposed.
 impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
     /// Attempts to compute the Cholesky decomposition of this matrix.
     ///
@@ -150,6 +158,33 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
         Cholesky::new(self.into_owned())
     }
 
+    /// Attempts to compute the lower-triangular factor `L` of the Cholesky decomposition of this
+    /// matrix.
+    ///
+    /// This is a shorthand for `self.clone_owned().cholesky().map(|c| c.unpack())`. Use
+    /// [`cholesky`](Self::cholesky) instead to retain the decomposition for solving systems.
+    /// Returns `None` if the input matrix is not definite-positive. The input matrix is assumed
+    /// to be symmetric and only the lower-triangular part is read.
+    #[must_use]
+    pub fn cholesky_l(&self) -> Option<OMatrix<T, D, D>>
+    where
+        DefaultAllocator: Allocator<T, D, D>,
+    {
+        Cholesky::new(self.clone_owned()).map(|c| c.unpack())
+    }
+
+    /// Computes the Cholesky decomposition with symmetric pivoting of this hermitian
+    /// positive-semidefinite matrix, stopping when every remaining pivot is smaller than or equal
+    /// to `eps`.
+    ///
+    /// Only the lower-triangular part of the input matrix is read.
+    pub fn pivoted_cholesky(self, eps: T::RealField) -> PivotedCholesky<T, D>
+    where
+        DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), D>,
+    {
+        PivotedCholesky::new(self.into_owned(), eps)
+    }
+
     /// Attempts to compute the UDU decomposition of this matrix.
     ///
     /// The input matrix `self` is assumed to be symmetric and this decomposition will only read
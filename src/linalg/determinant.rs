@@ -3,14 +3,24 @@ use simba::scalar::ComplexField;
 use crate::base::allocator::Allocator;
 use crate::base::dimension::DimMin;
 use crate::base::storage::Storage;
-use crate::base::{DefaultAllocator, SquareMatrix};
+use crate::base::{DefaultAllocator, OMatrix, SquareMatrix};
 
 use crate::linalg::LU;
 
 impl<T: ComplexField, D: DimMin<D, Output = D>, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
     /// Computes the matrix determinant.
     ///
-    /// If the matrix has a dimension larger than 3, an LU decomposition is used.
+    /// If the matrix has a dimension larger than 3, an LU decomposition is used. Otherwise, the
+    /// determinant is computed with closed-form cofactor expansions. Both approaches only rely
+    /// on field operations, so complex matrices are supported as well.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Complex, Matrix2};
+    /// let m = Matrix2::new(Complex::new(1.0, 1.0), Complex::new(2.0, 0.0),
+    ///                      Complex::new(3.0, 0.0), Complex::new(4.0, -1.0));
+    /// assert_eq!(m.determinant(), Complex::new(-1.0, 3.0));
+    /// ```
     #[inline]
     #[must_use]
     pub fn determinant(&self) -> T
@@ -58,4 +68,98 @@ impl<T: ComplexField, D: DimMin<D, Output = D>, S: Storage<T, D, D>> SquareMatri
             }
         }
     }
+
+    /// Computes the adjugate (or classical adjoint) of this matrix, i.e., the transpose of its
+    /// cofactor matrix.
+    ///
+    /// The adjugate satisfies `A * adj(A) = adj(A) * A = det(A) * I`, so it is equal to
+    /// `det(A) * A⁻¹` whenever `A` is invertible. Unlike the inverse, it is always defined and
+    /// does not involve any division.
+    ///
+    /// If the matrix has a dimension larger than 3, every cofactor is computed as the
+    /// determinant of a copy of the matrix where the corresponding row has been replaced by a
+    /// basis vector. Otherwise, closed-form expressions are used.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// let m = Matrix3::new(1.0, 2.0, 3.0,
+    ///                      0.0, 1.0, 4.0,
+    ///                      5.0, 6.0, 0.0);
+    /// let expected = Matrix3::new(-24.0,  18.0,  5.0,
+    ///                              20.0, -15.0, -4.0,
+    ///                              -5.0,   4.0,  1.0);
+    /// assert_eq!(m.adjugate(), expected);
+    /// assert_eq!(m * m.adjugate(), Matrix3::identity() * m.determinant());
+    /// ```
+    #[must_use]
+    pub fn adjugate(&self) -> OMatrix<T, D, D>
+    where
+        DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), D>,
+    {
+        assert!(
+            self.is_square(),
+            "Unable to compute the adjugate of a non-square matrix."
+        );
+        let dim = self.shape().0;
+        let mut adj = self.clone_owned();
+
+        unsafe {
+            match dim {
+                0 => {}
+                1 => *adj.get_unchecked_mut((0, 0)) = T::one(),
+                2 => {
+                    let m11 = *self.get_unchecked((0, 0));
+                    let m12 = *self.get_unchecked((0, 1));
+                    let m21 = *self.get_unchecked((1, 0));
+                    let m22 = *self.get_unchecked((1, 1));
+
+                    *adj.get_unchecked_mut((0, 0)) = m22;
+                    *adj.get_unchecked_mut((0, 1)) = -m12;
+                    *adj.get_unchecked_mut((1, 0)) = -m21;
+                    *adj.get_unchecked_mut((1, 1)) = m11;
+                }
+                3 => {
+                    let m11 = *self.get_unchecked((0, 0));
+                    let m12 = *self.get_unchecked((0, 1));
+                    let m13 = *self.get_unchecked((0, 2));
+
+                    let m21 = *self.get_unchecked((1, 0));
+                    let m22 = *self.get_unchecked((1, 1));
+                    let m23 = *self.get_unchecked((1, 2));
+
+                    let m31 = *self.get_unchecked((2, 0));
+                    let m32 = *self.get_unchecked((2, 1));
+                    let m33 = *self.get_unchecked((2, 2));
+
+                    *adj.get_unchecked_mut((0, 0)) = m22 * m33 - m32 * m23;
+                    *adj.get_unchecked_mut((0, 1)) = m32 * m13 - m12 * m33;
+                    *adj.get_unchecked_mut((0, 2)) = m12 * m23 - m22 * m13;
+
+                    *adj.get_unchecked_mut((1, 0)) = m31 * m23 - m21 * m33;
+                    *adj.get_unchecked_mut((1, 1)) = m11 * m33 - m31 * m13;
+                    *adj.get_unchecked_mut((1, 2)) = m21 * m13 - m11 * m23;
+
+                    *adj.get_unchecked_mut((2, 0)) = m21 * m32 - m31 * m22;
+                    *adj.get_unchecked_mut((2, 1)) = m31 * m12 - m11 * m32;
+                    *adj.get_unchecked_mut((2, 2)) = m11 * m22 - m21 * m12;
+                }
+                _ => {
+                    // The cofactor `(i, j)` is the determinant of the matrix where the row `i`
+                    // has been replaced by the `j`-th basis vector.
+                    let mut work = self.clone_owned();
+                    for i in 0..dim {
+                        for j in 0..dim {
+                            work.row_mut(i).fill(T::zero());
+                            *work.get_unchecked_mut((i, j)) = T::one();
+                            *adj.get_unchecked_mut((j, i)) = work.determinant();
+                        }
+                        work.row_mut(i).copy_from(&self.row(i));
+                    }
+                }
+            }
+        }
+
+        adj
+    }
 }
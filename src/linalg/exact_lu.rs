@@ -0,0 +1,272 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, OMatrix, Scalar};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::dimension::{Dim, DimMin, DimMinimum};
+use crate::storage::{Storage, StorageMut};
+use num::{One, Zero};
+use simba::scalar::{ClosedDiv, ClosedMul, ClosedNeg, ClosedSub};
+
+use crate::linalg::PermutationSequence;
+
+/// LU decomposition with partial (row) pivoting for exact arithmetic.
+///
+/// Unlike [`LU`](crate::LU), this only requires the scalar type to support the four arithmetic
+/// operations, so it can be used with types that do not implement `ComplexField`, like
+/// `num_rational::Ratio<i64>` or big rationals. Because the arithmetic is assumed to be exact,
+/// the pivot of each column is its first non-zero entry instead of the entry with the largest
+/// modulus.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{ExactLU, Matrix3};
+/// use num_rational::Ratio;
+///
+/// let r = |n: i64| Ratio::from_integer(n);
+/// let m = Matrix3::new(r(0), r(2), r(1),
+///                      r(3), r(1), r(4),
+///                      r(1), r(5), r(9));
+/// let lu = ExactLU::new(m.clone());
+/// let (p, l, u) = lu.clone().unpack();
+///
+/// let mut pm = m.clone();
+/// p.permute_rows(&mut pm);
+/// assert_eq!(l * u, pm);
+/// assert_eq!(lu.determinant(), Ratio::from_integer(-32));
+/// ```
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, R, C> +
+                           Allocator<(usize, usize), DimMinimum<R, C>>,
+         OMatrix<T, R, C>: Serialize,
+         PermutationSequence<DimMinimum<R, C>>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, R, C> +
+                           Allocator<(usize, usize), DimMinimum<R, C>>,
+         OMatrix<T, R, C>: Deserialize<'de>,
+         PermutationSequence<DimMinimum<R, C>>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct ExactLU<T: Scalar, R: DimMin<C>, C: Dim>
+where
+    DefaultAllocator: Allocator<T, R, C> + Allocator<(usize, usize), DimMinimum<R, C>>,
+{
+    lu: OMatrix<T, R, C>,
+    p: PermutationSequence<DimMinimum<R, C>>,
+}
+
+impl<T: Scalar, R: DimMin<C>, C: Dim> Copy for ExactLU<T, R, C>
+where
+    DefaultAllocator: Allocator<T, R, C> + Allocator<(usize, usize), DimMinimum<R, C>>,
+    OMatrix<T, R, C>: Copy,
+    PermutationSequence<DimMinimum<R, C>>: Copy,
+{
+}
+
+impl<T, R: DimMin<C>, C: Dim> ExactLU<T, R, C>
+where
+    T: Scalar + Zero + One + ClosedSub + ClosedMul + ClosedDiv,
+    DefaultAllocator: Allocator<T, R, C> + Allocator<(usize, usize), DimMinimum<R, C>>,
+{
+    /// Computes the LU decomposition with partial (row) pivoting of `matrix`, using the first
+    /// non-zero entry of each column as its pivot.
+    pub fn new(mut matrix: OMatrix<T, R, C>) -> Self {
+        let (nrows, ncols) = matrix.data.shape();
+        let min_nrows_ncols = nrows.min(ncols);
+
+        let mut p = PermutationSequence::identity_generic(min_nrows_ncols);
+
+        for i in 0..min_nrows_ncols.value() {
+            let piv = match (i..matrix.nrows()).find(|&k| !matrix[(k, i)].is_zero()) {
+                Some(piv) => piv,
+                // No non-zero entries on this column.
+                None => continue,
+            };
+
+            if piv != i {
+                p.append_permutation(i, piv);
+                matrix.swap_rows(i, piv);
+            }
+
+            let diag = matrix[(i, i)].inlined_clone();
+
+            for k in i + 1..matrix.nrows() {
+                if matrix[(k, i)].is_zero() {
+                    continue;
+                }
+
+                let coeff = matrix[(k, i)].inlined_clone() / diag.inlined_clone();
+
+                for j in i + 1..matrix.ncols() {
+                    let delta = coeff.inlined_clone() * matrix[(i, j)].inlined_clone();
+                    matrix[(k, j)] -= delta;
+                }
+
+                matrix[(k, i)] = coeff;
+            }
+        }
+
+        ExactLU { lu: matrix, p }
+    }
+
+    /// The lower triangular matrix of this decomposition.
+    #[inline]
+    #[must_use]
+    pub fn l(&self) -> OMatrix<T, R, DimMinimum<R, C>>
+    where
+        DefaultAllocator: Allocator<T, R, DimMinimum<R, C>>,
+    {
+        let (nrows, ncols) = self.lu.data.shape();
+        let mut m = self.lu.columns_generic(0, nrows.min(ncols)).into_owned();
+        m.fill_upper_triangle(T::zero(), 1);
+        m.fill_diagonal(T::one());
+        m
+    }
+
+    /// The upper triangular matrix of this decomposition.
+    #[inline]
+    #[must_use]
+    pub fn u(&self) -> OMatrix<T, DimMinimum<R, C>, C>
+    where
+        DefaultAllocator: Allocator<T, DimMinimum<R, C>, C>,
+    {
+        let (nrows, ncols) = self.lu.data.shape();
+        self.lu.rows_generic(0, nrows.min(ncols)).upper_triangle()
+    }
+
+    /// The row permutations of this decomposition.
+    #[inline]
+    #[must_use]
+    pub fn p(&self) -> &PermutationSequence<DimMinimum<R, C>> {
+        &self.p
+    }
+
+    /// The row permutations and two triangular matrices of this decomposition: `(P, L, U)`.
+    #[inline]
+    pub fn unpack(
+        self,
+    ) -> (
+        PermutationSequence<DimMinimum<R, C>>,
+        OMatrix<T, R, DimMinimum<R, C>>,
+        OMatrix<T, DimMinimum<R, C>, C>,
+    )
+    where
+        DefaultAllocator: Allocator<T, R, DimMinimum<R, C>> + Allocator<T, DimMinimum<R, C>, C>,
+    {
+        let l = self.l();
+        let u = self.u();
+
+        (self.p, l, u)
+    }
+}
+
+impl<T, D: DimMin<D, Output = D>> ExactLU<T, D, D>
+where
+    T: Scalar + Zero + One + ClosedSub + ClosedMul + ClosedDiv,
+    DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), D>,
+{
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `None` if `self` is not invertible.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
+    /// be overwritten with garbage.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            self.lu.nrows(),
+            b.nrows(),
+            "ExactLU solve matrix dimension mismatch."
+        );
+
+        if !self.is_invertible() {
+            return false;
+        }
+
+        self.p.permute_rows(b);
+
+        let n = self.lu.nrows();
+        for mut col in b.column_iter_mut() {
+            // Forward substitution with the unit lower triangular factor.
+            for i in 0..n {
+                for k in 0..i {
+                    let delta = self.lu[(i, k)].inlined_clone() * col[k].inlined_clone();
+                    col[i] -= delta;
+                }
+            }
+
+            // Backward substitution with the upper triangular factor.
+            for i in (0..n).rev() {
+                for k in i + 1..n {
+                    let delta = self.lu[(i, k)].inlined_clone() * col[k].inlined_clone();
+                    col[i] -= delta;
+                }
+
+                col[i] /= self.lu[(i, i)].inlined_clone();
+            }
+        }
+
+        true
+    }
+
+    /// Computes the inverse of the decomposed matrix.
+    ///
+    /// Returns `None` if the matrix is not invertible.
+    #[must_use]
+    pub fn try_inverse(&self) -> Option<OMatrix<T, D, D>> {
+        let (nrows, ncols) = self.lu.data.shape();
+        let mut res = OMatrix::identity_generic(nrows, ncols);
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    #[must_use]
+    pub fn determinant(&self) -> T
+    where
+        T: ClosedNeg,
+    {
+        let mut res = self.p.determinant::<T>();
+        for i in 0..self.lu.nrows() {
+            res *= self.lu[(i, i)].inlined_clone();
+        }
+
+        res
+    }
+
+    /// Indicates if the decomposed matrix is invertible.
+    #[must_use]
+    pub fn is_invertible(&self) -> bool {
+        (0..self.lu.nrows()).all(|i| !self.lu[(i, i)].is_zero())
+    }
+}
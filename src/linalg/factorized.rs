@@ -0,0 +1,209 @@
+use std::sync::OnceLock;
+
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, OMatrix};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::dimension::{Dim, DimMin};
+use crate::storage::{Storage, StorageMut};
+
+use crate::linalg::{Cholesky, LinearSolve, LU};
+
+/// The factorization cached by a [`FactorizedMatrix`].
+#[derive(Clone, Debug)]
+enum Factorization<T: ComplexField, D: DimMin<D, Output = D>>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), D>,
+{
+    Cholesky(Cholesky<T, D>),
+    LU(LU<T, D, D>),
+    /// The matrix was marked as symmetric positive-definite, but its Cholesky decomposition
+    /// failed.
+    NotPositiveDefinite,
+}
+
+/// A square matrix together with a lazily computed factorization, used to solve repeatedly
+/// against the same matrix.
+///
+/// The factorization is computed on the first call to [`solve`](Self::solve) and reused by all
+/// the subsequent solves. If the matrix was constructed with
+/// [`new_spd`](Self::new_spd), it is factorized with a Cholesky decomposition; otherwise an LU
+/// decomposition with partial pivoting is used.
+///
+/// The factorization is computed at most once, even when solving from multiple threads. This
+/// type is `Send` and `Sync` whenever the scalar type is. It also implements [`LinearSolve`], so
+/// it can be used by the algorithms that are generic over the choice of decomposition.
+#[derive(Clone, Debug)]
+pub struct FactorizedMatrix<T: ComplexField, D: DimMin<D, Output = D>>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), D>,
+{
+    matrix: OMatrix<T, D, D>,
+    spd: bool,
+    factorization: OnceLock<Factorization<T, D>>,
+}
+
+impl<T: ComplexField, D: DimMin<D, Output = D>> FactorizedMatrix<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), D>,
+{
+    /// Wraps a general square matrix, which will be factorized with an LU decomposition.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn new(matrix: OMatrix<T, D, D>) -> Self {
+        Self::with_spd_flag(matrix, false)
+    }
+
+    /// Wraps a hermitian positive-definite matrix, which will be factorized with a Cholesky
+    /// decomposition.
+    ///
+    /// Only the lower-triangular part of the matrix is read by the factorization.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn new_spd(matrix: OMatrix<T, D, D>) -> Self {
+        Self::with_spd_flag(matrix, true)
+    }
+
+    fn with_spd_flag(matrix: OMatrix<T, D, D>, spd: bool) -> Self {
+        assert!(
+            matrix.is_square(),
+            "Unable to factorize a non-square matrix."
+        );
+
+        FactorizedMatrix {
+            matrix,
+            spd,
+            factorization: OnceLock::new(),
+        }
+    }
+
+    /// The wrapped matrix.
+    #[inline]
+    #[must_use]
+    pub fn matrix(&self) -> &OMatrix<T, D, D> {
+        &self.matrix
+    }
+
+    /// Retrieves the wrapped matrix, discarding its factorization.
+    #[inline]
+    pub fn into_inner(self) -> OMatrix<T, D, D> {
+        self.matrix
+    }
+
+    /// Whether the wrapped matrix is marked as symmetric positive-definite, i.e., whether it is
+    /// factorized with a Cholesky decomposition.
+    #[inline]
+    #[must_use]
+    pub fn is_spd(&self) -> bool {
+        self.spd
+    }
+
+    /// Whether the factorization of the wrapped matrix has already been computed.
+    #[inline]
+    #[must_use]
+    pub fn is_factorized(&self) -> bool {
+        self.factorization.get().is_some()
+    }
+
+    fn factorization(&self) -> &Factorization<T, D> {
+        self.factorization.get_or_init(|| {
+            if self.spd {
+                Cholesky::new(self.matrix.clone())
+                    .map(Factorization::Cholesky)
+                    .unwrap_or(Factorization::NotPositiveDefinite)
+            } else {
+                Factorization::LU(LU::new(self.matrix.clone()))
+            }
+        })
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// The factorization of the wrapped matrix is computed by the first call to this method, and
+    /// reused afterwards. Returns `None` if the matrix is not invertible, or if it is marked as
+    /// symmetric positive-definite but its Cholesky decomposition fails.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// # use nalgebra::linalg::FactorizedMatrix;
+    /// let m = Matrix3::new(4.0, 1.0, 0.0,
+    ///                      1.0, 3.0, 1.0,
+    ///                      0.0, 1.0, 2.0);
+    /// let factorized = FactorizedMatrix::new_spd(m);
+    /// assert!(!factorized.is_factorized());
+    ///
+    /// for b in &[Vector3::x(), Vector3::y(), Vector3::z()] {
+    ///     let x = factorized.solve(b).unwrap();
+    ///     assert_relative_eq!(m * x, *b, epsilon = 1.0e-10);
+    /// }
+    /// assert!(factorized.is_factorized());
+    /// ```
+    #[must_use]
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        match self.factorization() {
+            Factorization::Cholesky(chol) => Some(chol.solve(b)),
+            Factorization::LU(lu) => lu.solve(b),
+            Factorization::NotPositiveDefinite => None,
+        }
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// The factorization of the wrapped matrix is computed by the first call to a solve method,
+    /// and reused afterwards. If the matrix is not invertible, or if it is marked as symmetric
+    /// positive-definite but its Cholesky decomposition fails, this returns `false` and `b` may
+    /// be overwritten with garbage.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        match self.factorization() {
+            Factorization::Cholesky(chol) => {
+                chol.solve_mut(b);
+                true
+            }
+            Factorization::LU(lu) => lu.solve_mut(b),
+            Factorization::NotPositiveDefinite => false,
+        }
+    }
+}
+
+impl<T: ComplexField, D: DimMin<D, Output = D>> LinearSolve<T, D> for FactorizedMatrix<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), D>,
+{
+    #[inline]
+    fn solve<R2: Dim, C2: Dim, S2>(&self, b: &Matrix<T, R2, C2, S2>) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        FactorizedMatrix::solve(self, b)
+    }
+
+    #[inline]
+    fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        FactorizedMatrix::solve_mut(self, b)
+    }
+}
@@ -1,14 +1,105 @@
 //! Construction of householder elementary reflections.
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, OMatrix, OVector, Unit, Vector};
-use crate::dimension::Dim;
+use crate::base::constraint::{AreMultipliable, DimEq, SameNumberOfRows, ShapeConstraint};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector, Unit, Vector};
+use crate::dimension::{Const, Dim, U1};
 use crate::storage::{Storage, StorageMut};
 use num::Zero;
 use simba::scalar::ComplexField;
 
 use crate::geometry::Reflection;
 
+/// A Householder reflection, i.e., an elementary reflector `H = I - 2 v vᴴ`.
+///
+/// The axis `v` either has unit length or is zero, in which case `H` is the identity. The
+/// reflection is Hermitian and unitary, so it is its own inverse.
+#[derive(Clone, Debug)]
+pub struct Householder<T: ComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    axis: OVector<T, D>,
+}
+
+impl<T: ComplexField, D: Dim> Householder<T, D>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    /// Computes the Householder reflection `H` that zeroes out all the components of `v` but
+    /// the first one.
+    ///
+    /// Returns the reflection together with `alpha`, the first component of `H * v`, such that
+    /// `H * v = [ alpha, 0, ..., 0 ]^t`. The modulus of `alpha` is the norm of `v`, and its
+    /// sign (or complex argument) is opposite to that of the first component of `v`, which avoids
+    /// catastrophic cancellations when computing the axis of the reflection. If `v` is zero, the
+    /// returned reflection is the identity and `alpha` is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `v` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Vector3;
+    /// # use nalgebra::linalg::householder::Householder;
+    /// let v = Vector3::new(3.0, 0.0, 4.0);
+    /// let (householder, alpha) = Householder::new(&v);
+    /// assert_relative_eq!(alpha, -5.0);
+    ///
+    /// let mut reflected = v;
+    /// householder.apply_left(&mut reflected);
+    /// assert_relative_eq!(reflected, Vector3::new(-5.0, 0.0, 0.0), epsilon = 1.0e-12);
+    /// ```
+    pub fn new<S: Storage<T, D>>(v: &Vector<T, D, S>) -> (Self, T) {
+        assert!(
+            !v.is_empty(),
+            "Cannot compute the Householder reflection of an empty vector."
+        );
+
+        let mut axis = v.clone_owned();
+        let (alpha, not_zero) = reflection_axis_mut(&mut axis);
+
+        if not_zero {
+            (Self { axis }, alpha)
+        } else {
+            axis.fill(T::zero());
+            (Self { axis }, T::zero())
+        }
+    }
+
+    /// The axis `v` of this reflection `H = I - 2 v vᴴ`.
+    ///
+    /// It has unit length, unless this reflection is the identity in which case it is zero.
+    #[must_use]
+    pub fn axis(&self) -> &OVector<T, D> {
+        &self.axis
+    }
+
+    /// Replaces `m` by `H * m`, i.e., applies this reflection to each column of `m`.
+    pub fn apply_left<R2: Dim, C2: Dim, S2>(&self, m: &mut Matrix<T, R2, C2, S2>)
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        let refl = Reflection::new(Unit::new_unchecked(self.axis.column(0)), T::zero());
+        refl.reflect(m);
+    }
+
+    /// Replaces `m` by `m * H`, i.e., applies this reflection to each row of `m`.
+    pub fn apply_right<R2: Dim, C2: Dim, S2>(&self, m: &mut Matrix<T, R2, C2, S2>)
+    where
+        S2: StorageMut<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R2>,
+        ShapeConstraint: DimEq<C2, D> + AreMultipliable<R2, C2, D, U1>,
+    {
+        let mut work = OVector::zeros_generic(m.data.shape().0, Const::<1>);
+        let refl = Reflection::new(Unit::new_unchecked(self.axis.column(0)), T::zero());
+        refl.reflect_rows(m, &mut work);
+    }
+}
+
 /// Replaces `column` by the axis of the householder reflection that transforms `column` into
 /// `(+/-|column|, 0, ..., 0)`.
 ///
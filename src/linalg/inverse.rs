@@ -1,3 +1,7 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use approx::RelativeEq;
 use simba::scalar::ComplexField;
 
 use crate::base::allocator::Allocator;
@@ -5,6 +9,7 @@ use crate::base::dimension::Dim;
 use crate::base::storage::{Storage, StorageMut};
 use crate::base::{DefaultAllocator, OMatrix, SquareMatrix};
 
+#[cfg(not(any(feature = "std", feature = "alloc")))]
 use crate::linalg::lu;
 
 impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
@@ -22,11 +27,99 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
             None
         }
     }
+
+    /// Computes the inverse of this matrix, assuming it is orthogonal (or unitary).
+    ///
+    /// The inverse of an orthogonal matrix is its transpose (the conjugate-transpose for a unitary
+    /// matrix), so this is just a copy of `self.adjoint()`. The orthogonality of `self` is not
+    /// checked: if it is not orthogonal, the result is not its inverse. Use
+    /// [`try_inverse_orthogonal`](Self::try_inverse_orthogonal) to check it first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not square.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Rotation3, Vector3};
+    /// let m: Matrix3<f64> = *Rotation3::from_scaled_axis(Vector3::new(0.1, -0.5, 1.2)).matrix();
+    /// assert_relative_eq!(m.inverse_orthogonal() * m, Matrix3::identity(), epsilon = 1.0e-12);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn inverse_orthogonal(&self) -> OMatrix<T, D, D>
+    where
+        DefaultAllocator: Allocator<T, D, D>,
+    {
+        assert!(self.is_square(), "Unable to invert a non-square matrix.");
+        self.adjoint()
+    }
+
+    /// Attempts to compute the inverse of this matrix by transposing it, after checking that it is
+    /// orthogonal (or unitary).
+    ///
+    /// Returns `None` if `Mᴴ × M` is not equal to the identity matrix with a relative error of
+    /// `eps`, see [`is_orthogonal`](Self::is_orthogonal). Checking orthogonality costs a matrix
+    /// product; use [`inverse_orthogonal`](Self::inverse_orthogonal) to skip it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not square.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let reflection = Matrix2::new(0.0, 1.0, 1.0, 0.0);
+    /// assert_eq!(reflection.try_inverse_orthogonal(1.0e-7), Some(reflection));
+    ///
+    /// let shear = Matrix2::new(1.0, 1.0, 0.0, 1.0);
+    /// assert_eq!(shear.try_inverse_orthogonal(1.0e-7), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn try_inverse_orthogonal(&self, eps: T::Epsilon) -> Option<OMatrix<T, D, D>>
+    where
+        T: RelativeEq,
+        T::Epsilon: Copy,
+        DefaultAllocator: Allocator<T, D, D>,
+    {
+        assert!(self.is_square(), "Unable to invert a non-square matrix.");
+
+        if self.is_orthogonal(eps) {
+            Some(self.adjoint())
+        } else {
+            None
+        }
+    }
 }
 
 impl<T: ComplexField, D: Dim, S: StorageMut<T, D, D>> SquareMatrix<T, D, S> {
-    /// Attempts to invert this matrix in-place. Returns `false` and leaves `self` untouched if
-    /// inversion fails.
+    /// Attempts to invert this matrix in-place.
+    ///
+    /// Matrices of dimension up to 4 are inverted with closed-form expressions. Larger matrices
+    /// are inverted with an in-place Gauss-Jordan elimination with partial pivoting, which does
+    /// not allocate a second matrix (only the `n` pivot indices are stored).
+    ///
+    /// Returns `false` if the matrix is not invertible, in which case `self` is left in an
+    /// unspecified state: it may have been partially overwritten and should not be relied upon.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not square.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::from_fn(6, 6, |i, j| if i == j { 4.0 } else { 1.0 / (1.0 + (i + j) as f64) });
+    /// let mut inv = m.clone();
+    /// assert!(inv.try_inverse_mut());
+    /// assert_relative_eq!(&m * inv, DMatrix::identity(6, 6), epsilon = 1.0e-12);
+    ///
+    /// let mut singular = DMatrix::<f64>::zeros(6, 6);
+    /// assert!(!singular.try_inverse_mut());
+    /// ```
     #[inline]
     pub fn try_inverse_mut(&mut self) -> bool
     where
@@ -110,6 +203,9 @@ impl<T: ComplexField, D: Dim, S: StorageMut<T, D, D>> SquareMatrix<T, D, S> {
                     let oself = self.clone_owned();
                     do_inverse4(&oself, self)
                 }
+                #[cfg(any(feature = "std", feature = "alloc"))]
+                _ => gauss_jordan_inverse_mut(self),
+                #[cfg(not(any(feature = "std", feature = "alloc")))]
                 _ => {
                     let oself = self.clone_owned();
                     lu::try_invert_to(oself, self)
@@ -119,6 +215,56 @@ impl<T: ComplexField, D: Dim, S: StorageMut<T, D, D>> SquareMatrix<T, D, S> {
     }
 }
 
+/// Inverts `m` in-place with a Gauss-Jordan elimination with partial pivoting.
+///
+/// Returns `false` if `m` is singular, in which case it has been partially overwritten.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn gauss_jordan_inverse_mut<T: ComplexField, D: Dim, S: StorageMut<T, D, D>>(
+    m: &mut SquareMatrix<T, D, S>,
+) -> bool {
+    let dim = m.nrows();
+    let mut pivots = Vec::with_capacity(dim);
+
+    for k in 0..dim {
+        let piv = m.slice_range(k.., k).icamax() + k;
+        let diag = m[(piv, k)];
+
+        if diag.is_zero() {
+            return false;
+        }
+
+        pivots.push(piv);
+        m.swap_rows(k, piv);
+
+        // Column `k` is progressively replaced by the corresponding column of the inverse.
+        m[(k, k)] = T::one();
+        let mut pivot_row = m.row_mut(k);
+        pivot_row /= diag;
+
+        for j in (0..dim).filter(|j| *j != k) {
+            let coeff = m[(k, j)];
+            if !coeff.is_zero() {
+                for i in (0..dim).filter(|i| *i != k) {
+                    let delta = m[(i, k)] * coeff;
+                    m[(i, j)] -= delta;
+                }
+            }
+        }
+
+        let inv_diag = m[(k, k)];
+        for i in (0..dim).filter(|i| *i != k) {
+            m[(i, k)] = -m[(i, k)] * inv_diag;
+        }
+    }
+
+    // The row swaps turn into column swaps on the inverse, applied in reverse order.
+    for (k, piv) in pivots.into_iter().enumerate().rev() {
+        m.swap_columns(k, piv);
+    }
+
+    true
+}
+
 // NOTE: this is an extremely efficient, loop-unrolled matrix inverse from MESA (MIT licensed).
 fn do_inverse4<T: ComplexField, D: Dim, S: StorageMut<T, D, D>>(
     m: &OMatrix<T, D, D>,
@@ -0,0 +1,190 @@
+use crate::allocator::Allocator;
+use crate::base::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, SquareMatrix};
+use crate::dimension::{Dim, DimMin};
+use crate::storage::{Storage, StorageMut};
+use simba::scalar::ComplexField;
+
+use crate::linalg::{Cholesky, FullPivLU, LU, QR};
+
+/// A decomposition of a square matrix `A` of dimension `D` that can be used to solve the linear
+/// system `A * X = B`.
+///
+/// This allows writing algorithms that are generic over the choice of decomposition. Each
+/// implementor also provides inherent `solve` and `solve_mut` methods, which remain the most
+/// convenient choice when the concrete decomposition is known.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{LinearSolve, Matrix2, Vector2};
+/// fn solve_twice<D: LinearSolve<f64, nalgebra::U2>>(decomp: &D, b: &Vector2<f64>) -> Vector2<f64> {
+///     let x = decomp.solve(b).unwrap();
+///     decomp.solve(&x).unwrap()
+/// }
+///
+/// let m = Matrix2::new(4.0, 1.0,
+///                      1.0, 3.0);
+/// let b = Vector2::new(1.0, 2.0);
+/// let inv = m.try_inverse().unwrap();
+/// let expected = inv * inv * b;
+///
+/// assert_relative_eq!(solve_twice(&m.lu(), &b), expected, epsilon = 1.0e-12);
+/// assert_relative_eq!(solve_twice(&m.qr(), &b), expected, epsilon = 1.0e-12);
+/// assert_relative_eq!(solve_twice(&m.cholesky().unwrap(), &b), expected, epsilon = 1.0e-12);
+/// ```
+pub trait LinearSolve<T: ComplexField, D: Dim> {
+    /// Solves the linear system `A * X = B`, where `A` is the decomposed matrix and `X` the
+    /// unknown to be determined.
+    ///
+    /// Returns `None` if the decomposed matrix is not invertible.
+    fn solve<R2: Dim, C2: Dim, S2>(&self, b: &Matrix<T, R2, C2, S2>) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>;
+
+    /// Solves the linear system `A * X = B`, where `A` is the decomposed matrix and `X` the
+    /// unknown to be determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
+    /// be overwritten with garbage.
+    fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>;
+}
+
+macro_rules! impl_linear_solve(
+    ($Decomp: ident; $($bounds: tt)*) => {
+        impl<T: ComplexField, D: DimMin<D, Output = D>> LinearSolve<T, D> for $Decomp<T, D, D>
+        where
+            DefaultAllocator: $($bounds)*,
+        {
+            #[inline]
+            fn solve<R2: Dim, C2: Dim, S2>(
+                &self,
+                b: &Matrix<T, R2, C2, S2>,
+            ) -> Option<OMatrix<T, R2, C2>>
+            where
+                S2: Storage<T, R2, C2>,
+                ShapeConstraint: SameNumberOfRows<R2, D>,
+                DefaultAllocator: Allocator<T, R2, C2>,
+            {
+                $Decomp::solve(self, b)
+            }
+
+            #[inline]
+            fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+            where
+                S2: StorageMut<T, R2, C2>,
+                ShapeConstraint: SameNumberOfRows<R2, D>,
+            {
+                $Decomp::solve_mut(self, b)
+            }
+        }
+    }
+);
+
+impl_linear_solve!(LU; Allocator<T, D, D> + Allocator<(usize, usize), D>);
+impl_linear_solve!(FullPivLU; Allocator<T, D, D> + Allocator<(usize, usize), D>);
+impl_linear_solve!(QR; Allocator<T, D, D> + Allocator<T, D>);
+
+impl<T: ComplexField, D: Dim> LinearSolve<T, D> for Cholesky<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    /// Solves the linear system `A * X = B`, where `A` is the decomposed matrix and `X` the
+    /// unknown to be determined.
+    ///
+    /// A Cholesky decomposition always describes an invertible matrix, so this never returns
+    /// `None`.
+    #[inline]
+    fn solve<R2: Dim, C2: Dim, S2>(&self, b: &Matrix<T, R2, C2, S2>) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        Some(Cholesky::solve(self, b))
+    }
+
+    /// Solves the linear system `A * X = B`, where `A` is the decomposed matrix and `X` the
+    /// unknown to be determined.
+    ///
+    /// A Cholesky decomposition always describes an invertible matrix, so this always returns
+    /// `true`.
+    #[inline]
+    fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        Cholesky::solve_mut(self, b);
+        true
+    }
+}
+
+impl<T: ComplexField, D: DimMin<D, Output = D>, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
+    /// Solves the linear system `self * x = b` with a Cholesky decomposition if `self` is
+    /// hermitian positive-definite, and with an LU decomposition otherwise.
+    ///
+    /// The Cholesky decomposition is attempted only if `self` is hermitian up to `eps`, and it
+    /// is accepted only if every pivot encountered during the factorization, i.e. the square of
+    /// every diagonal entry of the Cholesky factor, is greater than `eps`.
+    ///
+    /// When the Cholesky attempt fails, the work spent on it is lost: solving a general system
+    /// then costs roughly one and a half times as much as a direct call to `self.lu().solve(b)`
+    /// in the worst case. If the matrix is known to be indefinite or non-hermitian, calling
+    /// [`lu`](Self::lu) directly avoids this overhead.
+    ///
+    /// Returns `None` if the matrix is not invertible.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2, Vector2};
+    /// let b = Vector2::new(1.0, 2.0);
+    ///
+    /// // Positive-definite: solved with a Cholesky decomposition.
+    /// let spd = Matrix2::new(4.0, 1.0,
+    ///                        1.0, 3.0);
+    /// let x = spd.solve_spd_or_general(&b, 1.0e-12).unwrap();
+    /// assert_relative_eq!(spd * x, b, epsilon = 1.0e-12);
+    ///
+    /// // Indefinite: solved with an LU decomposition.
+    /// let indefinite = Matrix2::new(1.0,  2.0,
+    ///                               2.0, -1.0);
+    /// let x = indefinite.solve_spd_or_general(&b, 1.0e-12).unwrap();
+    /// assert_relative_eq!(indefinite * x, b, epsilon = 1.0e-12);
+    /// ```
+    #[must_use]
+    pub fn solve_spd_or_general<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+        eps: T::RealField,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), D> + Allocator<T, R2, C2>,
+    {
+        assert!(
+            self.is_square(),
+            "Unable to solve a linear system with a non-square matrix."
+        );
+
+        if self.is_hermitian(eps) {
+            if let Some(chol) = Cholesky::new(self.clone_owned()) {
+                let l = chol.l_dirty();
+                let pivots_ok = (0..l.nrows()).all(|j| (l[(j, j)] * l[(j, j)]).real() > eps);
+
+                if pivots_ok {
+                    return Some(chol.solve(b));
+                }
+            }
+        }
+
+        LU::new(self.clone_owned()).solve(b)
+    }
+}
@@ -2,11 +2,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::allocator::{Allocator, Reallocator};
-use crate::base::{DefaultAllocator, Matrix, OMatrix, Scalar};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector, Scalar};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
-use crate::dimension::{Dim, DimMin, DimMinimum};
+use crate::dimension::{Const, Dim, DimMin, DimMinimum};
 use crate::storage::{Storage, StorageMut};
-use simba::scalar::{ComplexField, Field};
+use num::{One, Zero};
+use simba::scalar::{ComplexField, Field, RealField};
 use std::mem;
 
 use crate::linalg::PermutationSequence;
@@ -258,6 +259,70 @@ where
         self.lu.solve_upper_triangular_mut(b)
     }
 
+    /// Solves the linear system `self.transpose() * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// This reuses the factors of `self` and does not compute any transpose explicitly. Solving
+    /// `x * self = b` for row vectors (or matrices) `x` and `b` amounts to calling this with
+    /// `b.transpose()` and transposing the result. For complex matrices, note that this uses the
+    /// transpose and not the adjoint.
+    ///
+    /// Returns `None` if `self` is not invertible.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, RowVector3};
+    /// let a = Matrix3::new(0.0, 2.0, 1.0,
+    ///                      3.0, 1.0, 4.0,
+    ///                      1.0, 5.0, 9.0);
+    /// let b = RowVector3::new(1.0, -2.0, 0.5);
+    ///
+    /// // Solve `x * a = b` for the row vector `x`.
+    /// let x = a.lu().solve_transpose(&b.transpose()).unwrap().transpose();
+    /// assert_relative_eq!(x * a, b, epsilon = 1.0e-12);
+    /// ```
+    #[must_use = "Did you mean to use solve_transpose_mut()?"]
+    pub fn solve_transpose<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_transpose_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves the linear system `self.transpose() * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
+    /// be overwritten with garbage.
+    pub fn solve_transpose_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            self.lu.nrows(),
+            b.nrows(),
+            "LU solve matrix dimension mismatch."
+        );
+        assert!(
+            self.lu.is_square(),
+            "LU solve: unable to solve a non-square system."
+        );
+
+        self.xx_solve_mut(b, false)
+    }
+
     /// Computes the inverse of the decomposed matrix.
     ///
     /// Returns `None` if the matrix is not invertible.
@@ -328,6 +393,228 @@ where
 
         true
     }
+
+    /// Estimates the reciprocal of the condition number of the decomposed matrix, in the 1-norm.
+    ///
+    /// The matrix `a` must be the matrix that was decomposed to obtain `self`. Its 1-norm is
+    /// computed exactly, while the 1-norm of its inverse is estimated with Hager's method, as
+    /// refined by Higham. This only requires a few solves with the factors of `self` instead of the
+    /// computation of the inverse. The estimate of the norm of the inverse never exceeds its
+    /// actual value and is usually within a factor of 3 of it, so the returned value is an upper
+    /// bound of the actual reciprocal condition number.
+    ///
+    /// Returns zero if the matrix is singular, and one if it is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let well_conditioned = Matrix2::new(2.0, 0.0,
+    ///                                     0.0, 1.0);
+    /// assert_eq!(well_conditioned.lu().rcond(&well_conditioned), 0.5);
+    ///
+    /// let ill_conditioned = Matrix2::new(1.0, 1.0,
+    ///                                    1.0, 1.0 + 1.0e-10);
+    /// assert!(ill_conditioned.lu().rcond(&ill_conditioned) < 1.0e-9);
+    /// ```
+    #[must_use]
+    pub fn rcond<S>(&self, a: &Matrix<T, D, D, S>) -> T::RealField
+    where
+        S: Storage<T, D, D>,
+        DefaultAllocator: Allocator<T, D>,
+    {
+        assert_eq!(
+            self.lu.shape(),
+            a.shape(),
+            "LU rcond: the matrix must have the same dimensions as the decomposed matrix."
+        );
+
+        let n = self.lu.nrows();
+        if n == 0 {
+            return T::RealField::one();
+        }
+        if !self.is_invertible() {
+            return T::RealField::zero();
+        }
+
+        let norm1 = |v: &OVector<T, D>| {
+            v.iter()
+                .fold(T::RealField::zero(), |acc, e| acc + e.modulus())
+        };
+
+        let mut a_norm = T::RealField::zero();
+        for col in a.column_iter() {
+            let col_norm = col
+                .iter()
+                .fold(T::RealField::zero(), |acc, e| acc + e.modulus());
+            a_norm = a_norm.max(col_norm);
+        }
+        if a_norm.is_zero() {
+            return T::RealField::zero();
+        }
+
+        // Hager's iteration, starting from a vector of unit 1-norm.
+        let dim = self.lu.data.shape().0;
+        let n_real: T::RealField = crate::convert(n as f64);
+        let mut x = OVector::from_element_generic(
+            dim,
+            Const::<1>,
+            T::from_real(T::RealField::one() / n_real),
+        );
+        let mut inv_norm = T::RealField::zero();
+        let mut last_j = None;
+
+        for _ in 0..5 {
+            let _ = self.solve_mut(&mut x);
+            let estimate = norm1(&x);
+            if last_j.is_some() && estimate <= inv_norm {
+                break;
+            }
+            inv_norm = estimate;
+
+            x.apply(|e| e.signum());
+            self.ad_solve_mut(&mut x);
+
+            let mut j = 0;
+            for i in 1..n {
+                if x[i].modulus() > x[j].modulus() {
+                    j = i;
+                }
+            }
+
+            if last_j == Some(j) {
+                break;
+            }
+            last_j = Some(j);
+
+            x.fill(T::zero());
+            x[j] = T::one();
+        }
+
+        // Higham's alternative estimate, which catches the cases where Hager's iteration
+        // stopped at a poor local maximum.
+        if n > 1 {
+            let mut alt = OVector::from_fn_generic(dim, Const::<1>, |i, _| {
+                let sign = if i % 2 == 0 {
+                    T::RealField::one()
+                } else {
+                    -T::RealField::one()
+                };
+                let i: T::RealField = crate::convert(i as f64);
+                let n_minus_one: T::RealField = crate::convert((n - 1) as f64);
+                T::from_real(sign * (T::RealField::one() + i / n_minus_one))
+            });
+            let _ = self.solve_mut(&mut alt);
+            let alt_estimate = norm1(&alt) * crate::convert(2.0) / (n_real * crate::convert(3.0));
+            inv_norm = inv_norm.max(alt_estimate);
+        }
+
+        T::RealField::one() / (a_norm * inv_norm)
+    }
+
+    /// Solves the linear system `self * x = b` and checks that the decomposed matrix is not
+    /// ill-conditioned.
+    ///
+    /// The matrix `a` must be the matrix that was decomposed to obtain `self`. Its reciprocal
+    /// condition number is estimated with [`rcond`](Self::rcond). If it is smaller than
+    /// `rcond_threshold`, or if the matrix is singular, a [`SolveWarning`] carrying the estimated
+    /// reciprocal condition number and the solution (if any) is returned instead of the solution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2, Vector2};
+    /// let a = Matrix2::new(1.0, 1.0,
+    ///                      1.0, 1.0 + 1.0e-10);
+    /// let b = Vector2::new(2.0, 2.0);
+    ///
+    /// let warning = a.lu().solve_checked(&a, &b, 1.0e-8).unwrap_err();
+    /// assert!(warning.rcond < 1.0e-8);
+    /// assert!(warning.solution.is_some());
+    ///
+    /// let x = Matrix2::<f64>::identity().lu().solve_checked(&Matrix2::identity(), &b, 1.0e-8);
+    /// assert_eq!(x, Ok(b));
+    /// ```
+    pub fn solve_checked<S, R2: Dim, C2: Dim, S2>(
+        &self,
+        a: &Matrix<T, D, D, S>,
+        b: &Matrix<T, R2, C2, S2>,
+        rcond_threshold: T::RealField,
+    ) -> Result<OMatrix<T, R2, C2>, SolveWarning<T::RealField, OMatrix<T, R2, C2>>>
+    where
+        S: Storage<T, D, D>,
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, D> + Allocator<T, R2, C2>,
+    {
+        let rcond = self.rcond(a);
+        match self.solve(b) {
+            Some(solution) if rcond >= rcond_threshold => Ok(solution),
+            solution => Err(SolveWarning { rcond, solution }),
+        }
+    }
+
+    /// Solves the linear system `self.adjoint() * x = b` for an invertible decomposed matrix.
+    fn ad_solve_mut<R2: Dim, S2>(&self, b: &mut Matrix<T, R2, Const<1>, S2>)
+    where
+        S2: StorageMut<T, R2, Const<1>>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        let _ = self.xx_solve_mut(b, true);
+    }
+
+    /// Solves the linear system `self.transpose() * x = b`, or `self.adjoint() * x = b` if
+    /// `conjugate` is `true`.
+    ///
+    /// Returns `false` if the decomposed matrix is not invertible.
+    fn xx_solve_mut<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &mut Matrix<T, R2, C2, S2>,
+        conjugate: bool,
+    ) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        // P * A = L * U, so A^T = U^T * L^T * P and A^H = U^H * L^H * P.
+        let solved = if conjugate {
+            self.lu.ad_solve_upper_triangular_mut(b)
+        } else {
+            self.lu.tr_solve_upper_triangular_mut(b)
+        };
+
+        if !solved {
+            return false;
+        }
+
+        // L has a unit diagonal that is not stored.
+        for mut col in b.column_iter_mut() {
+            for i in (0..self.lu.nrows()).rev() {
+                let l_col = self.lu.slice_range(i + 1.., i);
+                let tail = col.rows_range(i + 1..);
+                let dot = if conjugate {
+                    l_col.dotc(&tail)
+                } else {
+                    l_col.dot(&tail)
+                };
+                col[i] -= dot;
+            }
+        }
+
+        self.p.inv_permute_rows(b);
+        true
+    }
+}
+
+/// Warning returned by [`LU::solve_checked`] when the system to solve is ill-conditioned.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolveWarning<T, S> {
+    /// The estimated reciprocal condition number of the decomposed matrix, in the 1-norm.
+    ///
+    /// This is zero if the matrix is singular.
+    pub rcond: T,
+    /// The solution of the system, or `None` if the matrix is singular.
+    pub solution: Option<S>,
 }
 
 #[doc(hidden)]
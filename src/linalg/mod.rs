@@ -5,6 +5,7 @@ mod bidiagonal;
 mod cholesky;
 mod convolution;
 mod determinant;
+mod exact_lu;
 // TODO: this should not be needed. However, the exp uses
 // explicit float operations on `f32` and `f64`. We need to
 // get rid of these to allow exp to be used on a no-std context.
@@ -12,13 +13,24 @@ mod col_piv_qr;
 mod decomposition;
 #[cfg(feature = "std")]
 mod exp;
+#[cfg(feature = "std")]
+mod factorized;
 mod full_piv_lu;
 pub mod givens;
 mod hessenberg;
 pub mod householder;
 mod inverse;
+mod linear_solve;
 mod lu;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod orthogonal_iteration;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod packed_cholesky;
 mod permutation_sequence;
+mod pivoted_cholesky;
+mod polar;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod polynomial;
 mod pow;
 mod qr;
 mod schur;
@@ -26,6 +38,8 @@ mod solve;
 mod svd;
 mod symmetric_eigen;
 mod symmetric_tridiagonal;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod tridiagonal;
 mod udu;
 
 //// TODO: Not complete enough for publishing.
@@ -36,16 +50,28 @@ pub use self::bidiagonal::*;
 pub use self::cholesky::*;
 pub use self::col_piv_qr::*;
 pub use self::convolution::*;
+pub use self::exact_lu::*;
 #[cfg(feature = "std")]
 pub use self::exp::*;
+#[cfg(feature = "std")]
+pub use self::factorized::*;
 pub use self::full_piv_lu::*;
 pub use self::hessenberg::*;
+pub use self::linear_solve::*;
 pub use self::lu::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::packed_cholesky::*;
 pub use self::permutation_sequence::*;
+pub use self::pivoted_cholesky::*;
+pub use self::polar::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::polynomial::*;
 pub use self::pow::*;
 pub use self::qr::*;
 pub use self::schur::*;
 pub use self::svd::*;
 pub use self::symmetric_eigen::*;
 pub use self::symmetric_tridiagonal::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::tridiagonal::*;
 pub use self::udu::*;
@@ -0,0 +1,113 @@
+use num::Zero;
+use simba::scalar::ComplexField;
+
+use crate::base::{DMatrix, DVector};
+
+use crate::linalg::QR;
+
+impl<T: ComplexField> DMatrix<T> {
+    /// Computes an orthonormal basis of the dominant invariant subspace of dimension `k` of this
+    /// square matrix, using orthogonal iteration.
+    ///
+    /// Starting from an `n x k` matrix `Q₀` with orthonormal columns, this repeatedly computes
+    /// the QR decomposition `Qᵢ₊₁ Rᵢ₊₁ = A Qᵢ`. This is a block version of the power iteration,
+    /// with a reorthonormalization at each step. Each iteration costs `O(n² k)` operations, so
+    /// this is much cheaper than a full Schur decomposition when `k` is much smaller than `n`.
+    ///
+    /// Returns `(q, ritz_values)` where the columns of the `n x k` matrix `q` are the `k`
+    /// dominant Schur vectors of this matrix, and `ritz_values` is the diagonal of the
+    /// upper-triangular matrix `qᴴ * self * q`, i.e., the approximations of the `k` eigenvalues
+    /// of largest modulus, sorted by decreasing modulus. The convergence speed depends on the
+    /// ratios between the moduli of consecutive eigenvalues among the `k + 1` largest ones, so
+    /// the iteration may not converge if two of them have the same modulus. In particular, the
+    /// dominant eigenvalues of a real matrix must be real, which is always the case for
+    /// symmetric matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `k`         − dimension of the computed subspace.
+    /// * `eps`       − tolerance on the norms of the residual `self * q - q * qᴴ * self * q` and
+    ///   of the strictly lower-triangular part of `qᴴ * self * q`, relative to the norm of
+    ///   `self`.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    ///   number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    ///   continues indefinitely until convergence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square or if `k` is larger than its dimension.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{DMatrix, DVector};
+    /// let m = DMatrix::from_diagonal(&DVector::from_vec(vec![1.0f64, -8.0, 0.5, 4.0, 2.0]));
+    /// let (q, ritz_values) = m.orthogonal_iteration(2, 1.0e-12, 1000).unwrap();
+    ///
+    /// assert_relative_eq!(ritz_values, DVector::from_vec(vec![-8.0, 4.0]), epsilon = 1.0e-10);
+    /// assert_relative_eq!(q.column(0).amax(), 1.0, epsilon = 1.0e-10);
+    /// assert_relative_eq!(q[(1, 0)].abs(), 1.0, epsilon = 1.0e-10);
+    /// assert_relative_eq!(q[(3, 1)].abs(), 1.0, epsilon = 1.0e-10);
+    /// ```
+    pub fn orthogonal_iteration(
+        &self,
+        k: usize,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<(DMatrix<T>, DVector<T>)> {
+        assert!(
+            self.is_square(),
+            "Unable to compute the invariant subspace of a non-square matrix."
+        );
+
+        let n = self.nrows();
+        assert!(
+            k <= n,
+            "The dimension of the invariant subspace must not exceed the matrix dimension."
+        );
+
+        if k == 0 {
+            return Some((DMatrix::zeros(n, 0), DVector::zeros(0)));
+        }
+
+        // This starting basis is not orthogonal to the dominant subspace for most matrices,
+        // unlike the columns of the identity that are often eigenvectors themselves.
+        let start = DMatrix::from_fn(n, k, |i, j| {
+            if i == j {
+                T::one() + T::one()
+            } else {
+                T::one()
+            }
+        });
+        let mut q = QR::new(start).q();
+
+        let tol = eps * self.norm();
+        let mut niter = 0;
+
+        loop {
+            let aq = self * &q;
+            let t = q.ad_mul(&aq);
+            let residual = &aq - &q * &t;
+
+            // The spanned subspace must be invariant, and `t` upper-triangular so that the
+            // columns of `q` are Schur vectors.
+            let mut lower_norm_squared = T::RealField::zero();
+            for j in 0..k {
+                for i in j + 1..k {
+                    lower_norm_squared += t[(i, j)].modulus_squared();
+                }
+            }
+
+            if residual.norm() <= tol && lower_norm_squared.sqrt() <= tol {
+                return Some((q, t.diagonal()));
+            }
+
+            niter += 1;
+            if niter == max_niter {
+                return None;
+            }
+
+            q = QR::new(aq).q();
+        }
+    }
+}
@@ -0,0 +1,180 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+
+use simba::scalar::RealField;
+
+use crate::allocator::Allocator;
+use crate::base::{packed_lower_index, DefaultAllocator, Matrix, OMatrix, SymmetricMatrix};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::dimension::Dim;
+use crate::storage::{Storage, StorageMut};
+
+/// The Cholesky decomposition of a symmetric-definite-positive matrix stored in packed form.
+///
+/// The lower-triangular factor `L` is stored column-by-column in the same packed layout as
+/// [`SymmetricMatrix`], so the decomposition does not use more memory than the decomposed
+/// matrix.
+#[derive(Clone, Debug)]
+pub struct PackedCholesky<T: RealField, D: Dim> {
+    dim: D,
+    l: Vec<T>,
+}
+
+impl<T: RealField, D: Dim> SymmetricMatrix<T, D> {
+    /// Attempts to compute the Cholesky decomposition of this matrix, directly on its packed
+    /// storage.
+    ///
+    /// The factorization is computed in place, so this consumes the matrix; clone it first if it
+    /// is still needed. Returns `None` if the matrix is not definite-positive.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{DVector, SymmetricMatrix};
+    /// // A 1D Laplacian, which is symmetric definite-positive.
+    /// let n = 50;
+    /// let m = SymmetricMatrix::from_fn(n, |i, j| if i == j { 2.0 } else if i == j + 1 { -1.0 } else { 0.0 });
+    /// let b = DVector::from_fn(n, |i, _| i as f64);
+    ///
+    /// let x = m.clone().cholesky().unwrap().solve(&b);
+    /// assert_relative_eq!(m.mul_vector(&x), b, epsilon = 1.0e-9);
+    /// ```
+    pub fn cholesky(self) -> Option<PackedCholesky<T, D>> {
+        let n = self.dim();
+        let dim = self.dim_generic();
+        let mut l = self.into_packed_vec();
+
+        for j in 0..n {
+            let col_j_start = packed_lower_index(n, j, j);
+            let (left, right) = l.split_at_mut(col_j_start);
+            let col_j = &mut right[..n - j];
+
+            // Subtract the contributions of the previous columns to the rows `j..` of the
+            // column `j`. These rows are stored contiguously in each column.
+            for k in 0..j {
+                let start = packed_lower_index(n, j, k);
+                let col_k = &left[start..start + n - j];
+                let l_jk = col_k[0];
+
+                for (l_ij, l_ik) in col_j.iter_mut().zip(col_k) {
+                    *l_ij -= *l_ik * l_jk;
+                }
+            }
+
+            // This also rejects a NaN pivot.
+            let diag = col_j[0];
+            if diag.partial_cmp(&T::zero()) != Some(Ordering::Greater) {
+                return None;
+            }
+
+            let denom = diag.sqrt();
+            col_j[0] = denom;
+            for l_ij in &mut col_j[1..] {
+                *l_ij /= denom;
+            }
+        }
+
+        Some(PackedCholesky { dim, l })
+    }
+}
+
+impl<T: RealField, D: Dim> PackedCholesky<T, D> {
+    /// The lower-triangular factor `L` packed column-by-column.
+    #[inline]
+    #[must_use]
+    pub fn l_packed(&self) -> &[T] {
+        &self.l
+    }
+
+    /// Retrieves the lower-triangular factor of the Cholesky decomposition, with its strictly
+    /// upper-triangular part filled with zeros.
+    #[must_use]
+    pub fn l(&self) -> OMatrix<T, D, D>
+    where
+        DefaultAllocator: Allocator<T, D, D>,
+    {
+        let n = self.dim.value();
+        OMatrix::from_fn_generic(self.dim, self.dim, |i, j| {
+            if i >= j {
+                self.l[packed_lower_index(n, i, j)]
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Solves the system `self * x = b` where `self` is the decomposed matrix and `x` the unknown.
+    ///
+    /// The result is stored on `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `b` does not match the dimension of the decomposed matrix.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>)
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        let n = self.dim.value();
+        assert_eq!(
+            b.nrows(),
+            n,
+            "Cholesky solve: the matrix dimension does not match the number of rows of b."
+        );
+
+        for mut b in b.column_iter_mut() {
+            // Forward substitution with `L`, traversing `L` column-by-column.
+            for j in 0..n {
+                let col_j = &self.l[packed_lower_index(n, j, j)..][..n - j];
+                b[j] /= col_j[0];
+                let b_j = b[j];
+
+                for (i, l_ij) in col_j.iter().enumerate().skip(1) {
+                    b[j + i] -= *l_ij * b_j;
+                }
+            }
+
+            // Backward substitution with `Lᵀ`, whose rows are the columns of `L`.
+            for j in (0..n).rev() {
+                let col_j = &self.l[packed_lower_index(n, j, j)..][..n - j];
+                let mut b_j = b[j];
+
+                for (i, l_ij) in col_j.iter().enumerate().skip(1) {
+                    b_j -= *l_ij * b[j + i];
+                }
+
+                b[j] = b_j / col_j[0];
+            }
+        }
+    }
+
+    /// Returns the solution of the system `self * x = b` where `self` is the decomposed matrix and
+    /// `x` the unknown.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `b` does not match the dimension of the decomposed matrix.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<R2: Dim, C2: Dim, S2>(&self, b: &Matrix<T, R2, C2, S2>) -> OMatrix<T, R2, C2>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        let mut res = b.clone_owned();
+        self.solve_mut(&mut res);
+        res
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    #[must_use]
+    pub fn determinant(&self) -> T {
+        let n = self.dim.value();
+        let mut prod_diag = T::one();
+        for j in 0..n {
+            prod_diag *= self.l[packed_lower_index(n, j, j)];
+        }
+        prod_diag * prod_diag
+    }
+}
@@ -0,0 +1,189 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use simba::scalar::ComplexField;
+use std::cmp::Ordering;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, OMatrix};
+use crate::dimension::Dim;
+use crate::storage::Storage;
+
+use crate::linalg::{Cholesky, PermutationSequence};
+
+/// The Cholesky decomposition with symmetric pivoting of a hermitian positive-semidefinite
+/// matrix.
+///
+/// This computes a permutation `P` and a lower-triangular matrix `L` such that
+/// `P * A * Pᵀ = L * Lᴴ`. At each step, the largest remaining diagonal element is chosen as the
+/// pivot, and the factorization stops as soon as it is not greater than the given tolerance. The
+/// number of steps performed is the numerical rank of `A`: only the first `rank` columns of `L`
+/// are non-zero, so they can be used as a low-rank factor of `A`.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, D, D> +
+                           Allocator<(usize, usize), D>,
+         OMatrix<T, D, D>: Serialize,
+         PermutationSequence<D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, D, D> +
+                           Allocator<(usize, usize), D>,
+         OMatrix<T, D, D>: Deserialize<'de>,
+         PermutationSequence<D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct PivotedCholesky<T: ComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), D>,
+{
+    chol: OMatrix<T, D, D>,
+    p: PermutationSequence<D>,
+    rank: usize,
+}
+
+impl<T: ComplexField, D: Dim> Copy for PivotedCholesky<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), D>,
+    OMatrix<T, D, D>: Copy,
+    PermutationSequence<D>: Copy,
+{
+}
+
+impl<T: ComplexField, D: Dim> PivotedCholesky<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), D>,
+{
+    /// Computes the pivoted Cholesky decomposition of `matrix`.
+    ///
+    /// The input matrix is assumed to be hermitian and only its lower-triangular part is read.
+    /// The factorization stops when every remaining pivot is smaller than or equal to `eps`. If
+    /// the matrix is indefinite, it therefore stops at the latest when only non-positive pivots
+    /// remain, in which case `P * A * Pᵀ = L * Lᴴ` does not hold.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// # use nalgebra::linalg::PivotedCholesky;
+    /// // A rank-2 covariance matrix.
+    /// let u = Vector3::new(1.0, 2.0, 3.0);
+    /// let v = Vector3::new(0.0, 1.0, -1.0);
+    /// let m = u * u.transpose() + v * v.transpose();
+    ///
+    /// let chol = PivotedCholesky::new(m, 1.0e-10);
+    /// assert_eq!(chol.rank(), 2);
+    ///
+    /// let l = chol.l();
+    /// let mut reconstructed = l * l.transpose();
+    /// chol.p().inv_permute_rows(&mut reconstructed);
+    /// chol.p().inv_permute_columns(&mut reconstructed);
+    /// assert_relative_eq!(reconstructed, m, epsilon = 1.0e-10);
+    /// ```
+    pub fn new(mut matrix: OMatrix<T, D, D>, eps: T::RealField) -> Self {
+        assert!(matrix.is_square(), "The input matrix must be square.");
+
+        let n = matrix.nrows();
+        let mut p = PermutationSequence::identity_generic(matrix.data.shape().0);
+
+        // Only the lower-triangular part is read, so we start by making the matrix hermitian.
+        for j in 0..n {
+            for i in 0..j {
+                matrix[(i, j)] = matrix[(j, i)].conjugate();
+            }
+        }
+
+        let mut rank = n;
+
+        for j in 0..n {
+            let mut piv = j;
+            let mut max_pivot = matrix[(j, j)].real();
+            for i in j + 1..n {
+                let pivot = matrix[(i, i)].real();
+                if pivot > max_pivot {
+                    piv = i;
+                    max_pivot = pivot;
+                }
+            }
+
+            // This also stops the factorization if the pivot is NaN.
+            if max_pivot.partial_cmp(&eps) != Some(Ordering::Greater) {
+                rank = j;
+                break;
+            }
+
+            if piv != j {
+                matrix.swap_rows(j, piv);
+                matrix.swap_columns(j, piv);
+                p.append_permutation(j, piv);
+            }
+
+            let denom = max_pivot.sqrt();
+            matrix[(j, j)] = T::from_real(denom);
+
+            let (mut col_j, mut right) = matrix.columns_range_pair_mut(j, j + 1..);
+            let mut col_j = col_j.rows_range_mut(j + 1..);
+            col_j.unscale_mut(denom);
+
+            // Update the trailing submatrix with the new column of `L`.
+            right
+                .rows_range_mut(j + 1..)
+                .gerc(-T::one(), &col_j, &col_j, T::one());
+        }
+
+        matrix.columns_range_mut(rank..).fill(T::zero());
+        matrix.fill_upper_triangle(T::zero(), 1);
+
+        PivotedCholesky {
+            chol: matrix,
+            p,
+            rank,
+        }
+    }
+
+    /// The numerical rank of the decomposed matrix, i.e., the number of non-zero columns of `L`.
+    #[inline]
+    #[must_use]
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// The permutation `P` of this decomposition.
+    #[inline]
+    #[must_use]
+    pub fn p(&self) -> &PermutationSequence<D> {
+        &self.p
+    }
+
+    /// Retrieves the lower-triangular factor `L` of this decomposition.
+    ///
+    /// Only its first [`rank`](Self::rank) columns are non-zero.
+    #[inline]
+    #[must_use]
+    pub fn l(&self) -> OMatrix<T, D, D> {
+        self.chol.clone()
+    }
+
+    /// Retrieves the permutation `P` and the lower-triangular factor `L` of this decomposition.
+    #[inline]
+    pub fn unpack(self) -> (PermutationSequence<D>, OMatrix<T, D, D>) {
+        (self.p, self.chol)
+    }
+}
+
+impl<T: ComplexField, D: Dim> Cholesky<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<(usize, usize), D>,
+{
+    /// Computes the Cholesky decomposition with symmetric pivoting of the hermitian
+    /// positive-semidefinite `matrix`, stopping when every remaining pivot is smaller than or
+    /// equal to `eps`.
+    ///
+    /// This is a shorthand for [`PivotedCholesky::new`]. Unlike [`Cholesky::new`], this accepts
+    /// rank-deficient matrices.
+    pub fn new_pivoted(matrix: OMatrix<T, D, D>, eps: T::RealField) -> PivotedCholesky<T, D> {
+        PivotedCholesky::new(matrix, eps)
+    }
+}
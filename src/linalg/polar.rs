@@ -0,0 +1,208 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, OMatrix};
+use crate::dimension::{Dim, DimDiff, DimMin, DimSub, U1};
+use crate::linalg::SVD;
+
+/// The polar decomposition `A = U * P` of a square matrix.
+///
+/// `U` is an orthogonal (or unitary, for complex matrices) matrix and `P` is a hermitian
+/// semi-definite-positive matrix.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "OMatrix<T, D, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "OMatrix<T, D, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct Polar<T: ComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    u: OMatrix<T, D, D>,
+    p: OMatrix<T, D, D>,
+}
+
+impl<T: ComplexField, D: Dim> Copy for Polar<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D>,
+    OMatrix<T, D, D>: Copy,
+{
+}
+
+impl<T: ComplexField, D: Dim> Polar<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    /// Computes the polar decomposition of the invertible matrix `matrix` using Higham's scaled
+    /// Newton iteration `X_{k+1} = (ζ_k X_k + ζ_k⁻¹ X_k⁻ᴴ) / 2`, starting from `X_0 = matrix`.
+    ///
+    /// This does not require any SVD and usually converges in a handful of iterations for
+    /// well-conditioned matrices. The scaling factors `ζ_k` are chosen to minimize the Frobenius
+    /// norm of the iterates and are dropped once the iteration enters its quadratic convergence
+    /// regime.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − the iteration stops when `‖X_{k+1} - X_k‖ <= eps * ‖X_{k+1}‖` (Frobenius
+    /// norms). Because the residual `‖A - U P‖` of the resulting factors is controlled by this
+    /// relative change, `eps` also bounds the relative accuracy of the decomposition.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    /// continues indefinitely until convergence.
+    ///
+    /// Returns `None` if `matrix` (or one of the iterates) is not invertible, or if the iteration
+    /// did not converge. Use [`Polar::try_new`] for singular matrices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `matrix` is not square.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Polar};
+    /// let m = Matrix3::new(4.0, 1.0, -2.0,
+    ///                      0.5, 3.0,  1.0,
+    ///                      1.0, 0.0,  5.0);
+    /// let polar = Polar::new_newton(m, 1.0e-12, 100).unwrap();
+    ///
+    /// assert_relative_eq!(polar.u().transpose() * polar.u(), Matrix3::identity(), epsilon = 1.0e-10);
+    /// assert_relative_eq!(polar.recompose(), m, epsilon = 1.0e-10);
+    /// ```
+    pub fn new_newton(
+        matrix: OMatrix<T, D, D>,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<Self> {
+        assert!(
+            matrix.is_square(),
+            "Unable to compute the polar decomposition of a non-square matrix."
+        );
+
+        if matrix.is_empty() {
+            return Some(Self {
+                u: matrix.clone(),
+                p: matrix,
+            });
+        }
+
+        let half: T::RealField = crate::convert(0.5);
+        let scaling_threshold: T::RealField = crate::convert(1.0e-2);
+        let mut x = matrix.clone();
+        let mut scaled = true;
+        let mut niter = 0;
+
+        loop {
+            let x_inv = x.clone().try_inverse()?;
+
+            let mut next = if scaled {
+                let zeta = (x_inv.norm() / x.norm()).sqrt();
+                x.scale(zeta) + x_inv.adjoint().unscale(zeta)
+            } else {
+                &x + x_inv.adjoint()
+            };
+            next.scale_mut(half);
+
+            let delta = (&next - &x).norm();
+            let next_norm = next.norm();
+            x = next;
+
+            if !delta.is_finite() || !next_norm.is_finite() {
+                return None;
+            }
+
+            if delta <= eps * next_norm {
+                break;
+            }
+
+            if delta <= scaling_threshold * next_norm {
+                scaled = false;
+            }
+
+            niter += 1;
+            if niter == max_niter {
+                return None;
+            }
+        }
+
+        let p = x.ad_mul(&matrix);
+        let p = (&p + p.adjoint()).scale(half);
+
+        Some(Self { u: x, p })
+    }
+
+    /// The orthogonal (or unitary) factor `U` of this decomposition.
+    #[inline]
+    #[must_use]
+    pub fn u(&self) -> &OMatrix<T, D, D> {
+        &self.u
+    }
+
+    /// The hermitian semi-definite-positive factor `P` of this decomposition.
+    #[inline]
+    #[must_use]
+    pub fn p(&self) -> &OMatrix<T, D, D> {
+        &self.p
+    }
+
+    /// Retrieves the factors `(U, P)` of this decomposition.
+    #[inline]
+    pub fn unpack(self) -> (OMatrix<T, D, D>, OMatrix<T, D, D>) {
+        (self.u, self.p)
+    }
+
+    /// Computes the original matrix `U * P` from its polar decomposition.
+    #[must_use]
+    pub fn recompose(&self) -> OMatrix<T, D, D> {
+        &self.u * &self.p
+    }
+}
+
+impl<T: ComplexField, D: DimMin<D, Output = D>> Polar<T, D>
+where
+    D: DimSub<U1>, // for Bidiagonal.
+    DefaultAllocator: Allocator<T, D, D>
+        + Allocator<T, D>
+        + Allocator<T, DimDiff<D, U1>>
+        + Allocator<T::RealField, D>
+        + Allocator<T::RealField, DimDiff<D, U1>>,
+{
+    /// Computes the polar decomposition of `matrix` from its Singular Value Decomposition.
+    ///
+    /// With `matrix = W Σ Vᴴ`, the factors are `U = W Vᴴ` and `P = V Σ Vᴴ`. Unlike
+    /// [`Polar::new_newton`], this also works for singular matrices.
+    ///
+    /// The arguments `eps` and `max_niter` are forwarded to [`SVD::try_new`]. Returns `None` if
+    /// the SVD did not converge.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `matrix` is not square.
+    pub fn try_new(matrix: OMatrix<T, D, D>, eps: T::RealField, max_niter: usize) -> Option<Self> {
+        assert!(
+            matrix.is_square(),
+            "Unable to compute the polar decomposition of a non-square matrix."
+        );
+
+        let svd = SVD::try_new(matrix, true, true, eps, max_niter)?;
+        let w = svd.u?;
+        let v_t = svd.v_t?;
+
+        let u = &w * &v_t;
+        let mut sigma_v_t = v_t.clone();
+        for (mut row, s) in sigma_v_t.row_iter_mut().zip(svd.singular_values.iter()) {
+            row *= T::from_real(*s);
+        }
+        let p = v_t.ad_mul(&sigma_v_t);
+
+        Some(Self { u, p })
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use num::{One, Zero};
+use num_complex::Complex;
+use simba::scalar::{ClosedNeg, RealField};
+
+use crate::base::{DMatrix, Scalar};
+use crate::linalg::{balancing, Schur};
+
+impl<T: Scalar + Zero + One + ClosedNeg> DMatrix<T> {
+    /// Builds the companion matrix of the monic polynomial
+    /// `xⁿ + coeffs[0] xⁿ⁻¹ + … + coeffs[n - 2] x + coeffs[n - 1]`.
+    ///
+    /// The leading coefficient `1` is implicit and must not be part of `coeffs`. The result is the
+    /// `n × n` upper Hessenberg matrix with `-coeffs` on its first row and ones on its
+    /// subdiagonal. Its characteristic polynomial is the given polynomial, so its eigenvalues are
+    /// the roots of the polynomial (see [`polynomial_roots`]).
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix3};
+    /// // x³ - 6x² + 11x - 6 = (x - 1)(x - 2)(x - 3)
+    /// let companion = DMatrix::companion(&[-6.0, 11.0, -6.0]);
+    /// assert_eq!(companion, Matrix3::new(6.0, -11.0, 6.0,
+    ///                                    1.0,   0.0, 0.0,
+    ///                                    0.0,   1.0, 0.0));
+    /// ```
+    pub fn companion(coeffs: &[T]) -> Self {
+        let n = coeffs.len();
+        let mut res = DMatrix::zeros(n, n);
+
+        for (j, coeff) in coeffs.iter().enumerate() {
+            res[(0, j)] = -coeff.inlined_clone();
+        }
+
+        for i in 1..n {
+            res[(i, i - 1)] = T::one();
+        }
+
+        res
+    }
+}
+
+/// Computes the complex roots of the polynomial
+/// `coeffs[0] xⁿ + coeffs[1] xⁿ⁻¹ + … + coeffs[n - 1] x + coeffs[n]`.
+///
+/// The coefficients are given by decreasing degree and do not need to be normalized. The roots
+/// are computed as the eigenvalues of the balanced companion matrix (see [`DMatrix::companion`])
+/// of the polynomial, which are obtained from its real [`Schur`] decomposition. Each root
+/// appears as many times as its multiplicity, in no particular order.
+///
+/// Leading zero coefficients are ignored, so that the degree of the polynomial is that of its
+/// first non-zero coefficient, and trailing zero coefficients yield exact zero roots. Constant
+/// polynomials, including the zero polynomial and an empty `coeffs`, have no roots.
+///
+/// # Arguments
+///
+/// * `eps`       − tolerance used to determine when a value converged to 0 in the Schur
+///   decomposition.
+/// * `max_niter` − maximum total number of iterations performed by the Schur decomposition. If
+///   this number of iteration is exceeded, `None` is returned. If `niter == 0`, then the
+///   algorithm continues indefinitely until convergence. Because the Schur decomposition
+///   converges slowly on repeated roots, it may then never terminate if `eps` is too small: a
+///   finite `max_niter` should be preferred.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{polynomial_roots, Complex};
+/// // 2x³ - 2 has the three cubic roots of unity as roots.
+/// let mut roots = polynomial_roots(&[0.0, 2.0, 0.0, 0.0, -2.0], 1.0e-12, 1000).unwrap();
+/// roots.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+///
+/// let half_sqrt3 = 3.0f64.sqrt() / 2.0;
+/// assert_relative_eq!(roots[0], Complex::new(-0.5, -half_sqrt3), epsilon = 1.0e-12);
+/// assert_relative_eq!(roots[1], Complex::new(1.0, 0.0), epsilon = 1.0e-12);
+/// assert_relative_eq!(roots[2], Complex::new(-0.5, half_sqrt3), epsilon = 1.0e-12);
+/// ```
+pub fn polynomial_roots<T: RealField>(
+    coeffs: &[T],
+    eps: T,
+    max_niter: usize,
+) -> Option<Vec<Complex<T>>> {
+    let first_non_zero = coeffs
+        .iter()
+        .position(|c| !c.is_zero())
+        .unwrap_or(coeffs.len());
+    let coeffs = &coeffs[first_non_zero..];
+    let nzero_roots = coeffs.iter().rev().take_while(|c| c.is_zero()).count();
+    let coeffs = &coeffs[..coeffs.len() - nzero_roots];
+
+    let mut roots = Vec::with_capacity(coeffs.len().saturating_sub(1) + nzero_roots);
+
+    if coeffs.len() > 1 {
+        let leading = coeffs[0];
+        let monic: Vec<T> = coeffs[1..].iter().map(|c| *c / leading).collect();
+        let mut companion = DMatrix::companion(&monic);
+        let _ = balancing::balance_parlett_reinsch(&mut companion);
+
+        let schur = Schur::try_new(companion, eps, max_niter)?;
+        roots.extend(schur.complex_eigenvalues().iter().copied());
+    }
+
+    roots.extend((0..nzero_roots).map(|_| Complex::zero()));
+    Some(roots)
+}
@@ -3,6 +3,7 @@
 use serde::{Deserialize, Serialize};
 
 use approx::AbsDiffEq;
+use num::One;
 use num_complex::Complex as NumComplex;
 use simba::scalar::{ComplexField, RealField};
 use std::cmp;
@@ -129,10 +130,13 @@ where
 
         // Implicit double-shift QR method.
         let mut niter = 0;
+        // Number of iterations performed since the last time the subproblem shrunk.
+        let mut niter_no_deflation = 0;
         let (mut start, mut end) = Self::delimit_subproblem(&mut t, eps, dim.value() - 1);
 
         while end != start {
             let subdim = end - start + 1;
+            let prev_end = end;
 
             if subdim > 2 {
                 let m = end - 1;
@@ -149,8 +153,15 @@ where
                 let hnm = t[(n, m)];
                 let hmn = t[(m, n)];
 
-                let tra = hnn + hmm;
-                let det = hnn * hmm - hnm * hmn;
+                let (tra, det) = if niter_no_deflation > 0 && niter_no_deflation % 10 == 0 {
+                    // Exceptional shift, as in LAPACK, to break the cycles the standard shift
+                    // may fall into, e.g., on matrices with eigenvalues of opposite signs.
+                    let s = hnm.norm1() + t[(m, m - 1)].norm1();
+                    let h = T::from_real(s * crate::convert(0.75)) + hnn;
+                    (h + h, h * h + T::from_real(s * s * crate::convert(0.4375)))
+                } else {
+                    (hnn + hmm, hnn * hmm - hnm * hmn)
+                };
 
                 let mut axis = Vector3::new(
                     h11 * h11 + h12 * h21 - tra * h11 + det,
@@ -258,6 +269,12 @@ where
             start = sub.0;
             end = sub.1;
 
+            if end == prev_end {
+                niter_no_deflation += 1;
+            } else {
+                niter_no_deflation = 0;
+            }
+
             niter += 1;
             if niter == max_niter {
                 return None;
@@ -274,7 +291,7 @@ where
         let dim = t.nrows();
         let mut m = 0;
 
-        while m < dim - 1 {
+        while m + 1 < dim {
             let n = m + 1;
 
             if t[(n, m)].is_zero() {
@@ -286,7 +303,7 @@ where
             }
         }
 
-        if m == dim - 1 {
+        if m + 1 == dim {
             out[m] = t[(m, m)];
         }
 
@@ -302,7 +319,7 @@ where
         let dim = t.nrows();
         let mut m = 0;
 
-        while m < dim - 1 {
+        while m + 1 < dim {
             let n = m + 1;
 
             if t[(n, m)].is_zero() {
@@ -319,9 +336,13 @@ where
                 let val = (hmm - hnn) * crate::convert(0.5);
                 let discr = hnm * hmn + val * val;
 
-                // All 2x2 blocks have negative discriminant because we already decoupled those
-                // with positive eigenvalues.
-                let sqrt_discr = NumComplex::new(T::zero(), (-discr).sqrt());
+                // The 2x2 blocks with real eigenvalues have already been decoupled, except those
+                // that converged without being detected as such, e.g., with a double eigenvalue.
+                let sqrt_discr = if discr >= T::zero() {
+                    NumComplex::new(discr.sqrt(), T::zero())
+                } else {
+                    NumComplex::new(T::zero(), (-discr).sqrt())
+                };
 
                 let half_tra = (hnn + hmm) * crate::convert(0.5);
                 out[m] = NumComplex::new(half_tra, T::zero()) + sqrt_discr;
@@ -331,7 +352,7 @@ where
             }
         }
 
-        if m == dim - 1 {
+        if m + 1 == dim {
             out[m] = NumComplex::new(t[(m, m)], T::zero());
         }
     }
@@ -385,7 +406,27 @@ where
 
     /// Computes the real eigenvalues of the decomposed matrix.
     ///
-    /// Return `None` if some eigenvalues are complex.
+    /// The eigenvalues are read from the diagonal of the upper-quasitriangular matrix `T`, in
+    /// the order in which they appear on that diagonal. Returns `None` if some eigenvalues are
+    /// complex, i.e., if `T` contains a 2x2 diagonal block.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// let m = Matrix3::new(2.0, 1.0, 0.0,
+    ///                      0.0, 3.0, 1.0,
+    ///                      0.0, 0.0, 4.0);
+    /// let mut eigenvalues: Vec<f64> = m.schur().eigenvalues().unwrap().iter().copied().collect();
+    /// eigenvalues.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    /// assert_eq!(eigenvalues, vec![2.0, 3.0, 4.0]);
+    ///
+    /// // A rotation matrix has complex eigenvalues.
+    /// let rot = Matrix3::new(0.0, -1.0, 0.0,
+    ///                        1.0,  0.0, 0.0,
+    ///                        0.0,  0.0, 1.0);
+    /// assert!(rot.schur().eigenvalues().is_none());
+    /// ```
     #[must_use]
     pub fn eigenvalues(&self) -> Option<OVector<T, D>> {
         let mut out = unsafe {
@@ -399,6 +440,25 @@ where
     }
 
     /// Computes the complex eigenvalues of the decomposed matrix.
+    ///
+    /// Each 1x1 diagonal block of the upper-quasitriangular matrix `T` yields a real
+    /// eigenvalue, and each 2x2 diagonal block yields a pair of complex conjugate eigenvalues,
+    /// stored next to each other with the positive imaginary part first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Complex, Matrix3};
+    /// let rot = Matrix3::new(0.0, -1.0, 0.0,
+    ///                        1.0,  0.0, 0.0,
+    ///                        0.0,  0.0, 2.0);
+    /// let eigenvalues = rot.schur().complex_eigenvalues();
+    /// let pair = eigenvalues.iter().position(|e| e.im != 0.0).unwrap();
+    /// assert_relative_eq!(eigenvalues[pair], Complex::new(0.0, 1.0), epsilon = 1.0e-7);
+    /// assert_relative_eq!(eigenvalues[pair + 1], Complex::new(0.0, -1.0), epsilon = 1.0e-7);
+    /// assert!(eigenvalues.iter().any(|e| relative_eq!(*e, Complex::new(2.0, 0.0), epsilon = 1.0e-7)));
+    /// ```
     #[must_use]
     pub fn complex_eigenvalues(&self) -> OVector<NumComplex<T>, D>
     where
@@ -411,6 +471,101 @@ where
         Self::do_complex_eigenvalues(&self.t, &mut out);
         out
     }
+
+    /// Computes the condition number of each eigenvalue of the decomposed matrix.
+    ///
+    /// The condition number of a simple eigenvalue `λ` is `‖x‖ ‖y‖ / |yᴴx|`, where `x` and `y`
+    /// are its right and left eigenvectors. It is the factor by which a small perturbation of
+    /// the matrix may be amplified in `λ`: it is `1` for normal matrices and grows as `x` and `y`
+    /// become orthogonal. The condition numbers are given in the same order as the eigenvalues
+    /// returned by [`Schur::eigenvalues`]. Both eigenvectors are computed by substitution on the
+    /// triangular matrix `T`, which preserves the condition numbers since `Q` is unitary.
+    ///
+    /// Eigenvalues which are multiple but not semi-simple (e.g., those of a Jordan block) get a
+    /// very large condition number rather than an infinite one, because the eigenvalue
+    /// differences smaller than `ε ‖T‖` are perturbed to avoid divisions by zero, as in
+    /// LAPACK's `trevc`.
+    ///
+    /// Returns `None` if some eigenvalues are complex, i.e., if `T` contains a 2x2 diagonal block.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix2;
+    /// // The closer the eigenvalues, the larger the coupling term and the more sensitive they are.
+    /// let m = Matrix2::new(1.0, 100.0,
+    ///                      0.0, 1.1);
+    /// let cond = m.schur().eigenvalue_condition_numbers().unwrap();
+    /// assert_relative_eq!(cond[0], (1.0f64 + 1000.0 * 1000.0).sqrt(), epsilon = 1.0e-6);
+    /// assert_relative_eq!(cond[1], cond[0], epsilon = 1.0e-6);
+    ///
+    /// // Symmetric matrices have perfectly conditioned eigenvalues.
+    /// let sym = Matrix2::new(2.0, 1.0,
+    ///                        1.0, 3.0);
+    /// let cond = sym.schur().eigenvalue_condition_numbers().unwrap();
+    /// assert_relative_eq!(cond[0], 1.0, epsilon = 1.0e-7);
+    /// assert_relative_eq!(cond[1], 1.0, epsilon = 1.0e-7);
+    /// ```
+    #[must_use]
+    pub fn eigenvalue_condition_numbers(&self) -> Option<OVector<T::RealField, D>>
+    where
+        DefaultAllocator: Allocator<T::RealField, D>,
+    {
+        let dim = self.t.data.shape().0;
+        let n = dim.value();
+
+        if (1..n).any(|i| !self.t[(i, i - 1)].is_zero()) {
+            // Complex eigenvalue.
+            return None;
+        }
+
+        let smin = T::RealField::default_epsilon() * self.t.norm();
+        let mut work = OVector::<T, D>::zeros_generic(dim, Const::<1>);
+        let mut out = OVector::zeros_generic(dim, Const::<1>);
+
+        let solve = |rhs: T, pivot: T| {
+            if rhs.is_zero() {
+                T::zero()
+            } else if pivot.modulus() < smin {
+                rhs / T::from_real(smin)
+            } else {
+                rhs / pivot
+            }
+        };
+
+        for k in 0..n {
+            let lambda = self.t[(k, k)];
+
+            // Right eigenvector `x` of `T`, with `x[k] = 1` and `x[i] = 0` for `i > k`.
+            work[k] = T::one();
+            let mut x_norm_squared = T::RealField::one();
+            for i in (0..k).rev() {
+                let mut rhs = T::zero();
+                for j in i + 1..=k {
+                    rhs -= self.t[(i, j)] * work[j];
+                }
+                work[i] = solve(rhs, self.t[(i, i)] - lambda);
+                x_norm_squared += work[i].modulus_squared();
+            }
+
+            // Conjugate `w` of the left eigenvector `y` of `T`, i.e., `wᵀ T = λ wᵀ`, with `w[k] = 1`
+            // and `w[i] = 0` for `i < k`. Then `yᴴx = wᵀx = x[k] w[k] = 1`.
+            let mut y_norm_squared = T::RealField::one();
+            for i in k + 1..n {
+                let mut rhs = T::zero();
+                for j in k..i {
+                    rhs -= work[j] * self.t[(j, i)];
+                }
+                work[i] = solve(rhs, self.t[(i, i)] - lambda);
+                y_norm_squared += work[i].modulus_squared();
+            }
+
+            out[k] = (x_norm_squared * y_norm_squared).sqrt();
+        }
+
+        Some(out)
+    }
 }
 
 fn decompose_2x2<T: ComplexField, D: Dim>(
@@ -5,8 +5,12 @@ use approx::AbsDiffEq;
 use num::{One, Zero};
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, Matrix, Matrix2x3, OMatrix, OVector, Vector2};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::DMatrix;
+use crate::base::{DefaultAllocator, Matrix, Matrix2x3, OMatrix, OVector, SquareMatrix, Vector2};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::dimension::Dynamic;
 use crate::dimension::{Dim, DimDiff, DimMin, DimMinimum, DimSub, U1};
 use crate::storage::Storage;
 use simba::scalar::{ComplexField, RealField};
@@ -51,6 +55,9 @@ where
     /// The right-singular vectors `V^t` of this SVD.
     pub v_t: Option<OMatrix<T, DimMinimum<R, C>, C>>,
     /// The singular values of this SVD.
+    ///
+    /// They are non-negative and sorted in decreasing order. The `i`-th column of `u` and the
+    /// `i`-th row of `v_t` are the singular vectors associated to the `i`-th singular value.
     pub singular_values: OVector<T::RealField, DimMinimum<R, C>>,
 }
 
@@ -65,6 +72,16 @@ where
 {
 }
 
+/// The entries of a real bidiagonal matrix, as consumed by `SVD::do_bidiagonal_svd`.
+struct BidiagonalEntries<T: RealField, D: DimSub<U1>>
+where
+    DefaultAllocator: Allocator<T, D> + Allocator<T, DimDiff<D, U1>>,
+{
+    diagonal: OVector<T, D>,
+    off_diagonal: OVector<T, DimDiff<D, U1>>,
+    is_upper_diagonal: bool,
+}
+
 impl<T: ComplexField, R: DimMin<C>, C: Dim> SVD<T, R, C>
 where
     DimMinimum<R, C>: DimSub<U1>, // for Bidiagonal.
@@ -111,10 +128,6 @@ where
             !matrix.is_empty(),
             "Cannot compute the SVD of an empty matrix."
         );
-        let (nrows, ncols) = matrix.data.shape();
-        let min_nrows_ncols = nrows.min(ncols);
-        let dim = min_nrows_ncols.value();
-
         let m_amax = matrix.camax();
 
         if !m_amax.is_zero() {
@@ -122,14 +135,42 @@ where
         }
 
         let bi_matrix = Bidiagonal::new(matrix);
-        let mut u = if compute_u { Some(bi_matrix.u()) } else { None };
-        let mut v_t = if compute_v {
+        let u = if compute_u { Some(bi_matrix.u()) } else { None };
+        let v_t = if compute_v {
             Some(bi_matrix.v_t())
         } else {
             None
         };
-        let mut diagonal = bi_matrix.diagonal();
-        let mut off_diagonal = bi_matrix.off_diagonal();
+
+        let entries = BidiagonalEntries {
+            diagonal: bi_matrix.diagonal(),
+            off_diagonal: bi_matrix.off_diagonal(),
+            is_upper_diagonal: bi_matrix.is_upper_diagonal(),
+        };
+
+        Self::do_bidiagonal_svd(entries, u, v_t, m_amax, eps, max_niter)
+    }
+
+    /// Computes the SVD of the bidiagonal matrix described by `entries` using implicit shift.
+    ///
+    /// The singular vectors of the bidiagonal matrix are accumulated into `u` and `v_t` (when
+    /// they are computed), and the singular values are multiplied by `scale` at the end.
+    fn do_bidiagonal_svd(
+        entries: BidiagonalEntries<T::RealField, DimMinimum<R, C>>,
+        mut u: Option<OMatrix<T, R, DimMinimum<R, C>>>,
+        mut v_t: Option<OMatrix<T, DimMinimum<R, C>, C>>,
+        scale: T::RealField,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<Self> {
+        let BidiagonalEntries {
+            mut diagonal,
+            mut off_diagonal,
+            is_upper_diagonal,
+        } = entries;
+        let dim = diagonal.len();
+        let compute_u = u.is_some();
+        let compute_v = v_t.is_some();
 
         let mut niter = 0;
         let (mut start, mut end) = Self::delimit_subproblem(
@@ -137,7 +178,7 @@ where
             &mut off_diagonal,
             &mut u,
             &mut v_t,
-            bi_matrix.is_upper_diagonal(),
+            is_upper_diagonal,
             dim - 1,
             eps,
         );
@@ -206,7 +247,7 @@ where
                         subm[(0, 0)] = norm2;
 
                         if let Some(ref mut v_t) = v_t {
-                            if bi_matrix.is_upper_diagonal() {
+                            if is_upper_diagonal {
                                 rot1.rotate(&mut v_t.fixed_rows_mut::<2>(k));
                             } else {
                                 rot2.rotate(&mut v_t.fixed_rows_mut::<2>(k));
@@ -214,7 +255,7 @@ where
                         }
 
                         if let Some(ref mut u) = u {
-                            if bi_matrix.is_upper_diagonal() {
+                            if is_upper_diagonal {
                                 rot2.inverse().rotate_rows(&mut u.fixed_columns_mut::<2>(k));
                             } else {
                                 rot1.inverse().rotate_rows(&mut u.fixed_columns_mut::<2>(k));
@@ -241,10 +282,8 @@ where
                     diagonal[start],
                     off_diagonal[start],
                     diagonal[start + 1],
-                    compute_u && bi_matrix.is_upper_diagonal()
-                        || compute_v && !bi_matrix.is_upper_diagonal(),
-                    compute_v && bi_matrix.is_upper_diagonal()
-                        || compute_u && !bi_matrix.is_upper_diagonal(),
+                    compute_u && is_upper_diagonal || compute_v && !is_upper_diagonal,
+                    compute_v && is_upper_diagonal || compute_u && !is_upper_diagonal,
                 );
                 let u2 = u2.map(|u2| GivensRotation::new_unchecked(u2.c(), T::from_real(u2.s())));
                 let v2 = v2.map(|v2| GivensRotation::new_unchecked(v2.c(), T::from_real(v2.s())));
@@ -254,7 +293,7 @@ where
                 off_diagonal[start] = T::RealField::zero();
 
                 if let Some(ref mut u) = u {
-                    let rot = if bi_matrix.is_upper_diagonal() {
+                    let rot = if is_upper_diagonal {
                         u2.unwrap()
                     } else {
                         v2.unwrap()
@@ -263,7 +302,7 @@ where
                 }
 
                 if let Some(ref mut v_t) = v_t {
-                    let rot = if bi_matrix.is_upper_diagonal() {
+                    let rot = if is_upper_diagonal {
                         v2.unwrap()
                     } else {
                         u2.unwrap()
@@ -280,7 +319,7 @@ where
                 &mut off_diagonal,
                 &mut u,
                 &mut v_t,
-                bi_matrix.is_upper_diagonal(),
+                is_upper_diagonal,
                 end,
                 eps,
             );
@@ -293,7 +332,7 @@ where
             }
         }
 
-        diagonal *= m_amax;
+        diagonal *= scale;
 
         // Ensure all singular value are non-negative.
         for i in 0..dim {
@@ -308,6 +347,29 @@ where
             }
         }
 
+        // Sort the singular values in decreasing order, and permute the
+        // singular vectors accordingly.
+        for i in 0..dim {
+            let mut imax = i;
+            for j in i + 1..dim {
+                if diagonal[j] > diagonal[imax] {
+                    imax = j;
+                }
+            }
+
+            if imax != i {
+                diagonal.swap_rows(i, imax);
+
+                if let Some(ref mut u) = u {
+                    u.swap_columns(i, imax);
+                }
+
+                if let Some(ref mut v_t) = v_t {
+                    v_t.swap_rows(i, imax);
+                }
+            }
+        }
+
         Some(Self {
             u,
             v_t,
@@ -518,14 +580,19 @@ where
         self.singular_values.iter().filter(|e| **e > eps).count()
     }
 
-    /// Rebuild the original matrix.
+    /// Rebuild the original matrix, i.e., computes `U * Σ * V^t`.
+    ///
+    /// This is useful if some of the singular values have been manually modified, e.g., for
+    /// computing a low-rank approximation of the decomposed matrix. Because `U`, `Σ`, and `V^t`
+    /// are respectively `R x min(R, C)`, `min(R, C) x min(R, C)`, and `min(R, C) x C`
+    /// matrices, the result always has the shape of the decomposed matrix.
     ///
-    /// This is useful if some of the singular values have been manually modified.
     /// Returns `Err` if the right- and left- singular vectors have not been
     /// computed at construction-time.
-    pub fn recompose(self) -> Result<OMatrix<T, R, C>, &'static str> {
-        match (self.u, self.v_t) {
-            (Some(mut u), Some(v_t)) => {
+    pub fn recompose(&self) -> Result<OMatrix<T, R, C>, &'static str> {
+        match (&self.u, &self.v_t) {
+            (Some(u), Some(v_t)) => {
+                let mut u = u.clone();
                 for i in 0..self.singular_values.len() {
                     let val = self.singular_values[i];
                     u.column_mut(i).scale_mut(val);
@@ -538,6 +605,124 @@ where
         }
     }
 
+    /// Sets all but the `k` largest singular values of this decomposition to zero.
+    ///
+    /// Since the singular values are sorted in decreasing order, this zeroes all but the first
+    /// `k` singular values. Only the singular values are modified: the dimensions of `U`, `V^t`
+    /// and `Σ` are part of the type of the decomposition, so the discarded singular vectors are
+    /// kept as-is and `U` and `V^t` remain orthogonal. They simply no longer contribute to
+    /// [`recompose`](Self::recompose) and the other methods using the decomposition. If `k` is
+    /// larger than the number of singular values, this does nothing.
+    ///
+    /// To actually drop the discarded singular vectors of a dynamically-sized matrix, use
+    /// [`SVD::new_truncated`] instead.
+    pub fn zero_trailing_singular_values(&mut self, k: usize) {
+        let k = k.min(self.singular_values.len());
+        self.singular_values
+            .rows_range_mut(k..)
+            .fill(T::RealField::zero());
+    }
+
+    /// Fixes the arbitrary signs of the singular vectors, so that the decomposition of a given
+    /// matrix is reproducible.
+    ///
+    /// Each left-singular vector (i.e. each column of `U`) is multiplied by a sign so that its
+    /// component of largest magnitude is positive, and the matching right-singular vector (i.e.
+    /// row of `V^t`) is multiplied by the same sign. If several components have the same largest
+    /// magnitude, the first one is made positive. For complex matrices, the sign is generalized
+    /// to a unit complex number, so that this component becomes real and positive.
+    ///
+    /// This preserves the product `U * Σ * V^t`, so [`recompose`](Self::recompose) still returns
+    /// the decomposed matrix. If `U` has not been computed, the rows of `V^t` are normalized
+    /// instead. If neither has been computed, this does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix3x2;
+    /// let m = Matrix3x2::new(1.0, 2.0,
+    ///                        3.0, 4.0,
+    ///                        5.0, 6.0);
+    /// let mut svd = m.svd(true, true);
+    /// svd.canonicalize_signs();
+    ///
+    /// let u = svd.u.unwrap();
+    /// for col in u.column_iter() {
+    ///     assert!(col[col.iamax()] > 0.0);
+    /// }
+    /// assert_relative_eq!(svd.recompose().unwrap(), m, epsilon = 1.0e-10);
+    /// ```
+    pub fn canonicalize_signs(&mut self) {
+        fn largest_component<'a, T: ComplexField>(mut iter: impl Iterator<Item = &'a T>) -> T {
+            let first = iter.next().copied().unwrap_or_else(T::zero);
+            iter.fold(first, |max, e| {
+                if e.modulus_squared() > max.modulus_squared() {
+                    *e
+                } else {
+                    max
+                }
+            })
+        }
+
+        for i in 0..self.singular_values.len() {
+            let component = match (&self.u, &self.v_t) {
+                (Some(u), _) => largest_component(u.column(i).iter()),
+                (None, Some(v_t)) => largest_component(v_t.row(i).iter()),
+                (None, None) => return,
+            };
+
+            if component.is_zero() {
+                continue;
+            }
+
+            // `sign` has a unit modulus, so dividing by it is the same as multiplying by its
+            // conjugate, and the product of both singular vectors is unchanged.
+            let sign = component.unscale(component.modulus());
+            if let Some(u) = &mut self.u {
+                let mut col = u.column_mut(i);
+                col *= sign.conjugate();
+            }
+            if let Some(v_t) = &mut self.v_t {
+                let mut row = v_t.row_mut(i);
+                row *= if self.u.is_some() {
+                    sign
+                } else {
+                    sign.conjugate()
+                };
+            }
+        }
+    }
+
+    /// Computes the best rank-`k` approximation of the decomposed matrix, in the sense of the
+    /// Frobenius and spectral norms.
+    ///
+    /// This is obtained by recomposing the decomposition after zeroing all but its `k` largest
+    /// singular values (see [`zero_trailing_singular_values`](Self::zero_trailing_singular_values)).
+    /// `k` is clamped to the number of singular values. Returns `Err` if the right- and left-
+    /// singular vectors have not been computed at construction-time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// let m = Matrix3::new(3.0, 0.0, 0.0,
+    ///                      0.0, 1.0, 0.0,
+    ///                      0.0, 0.0, 2.0);
+    /// let svd = m.svd(true, true);
+    ///
+    /// let approx = svd.rank_k_approximation(2).unwrap();
+    /// assert_eq!(approx, Matrix3::new(3.0, 0.0, 0.0,
+    ///                                 0.0, 0.0, 0.0,
+    ///                                 0.0, 0.0, 2.0));
+    /// assert_eq!(svd.rank_k_approximation(5).unwrap(), m);
+    /// ```
+    pub fn rank_k_approximation(&self, k: usize) -> Result<OMatrix<T, R, C>, &'static str> {
+        let mut truncated = self.clone();
+        truncated.zero_trailing_singular_values(k);
+        truncated.recompose()
+    }
+
     /// Computes the pseudo-inverse of the decomposed matrix.
     ///
     /// Any singular value smaller than `eps` is assumed to be zero.
@@ -609,6 +794,176 @@ where
     }
 }
 
+impl<T: ComplexField, D: DimMin<D, Output = D>> SVD<T, D, D>
+where
+    D: DimSub<U1>, // for Bidiagonal.
+    DefaultAllocator: Allocator<T, D, D>
+        + Allocator<T, D>
+        + Allocator<T, DimDiff<D, U1>>
+        + Allocator<T::RealField, D>
+        + Allocator<T::RealField, DimDiff<D, U1>>,
+{
+    /// Attempts to compute the Singular Value Decomposition of an upper-bidiagonal matrix using
+    /// implicit shift.
+    ///
+    /// The bidiagonal matrix has the given `diagonal`, and `off_diagonal` on its superdiagonal.
+    /// This is the Golub-Kahan iteration performed by [`SVD::try_new`] once its input has been
+    /// reduced to bidiagonal form, so it can be used directly when a bidiagonal matrix is
+    /// already available, without forming it as a dense matrix. The computed `U` and `V^t` are
+    /// the singular vectors of the bidiagonal matrix itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `compute_u` − set this to `true` to enable the computation of left-singular vectors.
+    /// * `compute_v` − set this to `true` to enable the computation of right-singular vectors.
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    ///   number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    ///   continues indefinitely until convergence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `diagonal` is empty, or if `off_diagonal` does not have exactly one element less
+    /// than `diagonal`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{DMatrix, DVector, SVD};
+    /// let diagonal = DVector::from_vec(vec![1.0, 2.0, 3.0]);
+    /// let off_diagonal = DVector::from_vec(vec![0.5, -1.0]);
+    /// let svd = SVD::try_new_bidiagonal(diagonal, off_diagonal, true, true, 1.0e-15, 0).unwrap();
+    ///
+    /// let bidiagonal = DMatrix::from_row_slice(3, 3, &[1.0, 0.5,  0.0,
+    ///                                                  0.0, 2.0, -1.0,
+    ///                                                  0.0, 0.0,  3.0]);
+    /// assert_relative_eq!(svd.recompose().unwrap(), bidiagonal, epsilon = 1.0e-12);
+    /// assert_relative_eq!(svd.singular_values, bidiagonal.singular_values(), epsilon = 1.0e-12);
+    /// ```
+    pub fn try_new_bidiagonal(
+        mut diagonal: OVector<T::RealField, D>,
+        mut off_diagonal: OVector<T::RealField, DimDiff<D, U1>>,
+        compute_u: bool,
+        compute_v: bool,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<Self> {
+        assert!(
+            !diagonal.is_empty(),
+            "Cannot compute the SVD of an empty matrix."
+        );
+        assert_eq!(
+            off_diagonal.len() + 1,
+            diagonal.len(),
+            "SVD of a bidiagonal matrix: the off-diagonal must have one element less than the diagonal."
+        );
+
+        let amax = diagonal.amax().max(off_diagonal.amax());
+
+        if !amax.is_zero() {
+            diagonal.unscale_mut(amax);
+            off_diagonal.unscale_mut(amax);
+        }
+
+        let dim = diagonal.data.shape().0;
+        let u = if compute_u {
+            Some(OMatrix::identity_generic(dim, dim))
+        } else {
+            None
+        };
+        let v_t = if compute_v {
+            Some(OMatrix::identity_generic(dim, dim))
+        } else {
+            None
+        };
+
+        let entries = BidiagonalEntries {
+            diagonal,
+            off_diagonal,
+            is_upper_diagonal: true,
+        };
+
+        Self::do_bidiagonal_svd(entries, u, v_t, amax, eps, max_niter)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: ComplexField> SVD<T, Dynamic, Dynamic> {
+    /// Computes the `k` largest singular values of `matrix`, and their singular vectors.
+    ///
+    /// This is [`SVD::try_new_truncated`] with the default tolerance and no limit on the number of
+    /// iterations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `matrix` is empty.
+    pub fn new_truncated(matrix: DMatrix<T>, k: usize) -> Self {
+        Self::try_new_truncated(matrix, k, T::RealField::default_epsilon(), 0).unwrap()
+    }
+
+    /// Attempts to compute the `k` largest singular values of `matrix`, and their singular
+    /// vectors.
+    ///
+    /// The result is a thin decomposition: `U` has only `k` columns, `V^t` only `k` rows, and
+    /// the singular values are sorted in decreasing order. [`recompose`](Self::recompose) then
+    /// returns the best rank-`k` approximation of `matrix`. If `k` is larger than the number of
+    /// singular values, all of them are kept.
+    ///
+    /// The implicit-shift iteration performed after bidiagonalization does not converge to the
+    /// largest singular values first, so they are all computed, as by [`SVD::try_new`]. Only the
+    /// storage of the result is bounded by `k`: the discarded singular vectors are dropped right
+    /// away instead of being kept with a zero singular value like
+    /// [`zero_trailing_singular_values`](Self::zero_trailing_singular_values) does.
+    ///
+    /// # Arguments
+    ///
+    /// * `k`         − the number of singular triplets to keep.
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    ///   number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    ///   continues indefinitely until convergence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `matrix` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{DMatrix, SVD};
+    /// let m = DMatrix::from_row_slice(4, 3, &[1.0,  2.0,  3.0,
+    ///                                         4.0,  5.0,  6.0,
+    ///                                         7.0,  8.0,  9.0,
+    ///                                         10.0, 11.0, 12.5]);
+    /// let svd = SVD::try_new_truncated(m.clone(), 2, 1.0e-15, 0).unwrap();
+    ///
+    /// assert_eq!(svd.u.as_ref().unwrap().shape(), (4, 2));
+    /// assert_eq!(svd.v_t.as_ref().unwrap().shape(), (2, 3));
+    /// let largest = m.singular_values().rows(0, 2).into_owned();
+    /// assert_relative_eq!(svd.singular_values, largest, epsilon = 1.0e-10);
+    /// assert_relative_eq!(
+    ///     svd.recompose().unwrap(),
+    ///     m.svd(true, true).rank_k_approximation(2).unwrap(),
+    ///     epsilon = 1.0e-10
+    /// );
+    /// ```
+    pub fn try_new_truncated(
+        matrix: DMatrix<T>,
+        k: usize,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<Self> {
+        let svd = Self::try_new(matrix, true, true, eps, max_niter)?;
+        let k = k.min(svd.singular_values.len());
+
+        Some(SVD {
+            u: svd.u.map(|u| u.columns(0, k).into_owned()),
+            v_t: svd.v_t.map(|v_t| v_t.rows(0, k).into_owned()),
+            singular_values: svd.singular_values.rows(0, k).into_owned(),
+        })
+    }
+}
+
 impl<T: ComplexField, R: DimMin<C>, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S>
 where
     DimMinimum<R, C>: DimSub<U1>, // for Bidiagonal.
@@ -623,6 +978,8 @@ where
         + Allocator<T::RealField, DimDiff<DimMinimum<R, C>, U1>>,
 {
     /// Computes the singular values of this matrix.
+    ///
+    /// The singular values are non-negative and sorted in decreasing order.
     #[must_use]
     pub fn singular_values(&self) -> OVector<T::RealField, DimMinimum<R, C>> {
         SVD::new(self.clone_owned(), false, false).singular_values
@@ -648,6 +1005,70 @@ where
     }
 }
 
+impl<T: ComplexField, D: DimMin<D, Output = D>, S: Storage<T, D, D>> SquareMatrix<T, D, S>
+where
+    D: DimSub<U1>, // for Bidiagonal.
+    DefaultAllocator: Allocator<T, D, D>
+        + Allocator<T, D>
+        + Allocator<T, DimDiff<D, U1>>
+        + Allocator<T::RealField, D>
+        + Allocator<T::RealField, DimDiff<D, U1>>
+        + Allocator<(usize, usize), D>,
+{
+    /// Computes the sign and the natural logarithm of the absolute value of the determinant of
+    /// this matrix, using its singular value decomposition.
+    ///
+    /// This returns `(sign, ln_abs_det)` such that `det = sign * exp(ln_abs_det)`. The magnitude
+    /// is the sum of the logarithms of the singular values, and the sign is the product of the
+    /// determinants of the singular vector matrices `U` and `V^t`. Those are orthogonal (unitary
+    /// in the complex case), so their determinants are computed accurately even when this
+    /// matrix is ill-conditioned. For complex matrices, `sign` is a complex number of modulus 1.
+    ///
+    /// If this matrix is singular, i.e., one of its computed singular values is zero, this returns
+    /// a zero `sign` and a logarithm of negative infinity. Note that the SVD sets to zero the
+    /// singular values that are negligible compared to the largest one.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix3;
+    /// let m = Matrix3::new(0.0, 2.0, 0.0,
+    ///                      3.0, 0.0, 0.0,
+    ///                      0.0, 0.0, 1.0) * 1.0e-120;
+    ///
+    /// // The determinant, -6e-360, underflows.
+    /// assert_eq!(m.determinant(), 0.0);
+    ///
+    /// let (sign, ln_abs_det) = m.signed_log_determinant_svd();
+    /// assert_eq!(sign, -1.0);
+    /// assert_relative_eq!(ln_abs_det, 6.0f64.ln() - 360.0 * 10.0f64.ln(), epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn signed_log_determinant_svd(&self) -> (T, T::RealField) {
+        assert!(
+            self.is_square(),
+            "Unable to compute the determinant of a non-square matrix."
+        );
+
+        let svd = SVD::new(self.clone_owned(), true, true);
+        let mut ln_abs_det = T::RealField::zero();
+        for val in svd.singular_values.iter() {
+            ln_abs_det += val.ln();
+        }
+
+        if svd.singular_values.iter().any(|val| val.is_zero()) {
+            return (T::zero(), ln_abs_det);
+        }
+
+        let det_u = svd.u.as_ref().unwrap().determinant();
+        let det_v_t = svd.v_t.as_ref().unwrap().determinant();
+        let sign = det_u * det_v_t;
+
+        // Remove the rounding errors so that the sign has exactly modulus 1.
+        (sign.unscale(sign.modulus()), ln_abs_det)
+    }
+}
+
 // Explicit formulae inspired from the paper "Computing the Singular Values of 2-by-2 Complex
 // Matrices", Sanzheng Qiao and Xiaohong Wang.
 // http://www.cas.mcmaster.ca/sqrl/papers/sqrl5.pdf
@@ -667,10 +1088,8 @@ fn compute_2x2_uptrig_svd<T: RealField>(
 
     let denom = (m11 + m22).hypot(m12) + (m11 - m22).hypot(m12);
 
-    // NOTE: v1 is the singular value that is the closest to m22.
-    // This prevents cancellation issues when constructing the vector `csv` below. If we chose
-    // otherwise, we would have v1 ~= m11 when m12 is small. This would cause catastrophic
-    // cancellation on `v1 * v1 - m11 * m11` below.
+    // NOTE: v2 is the largest singular value, and v1 the other one, with the sign of the
+    // determinant `m11 * m22 = v1 * v2`.
     let mut v1 = m11 * m22 * two / denom;
     let mut v2 = half * denom;
 
@@ -678,21 +1097,34 @@ fn compute_2x2_uptrig_svd<T: RealField>(
     let mut v_t = None;
 
     if compute_u || compute_v {
-        let (csv, sgn_v) = GivensRotation::new(m11 * m12, v1 * v1 - m11 * m11);
-        v1 *= sgn_v;
-        v2 *= sgn_v;
+        // The right-singular vector associated to v2 is computed from the row of
+        // `M^t * M - v2² * I` with the largest norm. Both rows are orthogonal to it, but
+        // the other one may suffer from catastrophic cancellation, e.g., on `v2² - m11²` when
+        // m12 is small and m11 is the largest entry.
+        let sq_v2 = v2 * v2;
+        let tmn = m11 * m12;
+        let dm = sq_v2 - m11 * m11;
+        let dn = sq_v2 - m12 * m12 - m22 * m22;
+        let (csv, _) = if dm >= dn {
+            GivensRotation::new(dm, -tmn)
+        } else {
+            GivensRotation::new(tmn, -dn)
+        };
 
         if compute_v {
             v_t = Some(csv);
         }
 
         if compute_u {
-            let cu = (m11.scale(csv.c()) + m12 * csv.s()) / v1;
-            let su = (m22 * csv.s()) / v1;
+            // The left-singular vectors are deduced from `M * v = v2 * u` for the right- and
+            // left-singular vectors associated to v2, so that no division by the possibly
+            // vanishing v1 is needed.
+            let cu = (m22 * csv.c()) / v2;
+            let su = (m11 * csv.s() - m12 * csv.c()) / v2;
             let (csu, sgn_u) = GivensRotation::new(cu, su);
 
-            v1 *= sgn_u;
-            v2 *= sgn_u;
+            v1 *= sgn_u.signum();
+            v2 *= sgn_u.signum();
             u = Some(csu);
         }
     }
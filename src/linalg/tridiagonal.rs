@@ -0,0 +1,90 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use simba::scalar::ComplexField;
+
+use crate::base::DVector;
+
+/// Solves the tridiagonal linear system `A * x = rhs` with the Thomas algorithm.
+///
+/// The matrix `A` is given by its subdiagonal `sub`, its diagonal `diag`, and its superdiagonal
+/// `sup`, i.e., `A[(i + 1, i)] = sub[i]`, `A[(i, i)] = diag[i]` and `A[(i, i + 1)] = sup[i]`. The
+/// system is solved in `O(n)` time without ever building `A`.
+///
+/// The Thomas algorithm is Gaussian elimination without pivoting. It is stable for diagonally
+/// dominant or symmetric positive-definite matrices, which covers the tridiagonal systems arising
+/// from the discretization of most 1D PDEs. For other matrices, use [`LU`](crate::LU) on the full
+/// matrix instead.
+///
+/// Returns `None` if a zero pivot is encountered, which happens in particular if `A` is singular.
+///
+/// # Panics
+///
+/// Panics if `diag` and `rhs` do not have the same length, or if `sub` and `sup` do not both have
+/// one element less than `diag` (for a non-empty `diag`).
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{solve_tridiagonal, DVector};
+/// // The 1D Poisson equation `-u'' = 1` on 4 interior points with zero boundary conditions.
+/// let sub = [-1.0; 3];
+/// let diag = [2.0; 4];
+/// let sup = [-1.0; 3];
+/// let rhs = DVector::from_element(4, 1.0);
+///
+/// let x = solve_tridiagonal(&sub, &diag, &sup, &rhs).unwrap();
+/// assert_relative_eq!(x, DVector::from_column_slice(&[2.0, 3.0, 3.0, 2.0]), epsilon = 1.0e-12);
+/// ```
+pub fn solve_tridiagonal<T: ComplexField>(
+    sub: &[T],
+    diag: &[T],
+    sup: &[T],
+    rhs: &DVector<T>,
+) -> Option<DVector<T>> {
+    let n = diag.len();
+    assert_eq!(
+        rhs.len(),
+        n,
+        "Tridiagonal solve: the right-hand side must have as many elements as the diagonal."
+    );
+    assert!(
+        sub.len() == n.saturating_sub(1) && sup.len() == n.saturating_sub(1),
+        "Tridiagonal solve: the sub- and superdiagonals must have one element less than the diagonal."
+    );
+
+    let mut x = rhs.clone();
+    if n == 0 {
+        return Some(x);
+    }
+
+    // Forward elimination: the superdiagonal of the resulting unit upper-bidiagonal matrix is
+    // stored in `sup_elim`, and the transformed right-hand side in `x`.
+    let mut sup_elim = Vec::with_capacity(n - 1);
+    let mut pivot = diag[0];
+
+    for i in 0..n {
+        if pivot.is_zero() {
+            return None;
+        }
+
+        x[i] /= pivot;
+
+        if i + 1 < n {
+            let c = sup[i] / pivot;
+            sup_elim.push(c);
+
+            pivot = diag[i + 1] - sub[i] * c;
+            let x_i = x[i];
+            x[i + 1] -= sub[i] * x_i;
+        }
+    }
+
+    // Back substitution.
+    for i in (0..n - 1).rev() {
+        let x_next = x[i + 1];
+        x[i] -= sup_elim[i] * x_next;
+    }
+
+    Some(x)
+}
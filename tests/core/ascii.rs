@@ -0,0 +1,89 @@
+use na::{DMatrix, ParseAsciiError};
+
+#[test]
+fn from_ascii_separators() {
+    let expected = DMatrix::from_row_slice(3, 2, &[1.0, -2.5, 3.0e2, 4.0, 5.0, 0.125]);
+
+    let spaces = "1.0 -2.5\n300\t4\n  5   0.125  \n";
+    let commas = "1.0,-2.5\n300,4\n5,0.125";
+    let mixed = "\n1.0, -2.5\r\n\n300 ,4\n5 ,\t0.125\n\n";
+
+    assert_eq!(DMatrix::<f64>::from_ascii(spaces), Ok(expected.clone()));
+    assert_eq!(DMatrix::<f64>::from_ascii(commas), Ok(expected.clone()));
+    assert_eq!(DMatrix::<f64>::from_ascii(mixed), Ok(expected));
+}
+
+#[test]
+fn from_ascii_shapes() {
+    assert_eq!(DMatrix::<f64>::from_ascii(""), Ok(DMatrix::zeros(0, 0)));
+    assert_eq!(
+        DMatrix::<f64>::from_ascii(" \n\n"),
+        Ok(DMatrix::zeros(0, 0))
+    );
+    assert_eq!(
+        DMatrix::<i32>::from_ascii("1 2 3"),
+        Ok(DMatrix::from_row_slice(1, 3, &[1, 2, 3]))
+    );
+    assert_eq!(
+        DMatrix::<i32>::from_ascii("1\n2\n3"),
+        Ok(DMatrix::from_row_slice(3, 1, &[1, 2, 3]))
+    );
+}
+
+#[test]
+fn from_ascii_invalid_entry() {
+    assert_eq!(
+        DMatrix::<f64>::from_ascii("1 2 3\n\n4 five 6"),
+        Err(ParseAsciiError::InvalidEntry {
+            line: 3,
+            row: 1,
+            column: 1
+        })
+    );
+
+    // Empty fields between commas are rejected.
+    assert_eq!(
+        DMatrix::<f64>::from_ascii("1, 2, 3\n4,, 6"),
+        Err(ParseAsciiError::InvalidEntry {
+            line: 2,
+            row: 1,
+            column: 1
+        })
+    );
+    assert_eq!(
+        DMatrix::<f64>::from_ascii("1, 2,"),
+        Err(ParseAsciiError::InvalidEntry {
+            line: 1,
+            row: 0,
+            column: 2
+        })
+    );
+
+    // Entries are parsed with the scalar type's `FromStr` implementation.
+    assert_eq!(
+        DMatrix::<u8>::from_ascii("1 256"),
+        Err(ParseAsciiError::InvalidEntry {
+            line: 1,
+            row: 0,
+            column: 1
+        })
+    );
+}
+
+#[test]
+fn from_ascii_ragged_rows() {
+    let err = DMatrix::<f64>::from_ascii("1 2 3\n4 5 6\n7 8").unwrap_err();
+    assert_eq!(
+        err,
+        ParseAsciiError::RaggedRow {
+            line: 3,
+            row: 2,
+            expected_ncols: 3,
+            found_ncols: 2
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "row 2 (line 3) has 2 entries but the first row has 3"
+    );
+}
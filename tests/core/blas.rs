@@ -1,6 +1,73 @@
 use na::{geometry::Quaternion, Matrix2, Vector3};
 use num_traits::{One, Zero};
 
+#[test]
+fn gemm_matches_gemv_per_column() {
+    use na::{Complex, DMatrix};
+
+    let gemv_per_column = |c: &DMatrix<f64>, alpha, a: &DMatrix<f64>, b: &DMatrix<f64>, beta| {
+        let mut res = c.clone();
+        for j in 0..b.ncols() {
+            res.column_mut(j).gemv(alpha, a, &b.column(j), beta);
+        }
+        res
+    };
+    let to_complex = |m: &DMatrix<f64>| m.map(|e| Complex::new(e, 0.0));
+
+    // The second size is large enough for the generic path to use its cache-blocked kernel,
+    // including partial blocks and tiles.
+    for &(m, k, n) in &[(40, 70, 30), (131, 517, 47)] {
+        let (alpha, beta) = (0.3, -1.5);
+        let a = DMatrix::from_fn(m, k, |i, j| ((i * k + j) as f64).sin());
+        let b = DMatrix::from_fn(k, n, |i, j| ((i * n + j) as f64).cos());
+        let c = DMatrix::from_fn(m, n, |i, j| (i + j) as f64);
+        let expected = gemv_per_column(&c, alpha, &a, &b, beta);
+
+        // Large `f64` matrices are multiplied by `matrixmultiply`, with a different accumulation
+        // order.
+        let mut res = c.clone();
+        res.gemm(alpha, &a, &b, beta);
+        assert_relative_eq!(res, expected, epsilon = 1.0e-10);
+
+        // Other scalar types accumulate the terms in the same order as `gemv`.
+        let mut res = to_complex(&c);
+        res.gemm(
+            Complex::new(alpha, 0.0),
+            &to_complex(&a),
+            &to_complex(&b),
+            Complex::new(beta, 0.0),
+        );
+        assert_eq!(res.map(|e| e.re), expected);
+
+        // `self` is not read if `beta` is zero.
+        let expected = gemv_per_column(&c, alpha, &a, &b, 0.0);
+        let mut res = to_complex(&c).map(|_| Complex::new(f64::NAN, 0.0));
+        res.gemm(
+            Complex::new(alpha, 0.0),
+            &to_complex(&a),
+            &to_complex(&b),
+            Complex::new(0.0, 0.0),
+        );
+        assert_eq!(res.map(|e| e.re), expected);
+    }
+}
+
+#[test]
+fn gemm_blocked_integers() {
+    use na::DMatrix;
+
+    let a = DMatrix::from_fn(150, 300, |i, j| (i * 7 + j * 3) as i64 % 17 - 8);
+    let b = DMatrix::from_fn(300, 90, |i, j| (i * 5 + j * 11) as i64 % 13 - 6);
+    let c = DMatrix::from_fn(150, 90, |i, j| (i + j) as i64);
+
+    let mut res = c.clone();
+    res.gemm(3, &a, &b, -2);
+    let expected = DMatrix::from_fn(150, 90, |i, j| {
+        3 * (0..300).map(|k| a[(i, k)] * b[(k, j)]).sum::<i64>() - 2 * c[(i, j)]
+    });
+    assert_eq!(res, expected);
+}
+
 #[test]
 fn gemm_noncommutative() {
     type Qf64 = Quaternion<f64>;
@@ -1,6 +1,6 @@
 use na::{
-    DMatrix, Matrix, Matrix3, Matrix3x4, Matrix3x5, Matrix4, Matrix4x3, Matrix4x5, Matrix5,
-    Matrix5x3, Matrix5x4,
+    DMatrix, DVector, Matrix, Matrix3, Matrix3x4, Matrix3x5, Matrix4, Matrix4x3, Matrix4x5,
+    Matrix5, Matrix5x3, Matrix5x4, PermutationError, Vector3,
 };
 use na::{Dynamic, U3, U5};
 
@@ -170,6 +170,81 @@ fn upper_lower_triangular() {
     assert_eq!(m, expected_m);
 }
 
+#[test]
+#[rustfmt::skip]
+fn diagonal_and_strictly_triangular_parts() {
+    let m = Matrix3::new(
+        11.0, 12.0, 13.0,
+        21.0, 22.0, 23.0,
+        31.0, 32.0, 33.0);
+
+    let expected_d = Matrix3::new(
+        11.0,  0.0,  0.0,
+         0.0, 22.0,  0.0,
+         0.0,  0.0, 33.0);
+
+    let expected_l = Matrix3::new(
+         0.0,  0.0, 0.0,
+        21.0,  0.0, 0.0,
+        31.0, 32.0, 0.0);
+
+    let expected_u = Matrix3::new(
+        0.0, 12.0, 13.0,
+        0.0,  0.0, 23.0,
+        0.0,  0.0,  0.0);
+
+    assert_eq!(m.diagonal_matrix(), expected_d);
+    assert_eq!(m.strictly_lower_triangle(), expected_l);
+    assert_eq!(m.strictly_upper_triangle(), expected_u);
+
+    // Rectangular matrices, both tall and wide.
+    let m = DMatrix::from_fn(5, 3, |i, j| (i * 10 + j) as f64 + 11.0);
+    let (d, l, u) = (m.diagonal_matrix(), m.strictly_lower_triangle(), m.strictly_upper_triangle());
+    assert_eq!(&d + &l + &u, m);
+    assert!((0..3).all(|i| d[(i, i)] == m[(i, i)]));
+    assert_eq!(l.upper_triangle(), DMatrix::zeros(5, 3));
+    assert_eq!(u.lower_triangle(), DMatrix::zeros(5, 3));
+
+    let m = m.transpose();
+    assert_eq!(m.diagonal_matrix(), d.transpose());
+    assert_eq!(m.strictly_lower_triangle(), u.transpose());
+    assert_eq!(m.strictly_upper_triangle(), l.transpose());
+}
+
+#[test]
+#[rustfmt::skip]
+fn fill_and_set_diagonal() {
+    let mut m = Matrix3x4::new(
+        11, 12, 13, 14,
+        21, 22, 23, 24,
+        31, 32, 33, 34);
+
+    m.fill_diagonal(0);
+    let expected = Matrix3x4::new(
+        0,  12, 13, 14,
+        21, 0,  23, 24,
+        31, 32, 0,  34);
+    assert_eq!(m, expected);
+
+    m.set_diagonal(&Vector3::new(1, 2, 3));
+    let expected = Matrix3x4::new(
+        1,  12, 13, 14,
+        21, 2,  23, 24,
+        31, 32, 3,  34);
+    assert_eq!(m, expected);
+
+    let mut dm = DMatrix::from_element(3, 2, 7);
+    dm.set_diagonal(&DVector::from_vec(vec![1, 2]));
+    assert_eq!(dm, DMatrix::from_row_slice(3, 2, &[1, 7, 7, 2, 7, 7]));
+}
+
+#[test]
+#[should_panic]
+fn set_diagonal_wrong_length() {
+    let mut m = DMatrix::from_element(3, 3, 0);
+    m.set_diagonal(&DVector::from_vec(vec![1, 2]));
+}
+
 #[test]
 #[rustfmt::skip]
 fn swap_rows() {
@@ -210,6 +285,118 @@ fn swap_columns() {
     assert_eq!(m, expected);
 }
 
+#[test]
+#[rustfmt::skip]
+fn permute_rows() {
+    let mut m = Matrix5x3::new(
+        11.0, 12.0, 13.0,
+        21.0, 22.0, 23.0,
+        31.0, 32.0, 33.0,
+        41.0, 42.0, 43.0,
+        51.0, 52.0, 53.0);
+
+    // Two cycles: 0 -> 3 -> 1 -> 0 and 2 -> 4 -> 2.
+    let expected = Matrix5x3::new(
+        41.0, 42.0, 43.0,
+        11.0, 12.0, 13.0,
+        51.0, 52.0, 53.0,
+        21.0, 22.0, 23.0,
+        31.0, 32.0, 33.0);
+
+    assert_eq!(m.permute_rows(&[3, 0, 4, 1, 2]), Ok(()));
+    assert_eq!(m, expected);
+
+    let dm = DMatrix::from_fn(7, 4, |i, j| i * 10 + j);
+    let perm = [6, 2, 5, 0, 3, 1, 4];
+    let mut permuted = dm.clone();
+    assert_eq!(permuted.permute_rows(&perm), Ok(()));
+    assert_eq!(permuted, DMatrix::from_fn(7, 4, |i, j| dm[(perm[i], j)]));
+}
+
+#[test]
+#[rustfmt::skip]
+fn permute_columns() {
+    let mut m = Matrix3x5::new(
+        11.0, 12.0, 13.0, 14.0, 15.0,
+        21.0, 22.0, 23.0, 24.0, 25.0,
+        31.0, 32.0, 33.0, 34.0, 35.0);
+
+    let expected = Matrix3x5::new(
+        15.0, 13.0, 11.0, 12.0, 14.0,
+        25.0, 23.0, 21.0, 22.0, 24.0,
+        35.0, 33.0, 31.0, 32.0, 34.0);
+
+    assert_eq!(m.permute_columns(&[4, 2, 0, 1, 3]), Ok(()));
+    assert_eq!(m, expected);
+}
+
+#[test]
+fn permute_invalid() {
+    let m = Matrix3x4::from_fn(|i, j| i * 10 + j);
+
+    let mut permuted = m;
+    assert_eq!(
+        permuted.permute_rows(&[0, 1]),
+        Err(PermutationError::WrongLength {
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!(
+        permuted.permute_rows(&[0, 1, 3]),
+        Err(PermutationError::IndexOutOfBounds { index: 3, len: 3 })
+    );
+    assert_eq!(
+        permuted.permute_rows(&[2, 1, 1]),
+        Err(PermutationError::DuplicateIndex { index: 1 })
+    );
+    assert_eq!(
+        permuted.permute_columns(&[0, 1, 2]),
+        Err(PermutationError::WrongLength {
+            expected: 4,
+            found: 3
+        })
+    );
+    assert_eq!(
+        permuted.permute_columns(&[3, 2, 2, 0]),
+        Err(PermutationError::DuplicateIndex { index: 2 })
+    );
+    assert_eq!(permuted, m);
+}
+
+#[test]
+fn select_rows_and_columns() {
+    let m = Matrix3x4::from_fn(|i, j| i * 10 + j);
+
+    let rows = m.select_rows(&[2, 0, 2, 1]);
+    assert_eq!(rows.shape(), (4, 4));
+    assert_eq!(rows.row(0), m.row(2));
+    assert_eq!(rows.row(1), m.row(0));
+    assert_eq!(rows.row(2), m.row(2));
+    assert_eq!(rows.row(3), m.row(1));
+
+    let cols = m.select_columns(vec![3, 3, 0].iter());
+    assert_eq!(cols.shape(), (3, 3));
+    assert_eq!(cols.column(0), m.column(3));
+    assert_eq!(cols.column(1), m.column(3));
+    assert_eq!(cols.column(2), m.column(0));
+
+    assert_eq!(m.select_rows(&[]).shape(), (0, 4));
+    assert_eq!(m.select_columns(&[]).shape(), (3, 0));
+}
+
+#[test]
+#[should_panic(expected = "Row index out of bounds.")]
+fn select_rows_out_of_bounds() {
+    let _ = Matrix3x4::from_fn(|i, j| i * 10 + j).select_rows(&[0, 3]);
+}
+
+#[test]
+#[should_panic(expected = "Column index out of bounds.")]
+fn select_columns_out_of_bounds() {
+    let _ = Matrix3x4::from_fn(|i, j| i * 10 + j).select_columns(&[0, 4]);
+}
+
 #[test]
 #[rustfmt::skip]
 fn remove_columns() {
@@ -427,6 +614,26 @@ fn remove_rows_at() {
     assert_eq!(m.remove_rows_at(&[0,3,4]), expected3);
 }
 
+#[test]
+fn remove_rows_and_columns_at_unsorted_indices() {
+    let m = DMatrix::from_fn(4, 5, |i, j| i * 10 + j);
+
+    let rows_removed = m.clone().remove_rows_at(&[3, 1, 3]);
+    assert_eq!(rows_removed, m.clone().select_rows(&[0, 2]));
+
+    let cols_removed = m.clone().remove_columns_at(&[4, 0, 2, 0]);
+    assert_eq!(cols_removed, m.select_columns(&[1, 3]));
+}
+
+#[test]
+fn remove_rows_at_without_columns() {
+    let m = DMatrix::<i32>::zeros(4, 0);
+    assert_eq!(m.remove_rows_at(&[0, 2]).shape(), (2, 0));
+
+    let m = DMatrix::<i32>::zeros(0, 3);
+    assert_eq!(m.remove_columns_at(&[1]).shape(), (0, 2));
+}
+
 #[test]
 #[rustfmt::skip]
 fn insert_columns() {
@@ -565,6 +772,33 @@ fn insert_rows() {
     assert!(computed.eq(&expected2));
 }
 
+#[test]
+#[should_panic(expected = "Row insertion index out of range.")]
+fn insert_row_out_of_range() {
+    let _ = DMatrix::repeat(3, 2, 1).insert_row(4, 0);
+}
+
+#[test]
+#[should_panic(expected = "Column insertion index out of range.")]
+fn insert_columns_out_of_range() {
+    let _ = Matrix3x5::repeat(1).insert_columns(6, 2, 0);
+}
+
+#[test]
+fn insert_row_and_column_dynamic() {
+    // Grow a matrix whose variables are added one by one.
+    let mut m = DMatrix::from_element(1, 1, 1);
+    for k in 1..4 {
+        m = m.insert_row(k / 2, 0).insert_column(k / 2, 0);
+        m[(k / 2, k / 2)] = k + 1;
+    }
+
+    assert_eq!(
+        m,
+        DMatrix::from_diagonal(&DVector::from_vec(vec![2, 4, 3, 1]))
+    );
+}
+
 #[test]
 fn insert_rows_to_empty_matrix() {
     let m1 = DMatrix::repeat(0, 0, 0);
@@ -680,3 +914,35 @@ fn resize_empty_matrix() {
     assert_eq!(m1, m6.resize(0, 0, 42));
     assert_eq!(m1, m7.resize(0, 0, 42));
 }
+
+#[test]
+fn reshape_to_vector_and_back() {
+    let m = DMatrix::from_fn(3, 4, |i, j| i * 4 + j);
+    let original = m.clone();
+    let ptr = m.as_ptr();
+
+    let v = m.reshape_to_vector();
+    assert_eq!(v.len(), 12);
+    assert_eq!(v.as_slice(), original.as_slice());
+    assert_eq!(v.as_ptr(), ptr);
+
+    let reshaped = DMatrix::from_vector(v, 6, 2);
+    assert_eq!(reshaped.as_ptr(), ptr);
+    assert_eq!(
+        reshaped,
+        DMatrix::from_column_slice(6, 2, original.as_slice())
+    );
+    assert_eq!(
+        DMatrix::from_vector(reshaped.reshape_to_vector(), 3, 4),
+        original
+    );
+
+    let empty = DMatrix::<f64>::zeros(0, 3).reshape_to_vector();
+    assert_eq!(empty.len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn from_vector_wrong_dimensions() {
+    let _ = DMatrix::from_vector(DVector::from_vec(vec![1, 2, 3, 4, 5]), 2, 3);
+}
@@ -1,11 +1,11 @@
 use num::{One, Zero};
 use std::cmp::Ordering;
 
-use na::dimension::{U15, U8};
+use na::dimension::{Dynamic, U15, U8};
 use na::{
-    self, Const, DMatrix, DVector, Matrix2, Matrix2x3, Matrix2x4, Matrix3, Matrix3x2, Matrix3x4,
-    Matrix4, Matrix4x3, Matrix4x5, Matrix5, Matrix6, OMatrix, RowVector3, RowVector4, RowVector5,
-    Vector1, Vector2, Vector3, Vector4, Vector5, Vector6,
+    self, Complex, Const, DMatrix, DVector, Matrix2, Matrix2x3, Matrix2x4, Matrix3, Matrix3x2,
+    Matrix3x4, Matrix4, Matrix4x3, Matrix4x5, Matrix5, Matrix6, OMatrix, RowDVector, RowVector3,
+    RowVector4, RowVector5, Unit, Vector1, Vector2, Vector3, Vector4, Vector5, Vector6,
 };
 
 #[test]
@@ -77,6 +77,25 @@ fn iter() {
     assert!(it.next().is_none());
 }
 
+#[test]
+fn column_iter_double_ended() {
+    let mut a = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+
+    let mut it = a.column_iter();
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.next_back().unwrap(), a.column(2));
+    assert_eq!(it.next().unwrap(), a.column(0));
+    assert_eq!(it.len(), 1);
+    assert_eq!(it.next_back().unwrap(), a.column(1));
+    assert!(it.next().is_none());
+    assert!(it.next_back().is_none());
+
+    for (i, mut col) in a.column_iter_mut().rev().enumerate() {
+        col *= i as f64;
+    }
+    assert_eq!(a, Matrix2x3::new(2.0, 2.0, 0.0, 8.0, 5.0, 0.0));
+}
+
 #[test]
 fn debug_output_corresponds_to_data_container() {
     let m = Matrix2::new(1.0, 2.0, 3.0, 4.0);
@@ -175,6 +194,33 @@ fn coordinates() {
     assert_eq!(a.m34, 34);
 }
 
+#[test]
+fn from_fn_column_major_call_order() {
+    let mut calls = Vec::new();
+    let mut counter = 0;
+    let m = Matrix2x3::from_fn(|i, j| {
+        calls.push((i, j));
+        counter += 1;
+        counter
+    });
+
+    assert_eq!(calls, [(0, 0), (1, 0), (0, 1), (1, 1), (0, 2), (1, 2)]);
+    assert_eq!(m, Matrix2x3::from_iterator(1..=6));
+
+    let mut counter = 0;
+    let dm = DMatrix::from_fn(2, 3, |_, _| {
+        counter += 1;
+        counter
+    });
+    assert_eq!(dm, DMatrix::from_iterator(2, 3, 1..=6));
+
+    let gm =
+        OMatrix::<_, Const<2>, Dynamic>::from_fn_generic(Const::<2>, Dynamic::new(3), |i, j| {
+            i + 2 * j + 1
+        });
+    assert_eq!(gm.as_slice(), &[1, 2, 3, 4, 5, 6]);
+}
+
 #[test]
 fn from_diagonal() {
     let diag = Vector3::new(1, 2, 3);
@@ -231,6 +277,45 @@ fn from_columns_dynamic() {
     assert_eq!(a, expected);
 }
 
+#[test]
+fn from_diagonal_element_rectangular() {
+    let wide = Matrix2x4::from_diagonal_element(3.0);
+    assert_eq!(wide, Matrix2x4::new(3.0, 0.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0));
+
+    let tall = Matrix4x3::from_diagonal_element(3.0);
+    assert_eq!(
+        tall,
+        Matrix4x3::new(3.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0)
+    );
+
+    let dynamic = DMatrix::from_diagonal_element(4, 2, -1.0);
+    assert_eq!(
+        dynamic,
+        DMatrix::from_diagonal_element_generic(Dynamic::new(4), Dynamic::new(2), -1.0)
+    );
+    assert_eq!(
+        dynamic,
+        DMatrix::from_fn(4, 2, |i, j| if i == j { -1.0 } else { 0.0 })
+    );
+}
+
+#[test]
+fn from_row_arrays() {
+    let expected = Matrix3x4::new(11, 12, 13, 14, 21, 22, 23, 24, 31, 32, 33, 34);
+
+    let a = Matrix3x4::from_row_arrays([[11, 12, 13, 14], [21, 22, 23, 24], [31, 32, 33, 34]]);
+    assert_eq!(a, expected);
+
+    // The `From` conversion interprets the inner arrays as columns.
+    let b = Matrix3x4::from([[11, 21, 31], [12, 22, 32], [13, 23, 33], [14, 24, 34]]);
+    assert_eq!(b, expected);
+
+    let row = RowVector4::from_row_arrays([[1, 2, 3, 4]]);
+    assert_eq!(row, RowVector4::new(1, 2, 3, 4));
+    let col = Vector3::from_row_arrays([[1], [2], [3]]);
+    assert_eq!(col, Vector3::new(1, 2, 3));
+}
+
 #[test]
 #[should_panic]
 fn from_too_many_rows() {
@@ -263,6 +348,41 @@ fn from_rows_with_different_dimensions() {
     let _ = DMatrix::from_columns(columns);
 }
 
+#[test]
+fn from_iterator_consumes_exactly_the_required_elements() {
+    let mut iter = 0..;
+    let m = DMatrix::from_iterator(2, 3, &mut iter);
+    assert_eq!(m, DMatrix::from_row_slice(2, 3, &[0, 2, 4, 1, 3, 5]));
+    assert_eq!(iter.next(), Some(6));
+
+    let v = DVector::from_iterator(2, &mut iter);
+    assert_eq!(v, DVector::from_vec(vec![7, 8]));
+    assert_eq!(iter.next(), Some(9));
+
+    let m = Matrix2x3::from_iterator(&mut iter);
+    assert_eq!(m, Matrix2x3::new(10, 12, 14, 11, 13, 15));
+    assert_eq!(iter.next(), Some(16));
+}
+
+#[test]
+fn from_vec_reuses_the_allocation() {
+    let vec = vec![0, 1, 2, 3, 4, 5];
+    let vec_ptr = vec.as_ptr();
+    let m = DMatrix::from_vec(2, 3, vec);
+    assert_eq!(m.data.as_vec().as_ptr(), vec_ptr);
+
+    let vec = vec![0, 1, 2, 3, 4, 5];
+    let vec_ptr = vec.as_ptr();
+    let m = OMatrix::<_, Const<2>, Dynamic>::from_vec_generic(Const::<2>, Dynamic::new(3), vec);
+    assert_eq!(m.data.as_vec().as_ptr(), vec_ptr);
+}
+
+#[test]
+#[should_panic]
+fn from_iterator_too_short() {
+    let _ = DMatrix::from_iterator(2, 3, 0..5);
+}
+
 #[test]
 fn copy_from_slice() {
     let mut a = Matrix3::zeros();
@@ -405,6 +525,56 @@ fn simple_product() {
     assert_eq!(a * b * c, vec![a, b, c].into_iter().product());
 }
 
+#[test]
+fn perp_vector() {
+    let v = Vector2::new(3.0, -4.0);
+    let w = Vector2::new(0.5, 2.0);
+    let p = v.perp_vector();
+
+    assert_eq!(p, Vector2::new(4.0, 3.0));
+    assert_eq!(p.dot(&v), 0.0);
+    assert_eq!(v.perp(&w), p.dot(&w));
+    assert_eq!(
+        v.perp(&w),
+        Vector3::new(v.x, v.y, 0.0)
+            .cross(&Vector3::new(w.x, w.y, 0.0))
+            .z
+    );
+    assert_eq!(p.perp_vector(), -v);
+}
+
+#[test]
+fn angle_and_signed_angle() {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    // Nearly collinear vectors for which the cosine could round to a value slightly above 1.
+    for i in 1..100 {
+        let v = Vector3::new(0.1 * i as f64, 0.7, 1.0 / 3.0);
+        assert!(!v.angle(&(v * 3.0)).is_nan());
+        assert!(!v.angle(&(-v * 7.0)).is_nan());
+    }
+    assert_eq!(Vector3::new(1.0, 2.0, 3.0).angle(&Vector3::zeros()), 0.0);
+
+    let x = Vector2::new(1.0, 0.0);
+    assert_relative_eq!(x.signed_angle(&Vector2::new(1.0, 1.0)), FRAC_PI_4);
+    assert_relative_eq!(x.signed_angle(&Vector2::new(1.0, -1.0)), -FRAC_PI_4);
+    assert_relative_eq!(x.signed_angle(&Vector2::new(-3.0, 0.0)), PI);
+    assert_eq!(x.signed_angle(&Vector2::zeros()), 0.0);
+    assert_eq!(Vector2::zeros().signed_angle(&x), 0.0);
+
+    let x = Vector3::x();
+    let axis = Vector3::new(0.0, 0.0, 5.0);
+    assert_relative_eq!(x.signed_angle(&Vector3::y(), &axis), FRAC_PI_2);
+    assert_relative_eq!(x.signed_angle(&-Vector3::y(), &axis), -FRAC_PI_2);
+    // The axis does not need to be orthogonal to the vectors.
+    let tilted_axis = Vector3::new(1.0, -1.0, 1.0);
+    assert_relative_eq!(
+        x.signed_angle(&Vector3::new(1.0, 0.0, 1.0), &tilted_axis),
+        FRAC_PI_4
+    );
+    assert_eq!(x.signed_angle(&Vector3::zeros(), &axis), 0.0);
+}
+
 #[test]
 fn cross_product_vector_and_row_vector() {
     let v1 = Vector3::new(1.0, 2.0, 3.0);
@@ -731,6 +901,161 @@ fn kronecker() {
     assert_eq!(a.kronecker(&b), expected);
 }
 
+#[test]
+fn weighted_trace() {
+    let a = Matrix3::new(1, 2, 3, 4, 5, 6, 7, 8, 9);
+    let d = Vector3::new(2, -1, 3);
+    assert_eq!(a.weighted_trace(&d), 2 - 5 + 27);
+    assert_eq!(
+        a.weighted_trace(&d),
+        (a * Matrix3::from_diagonal(&d)).trace()
+    );
+
+    let a = DMatrix::from_fn(5, 5, |i, j| (i * 5 + j) as f64);
+    let d = DVector::from_fn(5, |i, _| 1.0 / (i + 1) as f64);
+    assert_eq!(
+        a.weighted_trace(&d),
+        (DMatrix::from_diagonal(&d) * &a).trace()
+    );
+    assert_eq!(a.weighted_trace(&DVector::from_element(5, 1.0)), a.trace());
+}
+
+#[test]
+#[should_panic]
+fn weighted_trace_dimension_mismatch() {
+    let _ = DMatrix::<f64>::identity(3, 3).weighted_trace(&DVector::zeros(2));
+}
+
+#[test]
+#[should_panic]
+fn weighted_trace_non_square() {
+    let _ = DMatrix::<f64>::identity(3, 2).weighted_trace(&DVector::zeros(3));
+}
+
+#[test]
+fn kronecker_sum() {
+    let a = Matrix2::new(1, 2, 3, 4);
+    let b = Matrix3::new(5, 6, 7, 8, 9, 10, 11, 12, 13);
+
+    let expected = Matrix6::from_row_slice(&[
+        6, 6, 7, 2, 0, 0, 8, 10, 10, 0, 2, 0, 11, 12, 14, 0, 0, 2, 3, 0, 0, 9, 6, 7, 0, 3, 0, 8,
+        13, 10, 0, 0, 3, 11, 12, 17,
+    ]);
+    assert_eq!(a.kronecker_sum(&b), expected);
+    assert_eq!(
+        a.kronecker_sum(&b),
+        a.kronecker(&Matrix3::identity()) + Matrix2::identity().kronecker(&b)
+    );
+
+    let a = DMatrix::from_fn(3, 3, |i, j| (i * 3 + j) as i32);
+    let b = DMatrix::from_fn(4, 4, |i, j| (i as i32) - (j as i32));
+    assert_eq!(
+        a.kronecker_sum(&b),
+        a.kronecker(&DMatrix::identity(4, 4)) + DMatrix::identity(3, 3).kronecker(&b)
+    );
+    assert_eq!(a.kronecker_sum(&DMatrix::zeros(0, 0)).shape(), (0, 0));
+}
+
+#[test]
+#[should_panic]
+fn kronecker_sum_non_square() {
+    let _ = Matrix2::new(1, 2, 3, 4).kronecker_sum(&Matrix2x3::new(1, 2, 3, 4, 5, 6));
+}
+
+#[test]
+fn lerp_mut() {
+    let mut a = Matrix2x3::new(0.0, 1.0, 2.0, 3.0, 4.0, 5.0);
+    let b = Matrix2x3::new(4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let expected = a.lerp(&b, 0.25);
+    a.lerp_mut(&b, 0.25);
+    assert_eq!(a, expected);
+
+    // Dynamic destination, static source, and a slice as destination.
+    let mut d = DMatrix::from_row_slice(2, 3, &[0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    d.lerp_mut(&b, 0.25);
+    assert_eq!(d, expected);
+
+    let mut m = Matrix3::zeros();
+    m.row_mut(1).lerp_mut(&RowVector3::new(2.0, 4.0, 6.0), 0.5);
+    assert_eq!(m, Matrix3::new(0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 0.0, 0.0, 0.0));
+}
+
+#[test]
+#[should_panic]
+fn lerp_mut_dimension_mismatch() {
+    let mut a = DMatrix::<f64>::zeros(2, 3);
+    a.lerp_mut(&DMatrix::zeros(3, 2), 0.5);
+}
+
+#[test]
+fn trace_of_product() {
+    let a = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    let b = Matrix3x2::new(7, 8, 9, 10, 11, 12);
+
+    assert_eq!(a.trace_of_product(&b), (a * b).trace());
+    assert_eq!(b.trace_of_product(&a), (b * a).trace());
+
+    let a = DMatrix::from_fn(4, 7, |i, j| (i * 3 + j) as f64 - 5.0);
+    let b = DMatrix::from_fn(7, 4, |i, j| (i + 2 * j) as f64 * 0.5);
+    assert_eq!(a.trace_of_product(&b), (&a * &b).trace());
+}
+
+#[test]
+#[should_panic]
+fn trace_of_product_not_square() {
+    let a = DMatrix::<f64>::zeros(2, 3);
+    let b = DMatrix::<f64>::zeros(3, 3);
+    let _ = a.trace_of_product(&b);
+}
+
+#[test]
+fn outer() {
+    let a = Vector2::new(1, 2);
+    let b = Vector3::new(10, 20, 30);
+    let expected = Matrix2x3::new(10, 20, 30, 20, 40, 60);
+
+    assert_eq!(a.outer(&b), expected);
+    assert_eq!(a.outer(&b), a * b.transpose());
+
+    let a = DVector::from_vec(vec![1, 2, 3]);
+    let b = DVector::from_vec(vec![4, 5]);
+    let expected = DMatrix::from_row_slice(3, 2, &[4, 5, 8, 10, 12, 15]);
+
+    assert_eq!(a.outer(&b), expected);
+
+    let a = Vector2::new(Complex::new(1.0, 2.0), Complex::new(3.0, -1.0));
+    let b = Vector3::new(
+        Complex::new(0.0, 1.0),
+        Complex::new(2.0, -3.0),
+        Complex::new(-1.0, 0.5),
+    );
+
+    assert_eq!(a.outer(&b), a * b.transpose());
+    assert_eq!(a.outer_conjugate(&b), a * b.adjoint());
+}
+
+#[test]
+fn block_diagonal() {
+    let a = DMatrix::from_row_slice(2, 2, &[1, 2, 3, 4]);
+    let b = DMatrix::from_row_slice(1, 3, &[5, 6, 7]);
+    let c = DMatrix::from_row_slice(2, 1, &[8, 9]);
+    let expected = DMatrix::from_row_slice(
+        5,
+        6,
+        &[
+            1, 2, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 5, 6, 7, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0,
+            9,
+        ],
+    );
+
+    assert_eq!(DMatrix::block_diagonal(&[&a, &b, &c]), expected);
+    assert_eq!(DMatrix::<i32>::block_diagonal(&[]).shape(), (0, 0));
+
+    let empty = DMatrix::<i32>::zeros(0, 2);
+    let expected = DMatrix::from_row_slice(2, 4, &[1, 2, 0, 0, 3, 4, 0, 0]);
+    assert_eq!(DMatrix::block_diagonal(&[&a, &empty]), expected);
+}
+
 #[test]
 fn set_row_column() {
     let a = Matrix4x5::new(
@@ -880,6 +1205,29 @@ mod transposition_tests {
     }
 }
 
+#[cfg(feature = "proptest-support")]
+mod angle_tests {
+    use crate::proptest::*;
+    use proptest::{prop_assert, proptest};
+
+    proptest! {
+        #[test]
+        fn signed_angle_2d_is_consistent_with_angle(a in vector2(), b in vector2()) {
+            let angle = a.signed_angle(&b);
+            prop_assert!(relative_eq!(angle.abs(), a.angle(&b), epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(b.signed_angle(&a), -angle, epsilon = 1.0e-7));
+            prop_assert!(angle * a.perp(&b) >= 0.0);
+        }
+
+        #[test]
+        fn signed_angle_3d_is_consistent_with_angle(a in vector3(), b in vector3(), axis in vector3()) {
+            let angle = a.signed_angle(&b, &axis);
+            prop_assert!(relative_eq!(angle.abs(), a.angle(&b), epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(a.signed_angle(&b, &-axis), -angle, epsilon = 1.0e-7));
+        }
+    }
+}
+
 #[cfg(feature = "proptest-support")]
 mod inversion_tests {
     use super::*;
@@ -954,9 +1302,408 @@ mod normalization_tests {
                 prop_assert!(relative_eq!(nv.norm(), 1.0, epsilon = 1.0e-7));
             }
         }
+
+        #[test]
+        fn normalized_columns_norm_is_one(m in dmatrix()) {
+            let mut nm = m.clone();
+            nm.normalize_columns_mut();
+
+            for (norm, new_norm) in m.column_norms().iter().zip(nm.column_norms().iter()) {
+                if *norm != 0.0 {
+                    prop_assert!(relative_eq!(*new_norm, 1.0, epsilon = 1.0e-7));
+                }
+            }
+        }
     }
 }
 
+#[test]
+fn column_and_row_norms() {
+    let m = Matrix2x3::new(3.0, 0.0, 0.0, 4.0, -2.0, 0.0);
+
+    assert_eq!(m.column_norms(), RowVector3::new(5.0, 2.0, 0.0));
+    assert_eq!(m.row_norms(), Vector2::new(3.0, (20.0f64).sqrt()));
+    assert_eq!(m.transpose().row_norms(), m.column_norms().transpose());
+
+    let mut normalized = m;
+    normalized.normalize_columns_mut();
+    let expected = Matrix2x3::new(0.6, 0.0, 0.0, 0.8, -1.0, 0.0);
+    assert_relative_eq!(normalized, expected, epsilon = 1.0e-7);
+}
+
+#[test]
+fn is_hermitian() {
+    let m = Matrix2::new(
+        Complex::new(1.0, 0.0),
+        Complex::new(2.0, -3.0),
+        Complex::new(2.0, 3.0),
+        Complex::new(4.0, 0.0),
+    );
+    assert!(m.is_hermitian(1.0e-7));
+    assert!(!(m * Complex::i()).is_hermitian(1.0e-7));
+
+    // The tolerance applies to both the real and imaginary parts.
+    let mut perturbed = m;
+    perturbed[(0, 1)].im += 1.0e-3;
+    assert!(!perturbed.is_hermitian(1.0e-7));
+    assert!(perturbed.is_hermitian(1.0e-2));
+    assert!(perturbed.hermitian_part().is_hermitian(1.0e-7));
+
+    // Diagonal elements must be real.
+    let mut non_real_diagonal = m;
+    non_real_diagonal[(1, 1)].im = 1.0;
+    assert!(!non_real_diagonal.is_hermitian(1.0e-7));
+
+    assert!(Matrix3::<f64>::identity().is_hermitian(0.0));
+    assert!(!Matrix2x3::<f64>::zeros().is_hermitian(1.0e-7));
+}
+
+#[test]
+#[rustfmt::skip]
+fn is_positive_definite_and_semidefinite() {
+    let definite = Matrix3::new(
+        4.0, 1.0, 0.5,
+        1.0, 3.0, 0.2,
+        0.5, 0.2, 2.0);
+    assert!(definite.is_positive_definite());
+    assert!(definite.is_positive_semidefinite(1.0e-12));
+
+    // Rank-2 sum of outer products.
+    let u = Vector4::new(1.0, 2.0, -1.0, 0.5);
+    let v = Vector4::new(0.0, 1.0, 3.0, -2.0);
+    let semidefinite = u * u.transpose() + v * v.transpose();
+    assert!(!semidefinite.is_positive_definite());
+    assert!(semidefinite.is_positive_semidefinite(1.0e-10));
+
+    // Eigenvalues 3 and -1.
+    let indefinite = Matrix2::new(
+        1.0, 2.0,
+        2.0, 1.0);
+    assert!(!indefinite.is_positive_definite());
+    assert!(!indefinite.is_positive_semidefinite(1.0e-10));
+
+    // A zero diagonal with non-zero off-diagonal entries stops the pivoted factorization
+    // immediately, but is still indefinite.
+    let zero_diagonal = Matrix2::new(
+        0.0, 1.0,
+        1.0, 0.0);
+    assert!(!zero_diagonal.is_positive_semidefinite(1.0e-10));
+    assert!(Matrix3::<f64>::zeros().is_positive_semidefinite(0.0));
+
+    // A slightly negative eigenvalue is accepted within the tolerance.
+    let almost = Matrix2::new(1.0, 0.0, 0.0, -1.0e-12);
+    assert!(!almost.is_positive_definite());
+    assert!(almost.is_positive_semidefinite(1.0e-10));
+    assert!(!almost.is_positive_semidefinite(1.0e-14));
+
+    let hermitian = Matrix2::new(
+        Complex::new(2.0, 0.0), Complex::new(0.0, -1.0),
+        Complex::new(0.0, 1.0), Complex::new(2.0, 0.0));
+    assert!(hermitian.is_positive_definite());
+    assert!(hermitian.is_positive_semidefinite(1.0e-12));
+
+    let nan = Matrix2::new(1.0, 0.0, f64::NAN, 1.0);
+    assert!(!nan.is_positive_definite());
+    assert!(!nan.is_positive_semidefinite(1.0e-10));
+
+    assert!(!DMatrix::<f64>::identity(2, 3).is_positive_definite());
+    assert!(!DMatrix::<f64>::identity(2, 3).is_positive_semidefinite(1.0e-10));
+}
+
+#[test]
+fn normalize_matrix_frobenius() {
+    let m = Matrix2x3::new(1.0, 2.0, 0.0, 2.0, 0.0, 4.0);
+    assert_eq!(m.norm(), 5.0);
+
+    let normalized = m.normalize();
+    assert_relative_eq!(normalized, m / 5.0, epsilon = 1.0e-7);
+    assert_relative_eq!(normalized.norm(), 1.0, epsilon = 1.0e-7);
+    assert_eq!(m.try_normalize(1.0e-6), Some(normalized));
+    assert_eq!(m.try_normalize(5.0), None);
+    assert_eq!(DMatrix::<f64>::zeros(3, 2).try_normalize(0.0), None);
+
+    let mut in_place = m;
+    assert_eq!(in_place.try_normalize_mut(5.0), None);
+    assert_eq!(in_place, m);
+    assert_eq!(in_place.try_normalize_mut(1.0e-6), Some(5.0));
+    assert_eq!(in_place, normalized);
+}
+
+#[test]
+fn unit_try_new_or() {
+    let fallback = Vector3::x_axis();
+
+    let dir = Unit::try_new_or(Vector3::new(0.0, -2.0, 0.0), 1.0e-6, fallback);
+    assert_eq!(dir, -Vector3::y_axis());
+
+    assert_eq!(
+        Unit::try_new_or(Vector3::zeros(), 1.0e-6, fallback),
+        fallback
+    );
+    assert_eq!(
+        Unit::try_new_or(Vector3::new(1.0e-7, 0.0, 0.0), 1.0e-6, -fallback),
+        -fallback
+    );
+
+    // The dynamic case.
+    let fallback = Unit::new_normalize(DVector::from_element(4, 1.0));
+    let dir = Unit::try_new_or(DVector::zeros(4), 0.0, fallback.clone());
+    assert_eq!(dir, fallback);
+}
+
+#[test]
+fn new_uninitialized_then_written() {
+    let (nrows, ncols) = (37, 11);
+    let m = unsafe {
+        let mut m = DMatrix::<usize>::new_uninitialized(nrows, ncols);
+        let data = (*m.as_mut_ptr()).as_mut_ptr();
+        for k in 0..nrows * ncols {
+            data.add(k).write(k);
+        }
+        m.assume_init()
+    };
+
+    let expected = DMatrix::from_fn(nrows, ncols, |i, j| i + j * nrows);
+    assert_eq!(m, expected);
+}
+
+#[test]
+fn project_onto_and_reject_from() {
+    let a = Vector3::new(3.0, 4.0, -2.0);
+    let b = Vector3::new(1.0, 1.0, 0.0);
+
+    let proj = a.project_onto(&b);
+    let rej = a.reject_from(&b);
+    assert_relative_eq!(proj, Vector3::new(3.5, 3.5, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(proj + rej, a, epsilon = 1.0e-7);
+    assert_relative_eq!(rej.dot(&b), 0.0, epsilon = 1.0e-7);
+
+    // Projecting onto a scaled direction gives the same result.
+    assert_relative_eq!(a.project_onto(&(b * -10.0)), proj, epsilon = 1.0e-7);
+
+    // Zero directions.
+    let zero = Vector3::zeros();
+    assert_eq!(a.project_onto(&zero), zero);
+    assert_eq!(a.reject_from(&zero), a);
+    assert_eq!(a.try_project_onto(&zero, 0.0), None);
+    assert_eq!(a.try_reject_from(&zero, 0.0), None);
+    assert_eq!(a.try_project_onto(&(b * 1.0e-8), 1.0e-6), None);
+    assert_eq!(a.try_reject_from(&b, 1.0e-6), Some(rej));
+
+    // Complex vectors.
+    let a = DVector::from_vec(vec![Complex::new(1.0, 2.0), Complex::new(0.0, -1.0)]);
+    let b = DVector::from_vec(vec![Complex::new(0.0, 1.0), Complex::new(1.0, 1.0)]);
+    let proj = a.project_onto(&b);
+    let rej = a.reject_from(&b);
+    assert_relative_eq!(&proj + &rej, a, epsilon = 1.0e-7);
+    assert_relative_eq!(b.dotc(&rej), Complex::new(0.0, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(proj.project_onto(&b), proj, epsilon = 1.0e-7);
+}
+
+#[test]
+fn nan_and_infinite_detection() {
+    let finite = Matrix2x3::new(1.0, -2.0, 0.0, f64::MAX, f64::MIN_POSITIVE, 6.0);
+    assert!(finite.is_finite());
+    assert!(!finite.has_nan());
+    assert!(!finite.has_infinite());
+
+    let mut nan = finite;
+    nan[(1, 2)] = f64::NAN;
+    assert!(!nan.is_finite());
+    assert!(nan.has_nan());
+    assert!(!nan.has_infinite());
+
+    let mut inf = finite;
+    inf[(0, 1)] = f64::NEG_INFINITY;
+    assert!(!inf.is_finite());
+    assert!(!inf.has_nan());
+    assert!(inf.has_infinite());
+
+    let empty = DMatrix::<f64>::zeros(0, 4);
+    assert!(empty.is_finite() && !empty.has_nan() && !empty.has_infinite());
+
+    // Both parts of complex scalars are checked.
+    let mut c = DVector::from_element(3, Complex::new(1.0, -1.0));
+    assert!(c.is_finite());
+    c[1].im = f64::NAN;
+    assert!(c.has_nan() && !c.has_infinite() && !c.is_finite());
+    c[1].im = 0.0;
+    c[2].im = f64::INFINITY;
+    assert!(!c.has_nan() && c.has_infinite() && !c.is_finite());
+}
+
+#[test]
+fn orthonormalize_columns_mut() {
+    // The third column is a combination of the first two, and the fifth column is zero.
+    let mut m = DMatrix::from_row_slice(
+        4,
+        5,
+        &[
+            1.0, 0.0, 1.0, 2.0, 0.0, //
+            1.0, 1.0, 2.0, 0.0, 0.0, //
+            0.0, 1.0, 1.0, 1.0, 0.0, //
+            1.0, 0.0, 1.0, 0.0, 0.0,
+        ],
+    );
+    let original = m.clone();
+    let rank = m.orthonormalize_columns_mut(1.0e-10);
+    assert_eq!(rank, 3);
+
+    let basis = m.columns(0, rank);
+    assert_relative_eq!(
+        basis.ad_mul(&basis),
+        DMatrix::identity(3, 3),
+        epsilon = 1.0e-10
+    );
+    assert!(m.columns_range(rank..).iter().all(|e| *e == 0.0));
+
+    // The orthonormal columns span the same subspace as the original columns.
+    let projected = &basis * basis.ad_mul(&original);
+    assert_relative_eq!(projected, original, epsilon = 1.0e-10);
+
+    // Complex columns.
+    let mut c = Matrix3::new(
+        Complex::new(1.0, 1.0),
+        Complex::new(0.0, 2.0),
+        Complex::new(1.0, 0.0),
+        Complex::new(0.0, 0.0),
+        Complex::new(-2.0, 0.0),
+        Complex::new(0.0, 1.0),
+        Complex::new(1.0, 0.0),
+        Complex::new(1.0, 1.0),
+        Complex::new(0.0, 0.0),
+    );
+    assert_eq!(c.orthonormalize_columns_mut(1.0e-10), 3);
+    assert_relative_eq!(c.ad_mul(&c), Matrix3::identity(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn gram() {
+    let a = Matrix4x3::new(
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0,
+    );
+    let g = a.gram();
+    assert_eq!(g, a.transpose() * a);
+    assert_eq!(g, g.transpose());
+
+    let wide = DMatrix::from_fn(2, 5, |i, j| (i * 5 + j) as f64 - 3.0);
+    assert_eq!(wide.gram(), wide.tr_mul(&wide));
+
+    let c = Matrix2::new(
+        Complex::new(1.0, 2.0),
+        Complex::new(0.0, -1.0),
+        Complex::new(3.0, 0.5),
+        Complex::new(-2.0, 1.0),
+    );
+    let g = c.gram();
+    assert_relative_eq!(g, c.ad_mul(&c), epsilon = 1.0e-10);
+    assert!(g.is_hermitian(0.0));
+
+    assert_eq!(DMatrix::<f64>::zeros(3, 0).gram(), DMatrix::zeros(0, 0));
+}
+
+#[test]
+fn standardize_columns() {
+    let data = DMatrix::from_fn(6, 3, |i, j| match j {
+        0 => i as f64 * 2.0 - 1.0,
+        1 => ((i * 7) % 5) as f64 + 100.0,
+        _ => 0.1,
+    });
+    let (standardized, means, std_devs) = data.standardize_columns();
+
+    // The standardized features have zero mean and unit variance.
+    assert_relative_eq!(
+        standardized.row_mean(),
+        RowDVector::zeros(3),
+        epsilon = 1.0e-12
+    );
+    let variances = standardized.row_variance();
+    assert_relative_eq!(variances[0], 1.0, epsilon = 1.0e-12);
+    assert_relative_eq!(variances[1], 1.0, epsilon = 1.0e-12);
+
+    // The constant column is centered but left unscaled.
+    assert_eq!(std_devs[2], 1.0);
+    assert_relative_eq!(standardized.column(2).amax(), 0.0, epsilon = 1.0e-12);
+
+    // The transformation can be reapplied to the original data.
+    let mut reapplied = data.clone();
+    for (j, mut col) in reapplied.column_iter_mut().enumerate() {
+        col.add_scalar_mut(-means[j]);
+        col /= std_devs[j];
+    }
+    assert_relative_eq!(reapplied, standardized, epsilon = 1.0e-12);
+
+    let (empty, means, std_devs) = DMatrix::<f64>::zeros(0, 2).standardize_columns();
+    assert_eq!(empty.shape(), (0, 2));
+    assert_eq!(means, RowDVector::zeros(2));
+    assert_eq!(std_devs, RowDVector::from_element(2, 1.0));
+}
+
+#[test]
+fn ewma_covariance_update() {
+    let x = DVector::from_vec(vec![1.0, -2.0, 0.5]);
+    let mut covariance = DMatrix::from_fn(3, 3, |i, j| if i == j { 2.0 } else { 0.5 });
+    let expected = &covariance * 0.75 + &x * x.transpose() * 0.25;
+    covariance.ewma_covariance_update(&x, 0.25);
+    assert_relative_eq!(covariance, expected, epsilon = 1.0e-12);
+
+    // With alpha = 1, the previous estimate is discarded without being read.
+    let mut covariance = Matrix3::repeat(f64::NAN);
+    let x = Vector3::new(1.0, 2.0, 3.0);
+    covariance.ewma_covariance_update(&x, 1.0);
+    assert_eq!(covariance, x * x.transpose());
+
+    covariance.ewma_covariance_update(&Vector3::repeat(100.0), 0.0);
+    assert_eq!(covariance, x * x.transpose());
+}
+
+#[test]
+#[should_panic]
+fn ewma_covariance_update_invalid_alpha() {
+    let mut covariance = Matrix2::<f64>::identity();
+    covariance.ewma_covariance_update(&Vector2::new(1.0, 2.0), 1.5);
+}
+
+#[test]
+fn is_diagonal_and_triangular() {
+    let upper = Matrix3::new(1.0, 2.0, 3.0, 0.0, 4.0, 5.0, 0.0, 0.0, 6.0);
+    assert!(upper.is_upper_triangular(1.0e-7));
+    assert!(!upper.is_lower_triangular(1.0e-7));
+    assert!(!upper.is_diagonal(1.0e-7));
+    assert!(upper.transpose().is_lower_triangular(1.0e-7));
+    assert!(!upper.transpose().is_upper_triangular(1.0e-7));
+
+    let diag = Matrix3::from_diagonal(&Vector3::new(1.0, -2.0, 3.0));
+    assert!(diag.is_diagonal(1.0e-7));
+    assert!(diag.is_upper_triangular(1.0e-7));
+    assert!(diag.is_lower_triangular(1.0e-7));
+    assert!(!diag.is_identity(1.0e-7));
+
+    // The tolerance is honored.
+    let mut perturbed = diag;
+    perturbed[(2, 0)] = 1.0e-3;
+    assert!(!perturbed.is_diagonal(1.0e-7));
+    assert!(!perturbed.is_upper_triangular(1.0e-7));
+    assert!(perturbed.is_diagonal(1.0e-2));
+    assert!(perturbed.is_upper_triangular(1.0e-2));
+    assert!(perturbed.is_lower_triangular(1.0e-7));
+
+    // Rectangular matrices.
+    let wide = Matrix2x3::new(1.0, 2.0, 3.0, 0.0, 4.0, 5.0);
+    assert!(wide.is_upper_triangular(1.0e-7));
+    assert!(!wide.is_lower_triangular(1.0e-7));
+    let tall = wide.transpose();
+    assert!(tall.is_lower_triangular(1.0e-7));
+    assert!(!tall.is_upper_triangular(1.0e-7));
+    assert!(Matrix3x2::new(1.0, 0.0, 0.0, 2.0, 0.0, 0.0).is_diagonal(1.0e-7));
+    assert!(!Matrix3x2::new(1.0, 0.0, 0.0, 2.0, 3.0, 0.0).is_diagonal(1.0e-7));
+
+    let empty = DMatrix::<f64>::zeros(0, 3);
+    assert!(empty.is_diagonal(0.0));
+    assert!(empty.is_upper_triangular(0.0));
+    assert!(empty.is_lower_triangular(0.0));
+}
+
 #[cfg(all(feature = "proptest-support", feature = "alga"))]
 // TODO: move this to alga ?
 mod finite_dim_inner_space_tests {
@@ -164,6 +164,41 @@ fn col_slice_mut() {
     assert_eq!(expected_a, a.clone_owned());
 }
 
+#[test]
+#[rustfmt::skip]
+fn diagonal_mut() {
+    let mut a = Matrix3x4::new(11.0, 12.0, 13.0, 14.0,
+                               21.0, 22.0, 23.0, 24.0,
+                               31.0, 32.0, 33.0, 34.0);
+
+    {
+        let mut d = a.diagonal_mut();
+        assert_eq!(d.len(), 3);
+        assert_eq!(d.clone_owned(), Vector3::new(11.0, 22.0, 33.0));
+        d.axpy(1.0, &Vector3::new(0.5, 0.25, 0.125), 1.0);
+    }
+
+    let expected_a = Matrix3x4::new(11.5, 12.0, 13.0, 14.0,
+                                    21.0, 22.25, 23.0, 24.0,
+                                    31.0, 32.0, 33.125, 34.0);
+    assert_eq!(expected_a, a);
+
+    // Tall matrix, and a diagonal view of a slice.
+    let mut b = DMatrix::from_element(5, 3, 1.0);
+    b.diagonal_mut().fill(0.0);
+    b.rows_mut(2, 3).diagonal_mut().iter_mut().for_each(|d| *d += 2.0);
+
+    let expected_b = DMatrix::from_row_slice(5, 3, &[0.0, 1.0, 1.0,
+                                                     1.0, 0.0, 1.0,
+                                                     3.0, 1.0, 0.0,
+                                                     1.0, 3.0, 1.0,
+                                                     1.0, 1.0, 3.0]);
+    assert_eq!(expected_b, b);
+
+    let mut empty = DMatrix::<f64>::zeros(0, 3);
+    assert!(empty.diagonal_mut().is_empty());
+}
+
 #[test]
 #[rustfmt::skip]
 fn rows_range_pair() {
@@ -1,5 +1,6 @@
 #[cfg(feature = "abomonation-serialize")]
 mod abomonation;
+mod ascii;
 mod blas;
 mod cg;
 mod conversion;
@@ -9,6 +10,9 @@ mod matrix;
 mod matrix_slice;
 #[cfg(feature = "mint")]
 mod mint;
+mod packed_symmetric;
+#[cfg(feature = "rayon")]
+mod par_iter;
 mod serde;
 
 #[cfg(feature = "compare")]
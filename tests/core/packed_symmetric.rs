@@ -0,0 +1,84 @@
+use na::{Const, DMatrix, DVector, Matrix4, SymmetricMatrix, Vector4};
+
+#[test]
+fn packed_symmetric_layout() {
+    let m = Matrix4::new(
+        1, 2, 3, 4, //
+        2, 5, 6, 7, //
+        3, 6, 8, 9, //
+        4, 7, 9, 10,
+    );
+    let mut sym = SymmetricMatrix::from_lower_triangle(&m);
+
+    assert_eq!(sym.dim(), 4);
+    assert_eq!(sym.as_packed_slice(), &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    assert_eq!(sym.to_matrix(), m);
+
+    for i in 0..4 {
+        for j in 0..4 {
+            assert_eq!(sym[(i, j)], m[(i, j)]);
+        }
+    }
+
+    // Both triangles refer to the same stored entry.
+    sym[(0, 3)] = 40;
+    assert_eq!(sym[(3, 0)], 40);
+    assert_eq!(sym.as_packed_slice()[3], 40);
+
+    let packed = sym.clone().into_packed_vec();
+    assert_eq!(
+        SymmetricMatrix::from_packed_vec_generic(Const::<4>, packed),
+        sym
+    );
+}
+
+#[test]
+fn packed_symmetric_ignores_upper_triangle() {
+    let m = DMatrix::from_fn(5, 5, |i, j| if i >= j { (i * 5 + j) as f64 } else { -1.0 });
+    let sym = SymmetricMatrix::from_lower_triangle(&m);
+    let full = sym.to_matrix();
+
+    assert_eq!(full, full.transpose());
+    assert_eq!(full.lower_triangle(), m.lower_triangle());
+    assert_eq!(SymmetricMatrix::from_fn(5, |i, j| m[(i, j)]), sym);
+}
+
+#[test]
+fn packed_symmetric_mul_vector() {
+    let sym = SymmetricMatrix::from_fn(7, |i, j| (i * 7 + j) as f64 - 10.0);
+    let x = DVector::from_fn(7, |i, _| (i as f64) * 0.5 - 1.0);
+
+    assert_eq!(sym.mul_vector(&x), sym.to_matrix() * &x);
+    assert_eq!(&sym * &x, sym.to_matrix() * &x);
+
+    // Static matrix times a dynamic vector slice.
+    let sym = SymmetricMatrix::from_lower_triangle(&Matrix4::from_fn(|i, j| (i + 2 * j) as f64));
+    let v = DVector::from_fn(6, |i, _| i as f64);
+    assert_eq!(
+        sym.mul_vector(&v.rows(1, 4)),
+        sym.to_matrix() * Vector4::new(1.0, 2.0, 3.0, 4.0)
+    );
+
+    let empty = SymmetricMatrix::<f64, _>::from_fn(0, |_, _| unreachable!());
+    assert_eq!(empty.mul_vector(&DVector::zeros(0)), DVector::zeros(0));
+}
+
+#[test]
+#[should_panic]
+fn packed_symmetric_wrong_packed_len() {
+    let _ = SymmetricMatrix::from_packed_vec(3, vec![1.0; 5]);
+}
+
+#[test]
+#[should_panic]
+fn packed_symmetric_mul_vector_dimension_mismatch() {
+    let sym = SymmetricMatrix::from_fn(3, |_, _| 1.0);
+    let _ = sym.mul_vector(&DVector::zeros(4));
+}
+
+#[test]
+#[should_panic]
+fn packed_symmetric_index_out_of_bounds() {
+    let sym = SymmetricMatrix::from_fn(3, |_, _| 1.0);
+    let _ = sym[(0, 3)];
+}
@@ -0,0 +1,45 @@
+use na::{DMatrix, Matrix3x4};
+use rayon::prelude::*;
+
+#[test]
+fn par_column_iter() {
+    let m = DMatrix::from_fn(5, 257, |i, j| (i * 1000 + j) as f64);
+    let sums: Vec<f64> = m.par_column_iter().map(|col| col.sum()).collect();
+
+    assert_eq!(sums.len(), m.ncols());
+    for (j, sum) in sums.iter().enumerate() {
+        assert_eq!(*sum, m.column(j).sum());
+    }
+}
+
+#[test]
+fn par_column_iter_mut() {
+    let mut m = DMatrix::from_fn(5, 257, |i, j| (i * 1000 + j) as f64);
+    let expected = DMatrix::from_fn(5, 257, |i, j| ((i * 1000 + j) * (j + 1)) as f64);
+
+    m.par_column_iter_mut()
+        .enumerate()
+        .for_each(|(j, mut col)| col *= (j + 1) as f64);
+
+    assert_eq!(m, expected);
+}
+
+#[test]
+fn par_column_iter_static() {
+    let mut m = Matrix3x4::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+    let expected = Matrix3x4::new(2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13);
+
+    m.par_column_iter_mut()
+        .for_each(|mut col| col.add_scalar_mut(1));
+    assert_eq!(m, expected);
+
+    let maxima: Vec<i32> = m.par_column_iter().map(|col| col.max()).collect();
+    assert_eq!(maxima, vec![10, 11, 12, 13]);
+}
+
+#[test]
+fn par_column_iter_empty() {
+    let mut m = DMatrix::<f64>::zeros(3, 0);
+    assert_eq!(m.par_column_iter().count(), 0);
+    assert_eq!(m.par_column_iter_mut().count(), 0);
+}
@@ -1,4 +1,4 @@
-use na::{Quaternion, RealField, UnitQuaternion, Vector2, Vector3};
+use na::{Matrix3, Quaternion, RealField, Rotation3, UnitQuaternion, Vector2, Vector3};
 
 #[test]
 fn angle_2() {
@@ -30,9 +30,20 @@ fn quaternion_euler_angles_issue_494() {
     assert_eq!(angs.2, 0.0);
 }
 
+#[test]
+fn from_matrix_svd_reflection() {
+    let rot = Rotation3::from_euler_angles(0.1, 0.2, 0.3);
+    // A reflection with distinct singular values: the nearest rotation is `rot`.
+    let m = rot.matrix() * Matrix3::from_diagonal(&Vector3::new(3.0, 2.0, -0.5));
+
+    let projected = Rotation3::from_matrix_svd(&m, 1.0e-15, 0).unwrap();
+    assert_relative_eq!(projected.matrix().determinant(), 1.0, epsilon = 1.0e-10);
+    assert_relative_eq!(projected, rot, epsilon = 1.0e-10);
+}
+
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
-    use na::{self, Rotation2, Rotation3, Unit};
+    use na::{self, Matrix3, Rotation2, Rotation3, Unit};
     use simba::scalar::RealField;
     use std::f64;
 
@@ -212,6 +223,35 @@ mod proptest_tests {
             prop_assert!(relative_eq!(r.angle(), pangle, epsilon = 1.0e-7));
         }
 
+        #[test]
+        fn slerp_rotation_matrix(r1 in rotation3(), r2 in rotation3(), t in 0.0..1.0) {
+            let m = r1.matrix().slerp_rotation(r2.matrix(), t);
+
+            prop_assert!(m.is_special_orthogonal(1.0e-7));
+            prop_assert!(relative_eq!(m, r1.slerp(&r2, t).into_inner(), epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(r1.matrix().slerp_rotation(r2.matrix(), 0.0), r1.matrix(), epsilon = 1.0e-7));
+        }
+
+        #[test]
+        fn from_matrix_svd(r in rotation3(), d in vector3()) {
+            // Scaling along the rotated axes does not change the nearest rotation.
+            let scaling = Matrix3::from_diagonal(&d.map(|e| e.abs() + 1.0));
+            let projected = Rotation3::from_matrix_svd(&(r.matrix() * scaling), 1.0e-15, 0).unwrap();
+            prop_assert!(relative_eq!(projected, r, epsilon = 1.0e-7));
+        }
+
+        #[test]
+        fn lerp_matrix(m1 in matrix3(), m2 in matrix3(), t in PROPTEST_F64) {
+            prop_assert!(relative_eq!(m1.lerp(&m2, t), m1 * (1.0 - t) + m2 * t, epsilon = 1.0e-7));
+        }
+
+        #[test]
+        fn lerp_mut_matrix(m1 in matrix3(), m2 in matrix3(), t in PROPTEST_F64) {
+            let mut m = m1;
+            m.lerp_mut(&m2, t);
+            prop_assert_eq!(m, m1.lerp(&m2, t));
+        }
+
         #[test]
         fn powf_rotation_3(axisangle in vector3(), pow in PROPTEST_F64) {
             let r = Rotation3::new(axisangle).powf(pow);
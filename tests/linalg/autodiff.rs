@@ -0,0 +1,679 @@
+//! Checks that the decompositions can be used with a forward-mode automatic differentiation
+//! scalar, i.e., that they only rely on the `ComplexField` trait and not on `f32`/`f64`
+//! specifics.
+
+use std::fmt;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use na::{ComplexField, Matrix3, RealField, SimdValue, Vector3};
+use num::{Bounded, FromPrimitive, Num, One, Signed, Zero};
+use simba::scalar::SubsetOf;
+
+/// A dual number `re + eps ε` with `ε² = 0`.
+///
+/// Evaluating a function `f` on `x + ε` yields `f(x) + f'(x) ε`.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+struct Dual {
+    re: f64,
+    eps: f64,
+}
+
+impl Dual {
+    fn new(re: f64, eps: f64) -> Self {
+        Self { re, eps }
+    }
+
+    fn constant(re: f64) -> Self {
+        Self::new(re, 0.0)
+    }
+
+    /// Applies a function with value `f` and derivative `df` at `self.re`.
+    fn chain(self, f: f64, df: f64) -> Self {
+        Self::new(f, df * self.eps)
+    }
+}
+
+impl fmt::Display for Dual {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}ε", self.re, self.eps)
+    }
+}
+
+impl Add for Dual {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.eps + rhs.eps)
+    }
+}
+
+impl Sub for Dual {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.eps - rhs.eps)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.re * rhs.re, self.re * rhs.eps + self.eps * rhs.re)
+    }
+}
+
+impl Div for Dual {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        Self::new(
+            self.re / rhs.re,
+            (self.eps * rhs.re - self.re * rhs.eps) / (rhs.re * rhs.re),
+        )
+    }
+}
+
+impl Rem for Dual {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        self - rhs * (self / rhs).trunc()
+    }
+}
+
+impl Neg for Dual {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.eps)
+    }
+}
+
+macro_rules! impl_assign_op(
+    ($($Trait: ident, $method: ident, $op: tt);*) => {$(
+        impl $Trait for Dual {
+            fn $method(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    )*}
+);
+
+impl_assign_op!(
+    AddAssign, add_assign, +;
+    SubAssign, sub_assign, -;
+    MulAssign, mul_assign, *;
+    DivAssign, div_assign, /;
+    RemAssign, rem_assign, %
+);
+
+impl Zero for Dual {
+    fn zero() -> Self {
+        Self::constant(0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re == 0.0 && self.eps == 0.0
+    }
+}
+
+impl One for Dual {
+    fn one() -> Self {
+        Self::constant(1.0)
+    }
+}
+
+impl Num for Dual {
+    type FromStrRadixErr = <f64 as Num>::FromStrRadixErr;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        f64::from_str_radix(s, radix).map(Self::constant)
+    }
+}
+
+impl Signed for Dual {
+    fn abs(&self) -> Self {
+        ComplexField::abs(*self)
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self <= other {
+            Self::zero()
+        } else {
+            *self - *other
+        }
+    }
+
+    fn signum(&self) -> Self {
+        Self::constant(self.re.signum())
+    }
+
+    fn is_positive(&self) -> bool {
+        self.re > 0.0
+    }
+
+    fn is_negative(&self) -> bool {
+        self.re < 0.0
+    }
+}
+
+impl Bounded for Dual {
+    fn min_value() -> Self {
+        Self::constant(f64::MIN)
+    }
+
+    fn max_value() -> Self {
+        Self::constant(f64::MAX)
+    }
+}
+
+impl FromPrimitive for Dual {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Self::constant(n as f64))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::constant(n as f64))
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(Self::constant(n))
+    }
+}
+
+impl AbsDiffEq for Dual {
+    type Epsilon = Self;
+
+    fn default_epsilon() -> Self {
+        Self::constant(f64::default_epsilon())
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self) -> bool {
+        self.re.abs_diff_eq(&other.re, epsilon.re) && self.eps.abs_diff_eq(&other.eps, epsilon.re)
+    }
+}
+
+impl RelativeEq for Dual {
+    fn default_max_relative() -> Self {
+        Self::constant(f64::default_max_relative())
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self, max_relative: Self) -> bool {
+        self.re.relative_eq(&other.re, epsilon.re, max_relative.re)
+            && self
+                .eps
+                .relative_eq(&other.eps, epsilon.re, max_relative.re)
+    }
+}
+
+impl UlpsEq for Dual {
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self, max_ulps: u32) -> bool {
+        self.re.ulps_eq(&other.re, epsilon.re, max_ulps)
+            && self.eps.ulps_eq(&other.eps, epsilon.re, max_ulps)
+    }
+}
+
+impl SubsetOf<Dual> for Dual {
+    fn to_superset(&self) -> Dual {
+        *self
+    }
+
+    fn from_superset_unchecked(element: &Dual) -> Self {
+        *element
+    }
+
+    fn is_in_subset(_: &Dual) -> bool {
+        true
+    }
+}
+
+impl SubsetOf<Dual> for f64 {
+    fn to_superset(&self) -> Dual {
+        Dual::constant(*self)
+    }
+
+    fn from_superset_unchecked(element: &Dual) -> Self {
+        element.re
+    }
+
+    fn is_in_subset(element: &Dual) -> bool {
+        element.eps == 0.0
+    }
+}
+
+impl SimdValue for Dual {
+    type Element = Self;
+    type SimdBool = bool;
+
+    fn lanes() -> usize {
+        1
+    }
+
+    fn splat(val: Self) -> Self {
+        val
+    }
+
+    fn extract(&self, _: usize) -> Self {
+        *self
+    }
+
+    unsafe fn extract_unchecked(&self, _: usize) -> Self {
+        *self
+    }
+
+    fn replace(&mut self, _: usize, val: Self) {
+        *self = val
+    }
+
+    unsafe fn replace_unchecked(&mut self, _: usize, val: Self) {
+        *self = val
+    }
+
+    fn select(self, cond: bool, other: Self) -> Self {
+        if cond {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+impl na::Field for Dual {}
+
+impl ComplexField for Dual {
+    type RealField = Self;
+
+    fn from_real(re: Self) -> Self {
+        re
+    }
+
+    fn real(self) -> Self {
+        self
+    }
+
+    fn imaginary(self) -> Self {
+        Self::zero()
+    }
+
+    fn modulus(self) -> Self {
+        ComplexField::abs(self)
+    }
+
+    fn modulus_squared(self) -> Self {
+        self * self
+    }
+
+    fn argument(self) -> Self {
+        if self.re >= 0.0 {
+            Self::zero()
+        } else {
+            Self::pi()
+        }
+    }
+
+    fn norm1(self) -> Self {
+        ComplexField::abs(self)
+    }
+
+    fn scale(self, factor: Self) -> Self {
+        self * factor
+    }
+
+    fn unscale(self, factor: Self) -> Self {
+        self / factor
+    }
+
+    fn floor(self) -> Self {
+        Self::constant(self.re.floor())
+    }
+
+    fn ceil(self) -> Self {
+        Self::constant(self.re.ceil())
+    }
+
+    fn round(self) -> Self {
+        Self::constant(self.re.round())
+    }
+
+    fn trunc(self) -> Self {
+        Self::constant(self.re.trunc())
+    }
+
+    fn fract(self) -> Self {
+        Self::new(self.re.fract(), self.eps)
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    fn abs(self) -> Self {
+        if self.re < 0.0 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        (self * self + other * other).sqrt()
+    }
+
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+
+    fn conjugate(self) -> Self {
+        self
+    }
+
+    fn sin(self) -> Self {
+        self.chain(self.re.sin(), self.re.cos())
+    }
+
+    fn cos(self) -> Self {
+        self.chain(self.re.cos(), -self.re.sin())
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    fn tan(self) -> Self {
+        self.sin() / self.cos()
+    }
+
+    fn asin(self) -> Self {
+        self.chain(self.re.asin(), 1.0 / (1.0 - self.re * self.re).sqrt())
+    }
+
+    fn acos(self) -> Self {
+        self.chain(self.re.acos(), -1.0 / (1.0 - self.re * self.re).sqrt())
+    }
+
+    fn atan(self) -> Self {
+        self.chain(self.re.atan(), 1.0 / (1.0 + self.re * self.re))
+    }
+
+    fn sinh(self) -> Self {
+        self.chain(self.re.sinh(), self.re.cosh())
+    }
+
+    fn cosh(self) -> Self {
+        self.chain(self.re.cosh(), self.re.sinh())
+    }
+
+    fn tanh(self) -> Self {
+        self.sinh() / self.cosh()
+    }
+
+    fn asinh(self) -> Self {
+        self.chain(self.re.asinh(), 1.0 / (self.re * self.re + 1.0).sqrt())
+    }
+
+    fn acosh(self) -> Self {
+        self.chain(self.re.acosh(), 1.0 / (self.re * self.re - 1.0).sqrt())
+    }
+
+    fn atanh(self) -> Self {
+        self.chain(self.re.atanh(), 1.0 / (1.0 - self.re * self.re))
+    }
+
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    fn log2(self) -> Self {
+        self.log(Self::constant(2.0))
+    }
+
+    fn log10(self) -> Self {
+        self.log(Self::constant(10.0))
+    }
+
+    fn ln(self) -> Self {
+        self.chain(self.re.ln(), 1.0 / self.re)
+    }
+
+    fn ln_1p(self) -> Self {
+        self.chain(self.re.ln_1p(), 1.0 / (1.0 + self.re))
+    }
+
+    fn sqrt(self) -> Self {
+        let sqrt = self.re.sqrt();
+        self.chain(sqrt, 0.5 / sqrt)
+    }
+
+    fn exp(self) -> Self {
+        let exp = self.re.exp();
+        self.chain(exp, exp)
+    }
+
+    fn exp2(self) -> Self {
+        (self * Self::ln_2()).exp()
+    }
+
+    fn exp_m1(self) -> Self {
+        self.chain(self.re.exp_m1(), self.re.exp())
+    }
+
+    fn powi(self, n: i32) -> Self {
+        self.chain(self.re.powi(n), n as f64 * self.re.powi(n - 1))
+    }
+
+    fn powf(self, n: Self) -> Self {
+        (n * self.ln()).exp()
+    }
+
+    fn powc(self, n: Self) -> Self {
+        self.powf(n)
+    }
+
+    fn cbrt(self) -> Self {
+        let cbrt = self.re.cbrt();
+        self.chain(cbrt, 1.0 / (3.0 * cbrt * cbrt))
+    }
+
+    fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.eps.is_finite()
+    }
+
+    fn try_sqrt(self) -> Option<Self> {
+        if self.re >= 0.0 {
+            Some(self.sqrt())
+        } else {
+            None
+        }
+    }
+}
+
+impl RealField for Dual {
+    fn is_sign_positive(self) -> bool {
+        self.re.is_sign_positive()
+    }
+
+    fn is_sign_negative(self) -> bool {
+        self.re.is_sign_negative()
+    }
+
+    fn copysign(self, to: Self) -> Self {
+        if self.is_sign_positive() {
+            ComplexField::abs(to)
+        } else {
+            -ComplexField::abs(to)
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        if self >= other {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if self <= other {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn clamp(self, min: Self, max: Self) -> Self {
+        RealField::min(RealField::max(self, min), max)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        let denom = self.re * self.re + other.re * other.re;
+        Self::new(
+            self.re.atan2(other.re),
+            (other.re * self.eps - self.re * other.eps) / denom,
+        )
+    }
+
+    fn pi() -> Self {
+        Self::constant(f64::pi())
+    }
+
+    fn two_pi() -> Self {
+        Self::constant(f64::two_pi())
+    }
+
+    fn frac_pi_2() -> Self {
+        Self::constant(f64::frac_pi_2())
+    }
+
+    fn frac_pi_3() -> Self {
+        Self::constant(f64::frac_pi_3())
+    }
+
+    fn frac_pi_4() -> Self {
+        Self::constant(f64::frac_pi_4())
+    }
+
+    fn frac_pi_6() -> Self {
+        Self::constant(f64::frac_pi_6())
+    }
+
+    fn frac_pi_8() -> Self {
+        Self::constant(f64::frac_pi_8())
+    }
+
+    fn frac_1_pi() -> Self {
+        Self::constant(f64::frac_1_pi())
+    }
+
+    fn frac_2_pi() -> Self {
+        Self::constant(f64::frac_2_pi())
+    }
+
+    fn frac_2_sqrt_pi() -> Self {
+        Self::constant(f64::frac_2_sqrt_pi())
+    }
+
+    fn e() -> Self {
+        Self::constant(f64::e())
+    }
+
+    fn log2_e() -> Self {
+        Self::constant(f64::log2_e())
+    }
+
+    fn log10_e() -> Self {
+        Self::constant(f64::log10_e())
+    }
+
+    fn ln_2() -> Self {
+        Self::constant(f64::ln_2())
+    }
+
+    fn ln_10() -> Self {
+        Self::constant(f64::ln_10())
+    }
+}
+
+/// The symmetric positive-definite system `A(t) x(t) = b` used by the tests, with
+/// `A(t) = A0 + t A1`.
+fn system() -> (Matrix3<f64>, Matrix3<f64>, Vector3<f64>) {
+    let a0 = Matrix3::new(4.0, 1.0, 0.5, 1.0, 3.0, 0.2, 0.5, 0.2, 2.0);
+    let a1 = Matrix3::new(1.0, 0.3, 0.0, 0.3, 0.5, 0.1, 0.0, 0.1, 0.2);
+    let b = Vector3::new(1.0, -2.0, 0.5);
+    (a0, a1, b)
+}
+
+/// Builds `A(t)` as a matrix of dual numbers whose infinitesimal part is `dA/dt` at `t`.
+fn dual_matrix(t: f64) -> Matrix3<Dual> {
+    let (a0, a1, _) = system();
+    (a0 + a1 * t).zip_map(&a1, Dual::new)
+}
+
+/// Computes `x(t)` and its analytic derivative `dx/dt = -A(t)⁻¹ A1 x(t)`.
+fn expected_solution(t: f64) -> (Vector3<f64>, Vector3<f64>) {
+    let (a0, a1, b) = system();
+    let a = a0 + a1 * t;
+    let x = a.lu().solve(&b).unwrap();
+    let dx = -a.lu().solve(&(a1 * x)).unwrap();
+    (x, dx)
+}
+
+fn real_and_derivative(x: &Vector3<Dual>) -> (Vector3<f64>, Vector3<f64>) {
+    (x.map(|e| e.re), x.map(|e| e.eps))
+}
+
+#[test]
+fn autodiff_cholesky_solve() {
+    let t = 0.7;
+    let b = system().2.map(Dual::constant);
+    let x = dual_matrix(t).cholesky().unwrap().solve(&b);
+
+    let (x_re, dx) = real_and_derivative(&x);
+    let (expected_x, expected_dx) = expected_solution(t);
+    assert_relative_eq!(x_re, expected_x, epsilon = 1.0e-10);
+    assert_relative_eq!(dx, expected_dx, epsilon = 1.0e-10);
+
+    // Compare with central finite differences.
+    let h = 1.0e-6;
+    let (x_plus, _) = expected_solution(t + h);
+    let (x_minus, _) = expected_solution(t - h);
+    assert_relative_eq!(dx, (x_plus - x_minus) / (2.0 * h), epsilon = 1.0e-6);
+}
+
+#[test]
+fn autodiff_cholesky_determinant() {
+    let t = 0.3;
+    let (a0, a1, _) = system();
+    let a = a0 + a1 * t;
+    let det = dual_matrix(t).cholesky().unwrap().determinant();
+
+    // Jacobi's formula: d(det A)/dt = det(A) tr(A⁻¹ dA/dt).
+    let expected_ddet = a.determinant() * (a.try_inverse().unwrap() * a1).trace();
+    assert_relative_eq!(det.re, a.determinant(), epsilon = 1.0e-10);
+    assert_relative_eq!(det.eps, expected_ddet, epsilon = 1.0e-10);
+}
+
+#[test]
+fn autodiff_lu_solve() {
+    let t = -0.4;
+    let b = system().2.map(Dual::constant);
+    let x = dual_matrix(t).lu().solve(&b).unwrap();
+
+    let (x_re, dx) = real_and_derivative(&x);
+    let (expected_x, expected_dx) = expected_solution(t);
+    assert_relative_eq!(x_re, expected_x, epsilon = 1.0e-10);
+    assert_relative_eq!(dx, expected_dx, epsilon = 1.0e-10);
+}
+
+#[test]
+fn autodiff_qr_solve() {
+    let t = 1.5;
+    let b = system().2.map(Dual::constant);
+    let x = dual_matrix(t).qr().solve(&b).unwrap();
+
+    let (x_re, dx) = real_and_derivative(&x);
+    let (expected_x, expected_dx) = expected_solution(t);
+    assert_relative_eq!(x_re, expected_x, epsilon = 1.0e-10);
+    assert_relative_eq!(dx, expected_dx, epsilon = 1.0e-10);
+}
@@ -4,7 +4,7 @@ use na::balancing;
 use na::DMatrix;
 
 use crate::proptest::*;
-use proptest::{prop_assert_eq, proptest};
+use proptest::{prop_assert, prop_assert_eq, proptest};
 
 proptest! {
     #[test]
@@ -25,4 +25,17 @@ proptest! {
 
         prop_assert_eq!(balanced, m);
     }
+
+    #[test]
+    fn balancing_parlett_reinsch_unbalance_vectors(m in matrix4(), v in matrix4()) {
+        // If B = D⁻¹ * M * D then M * (D * v) = D * (B * v), so eigenvectors of B are mapped to
+        // eigenvectors of M.
+        let (balanced, d) = balancing::balance_parlett_reinsch_owned(m);
+        let mut bv = balanced * v;
+        let mut v = v;
+        balancing::unbalance_eigenvectors(&mut v, &d);
+        balancing::unbalance_eigenvectors(&mut bv, &d);
+
+        prop_assert!(relative_eq!(m * v, bv, epsilon = 1.0e-7, max_relative = 1.0e-7));
+    }
 }
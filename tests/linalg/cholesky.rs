@@ -5,8 +5,9 @@ macro_rules! gen_tests(
         mod $module {
             use na::debug::RandomSDP;
             use na::dimension::{Const, Dynamic};
-            use na::{DMatrix, DVector, Matrix4x3, Vector4};
+            use na::{DMatrix, DVector, Matrix4x2, Matrix4x3, Vector4};
             use rand::random;
+            use num::Zero;
             use simba::scalar::ComplexField;
             #[allow(unused_imports)]
             use crate::core::helper::{RandScalar, RandComplex};
@@ -22,6 +23,13 @@ macro_rules! gen_tests(
                     prop_assert!(relative_eq!(m, &l * l.adjoint(), epsilon = 1.0e-7));
                 }
 
+                #[test]
+                fn cholesky_l(n in PROPTEST_MATRIX_DIM) {
+                    let m = RandomSDP::new(Dynamic::new(n), || random::<$scalar>().0).unwrap();
+                    let l = m.cholesky_l().unwrap();
+                    prop_assert!(l == m.cholesky().unwrap().l());
+                }
+
                 #[test]
                 fn cholesky_static(_n in PROPTEST_MATRIX_DIM) {
                     let m = RandomSDP::new(Const::<4>, || random::<$scalar>().0).unwrap();
@@ -68,6 +76,7 @@ macro_rules! gen_tests(
                     let id2 = &m1 * &m;
 
                     prop_assert!(id1.is_identity(1.0e-7) && id2.is_identity(1.0e-7));
+                    prop_assert!(m1 == m1.adjoint());
                 }
 
                 #[test]
@@ -90,6 +99,16 @@ macro_rules! gen_tests(
                     prop_assert!(relative_eq!(lu_det.real(), chol_det, epsilon = 1.0e-7));
                 }
 
+                #[test]
+                fn cholesky_ln_determinant(n in PROPTEST_MATRIX_DIM) {
+                    let m = RandomSDP::new(Dynamic::new(n), || random::<$scalar>().0).unwrap();
+                    let lu_det = m.clone().lu().determinant();
+                    assert_relative_eq!(lu_det.imaginary(), 0., epsilon = 1.0e-7);
+                    let chol_ln_det = m.cholesky().unwrap().ln_determinant();
+
+                    prop_assert!(relative_eq!(lu_det.real().ln(), chol_ln_det, epsilon = 1.0e-7));
+                }
+
                 #[test]
                 fn cholesky_determinant_static(_n in PROPTEST_MATRIX_DIM) {
                     let m = RandomSDP::new(Const::<4>, || random::<$scalar>().0).unwrap();
@@ -100,6 +119,56 @@ macro_rules! gen_tests(
                     prop_assert!(relative_eq!(lu_det.real(), chol_det, epsilon = 1.0e-7));
                 }
 
+                #[test]
+                fn cholesky_woodbury_solve(_n in PROPTEST_MATRIX_DIM) {
+                    let m = RandomSDP::new(Const::<4>, || random::<$scalar>().0).unwrap();
+                    let c = RandomSDP::new(Const::<2>, || random::<$scalar>().0).unwrap();
+                    let u = Matrix4x2::<$scalar>::new_random().map(|e| e.0);
+                    let b = Matrix4x3::<$scalar>::new_random().map(|e| e.0);
+
+                    let chol = m.clone().cholesky().unwrap();
+                    let sol = chol.woodbury_solve(&u, &c, &u, &b).unwrap();
+                    let updated = m + u * c * u.adjoint();
+
+                    prop_assert!(relative_eq!(updated * sol, b, epsilon = 1.0e-7));
+                }
+
+                #[test]
+                fn cholesky_weighted_least_squares(_n in PROPTEST_MATRIX_DIM) {
+                    let w = RandomSDP::new(Const::<4>, || random::<$scalar>().0).unwrap();
+                    let a = Matrix4x2::<$scalar>::new_random().map(|e| e.0);
+                    let b = Matrix4x3::<$scalar>::new_random().map(|e| e.0);
+
+                    let chol = w.clone().cholesky().unwrap();
+                    let x = chol.weighted_least_squares(&a, &b).unwrap();
+
+                    // The solution satisfies the weighted normal equations.
+                    prop_assert!(relative_eq!(a.adjoint() * w * (a * x), a.adjoint() * w * b, epsilon = 1.0e-7));
+                }
+
+                #[test]
+                fn cholesky_transform_standard_normal(n in PROPTEST_MATRIX_DIM) {
+                    let m = RandomSDP::new(Dynamic::new(n), || random::<$scalar>().0).unwrap();
+                    let z = DVector::<$scalar>::new_random(n).map(|e| e.0);
+
+                    let chol = m.cholesky().unwrap();
+                    let x = chol.transform_standard_normal(&z);
+
+                    prop_assert!(relative_eq!(x, chol.l() * z, epsilon = 1.0e-7));
+                }
+
+                #[test]
+                fn cholesky_mahalanobis_squared(n in PROPTEST_MATRIX_DIM) {
+                    let m = RandomSDP::new(Dynamic::new(n), || random::<$scalar>().0).unwrap();
+                    let delta = DVector::<$scalar>::new_random(n).map(|e| e.0);
+
+                    let chol = m.cholesky().unwrap();
+                    let d2 = chol.mahalanobis_squared(&delta);
+
+                    prop_assert!(d2 >= 0.0);
+                    prop_assert!(relative_eq!(d2, delta.dotc(&chol.solve(&delta)).real(), epsilon = 1.0e-7));
+                }
+
                 #[test]
                 fn cholesky_rank_one_update(_n in PROPTEST_MATRIX_DIM) {
                     let mut m = RandomSDP::new(Const::<4>, || random::<$scalar>().0).unwrap();
@@ -154,6 +223,35 @@ macro_rules! gen_tests(
 
                     prop_assert!(relative_eq!(m_updated, m_chol_updated, epsilon = 1.0e-7));
                 }
+
+                #[test]
+                fn cholesky_pivoted(n in PROPTEST_MATRIX_DIM, k in PROPTEST_MATRIX_DIM) {
+                    let n = n.max(1);
+                    let k = k.min(n);
+                    let b = DMatrix::<$scalar>::new_random(n, k).map(|e| e.0);
+                    let m = &b * b.adjoint();
+
+                    let chol = m.clone().pivoted_cholesky(1.0e-10);
+                    let l = chol.l();
+                    prop_assert!(chol.rank() <= k);
+                    prop_assert!(l.columns_range(chol.rank()..).iter().all(|e| e.is_zero()));
+
+                    let mut m_chol = &l * l.adjoint();
+                    chol.p().inv_permute_rows(&mut m_chol);
+                    chol.p().inv_permute_columns(&mut m_chol);
+                    prop_assert!(relative_eq!(m, m_chol, epsilon = 1.0e-7));
+                }
+
+                #[test]
+                fn cholesky_pivoted_full_rank(_n in PROPTEST_MATRIX_DIM) {
+                    let m = RandomSDP::new(Const::<4>, || random::<$scalar>().0).unwrap();
+                    let (p, l) = na::Cholesky::new_pivoted(m, 1.0e-10).unpack();
+
+                    let mut m_chol = l * l.adjoint();
+                    p.inv_permute_rows(&mut m_chol);
+                    p.inv_permute_columns(&mut m_chol);
+                    prop_assert!(relative_eq!(m, m_chol, epsilon = 1.0e-7));
+                }
             }
         }
     }
@@ -161,3 +259,171 @@ macro_rules! gen_tests(
 
 gen_tests!(complex, RandComplex<f64>);
 gen_tests!(f64, RandScalar<f64>);
+
+#[test]
+#[cfg(feature = "rand")]
+fn cholesky_sample_gaussian() {
+    use na::{Matrix3, Vector3};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    let cov = Matrix3::new(4.0, 2.0, 0.6, 2.0, 5.0, 1.5, 0.6, 1.5, 3.0);
+    let mean = Vector3::new(1.0, -2.0, 3.0);
+    let chol = cov.cholesky().unwrap();
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let sample = chol.sample_gaussian(&mean, &mut rng);
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let z = Vector3::from_distribution(&rand_distr::StandardNormal, &mut rng);
+    assert_relative_eq!(sample, mean + chol.l() * z, epsilon = 1.0e-12);
+}
+
+#[test]
+fn cholesky_ln_determinant_no_overflow() {
+    // The determinant 1e-400 is not representable as a f64.
+    let m = na::DMatrix::from_diagonal_element(200, 200, 1.0e-2);
+    let chol = m.cholesky().unwrap();
+
+    assert_eq!(chol.determinant(), 0.0);
+    assert_relative_eq!(
+        chol.ln_determinant(),
+        200.0 * 1.0e-2f64.ln(),
+        epsilon = 1.0e-9
+    );
+}
+
+#[test]
+fn cholesky_non_contiguous_slice() {
+    let m = na::DMatrix::from_fn(6, 6, |i, j| if i == j { 10.0 } else { 1.0 });
+
+    // Every other row and column of a symmetric positive-definite matrix.
+    let slice = m.slice_with_steps((0, 0), (3, 3), (1, 1));
+    let l = slice.cholesky().unwrap().unpack();
+    assert_relative_eq!(slice.clone_owned(), &l * l.transpose(), epsilon = 1.0e-7);
+
+    let slice = m.fixed_slice::<4, 4>(1, 1);
+    let l = slice.cholesky().unwrap().unpack();
+    assert_relative_eq!(slice.clone_owned(), l * l.transpose(), epsilon = 1.0e-7);
+}
+
+#[test]
+fn cholesky_pivoted_semidefinite() {
+    use na::{Matrix3, Vector3};
+
+    // Rank-one matrix, with garbage in its strictly upper-triangular part which must be ignored.
+    let u = Vector3::new(1.0, -2.0, 3.0);
+    let mut m = u * u.transpose();
+    m[(0, 2)] = 100.0;
+    let chol = m.pivoted_cholesky(1.0e-12);
+    assert_eq!(chol.rank(), 1);
+
+    // The largest diagonal entry is chosen as the first pivot.
+    let mut pivoted = Vector3::new(0.0, 1.0, 2.0);
+    chol.p().permute_rows(&mut pivoted);
+    assert_eq!(pivoted[0], 2.0);
+
+    let l = chol.l();
+    assert_relative_eq!(l[(0, 0)], 3.0, epsilon = 1.0e-12);
+    let mut m_chol = l * l.transpose();
+    chol.p().inv_permute_rows(&mut m_chol);
+    chol.p().inv_permute_columns(&mut m_chol);
+    assert_relative_eq!(m_chol, u * u.transpose(), epsilon = 1.0e-12);
+
+    let zero = Matrix3::<f64>::zeros().pivoted_cholesky(0.0);
+    assert_eq!(zero.rank(), 0);
+    assert_eq!(zero.l(), Matrix3::zeros());
+
+    // The factorization stops at the first non-positive pivot of an indefinite matrix.
+    let indefinite = Matrix3::from_diagonal(&Vector3::new(-1.0, 4.0, 0.0));
+    assert_eq!(indefinite.pivoted_cholesky(1.0e-12).rank(), 1);
+}
+
+#[test]
+fn cholesky_new_with_info() {
+    use na::{Cholesky, DMatrix, Matrix4};
+
+    let m = Matrix4::new(
+        4.0, 2.0, 0.0, 0.0, 2.0, 5.0, 1.0, 0.0, 0.0, 1.0, -3.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+    );
+    assert_eq!(Cholesky::new_with_info(m).unwrap_err(), 2);
+    assert!(Cholesky::new(m).is_none());
+
+    // A zero pivot also fails.
+    let mut m = DMatrix::<f64>::identity(5, 5);
+    m[(3, 3)] = 0.0;
+    assert_eq!(Cholesky::new_with_info(m).unwrap_err(), 3);
+
+    // The first failing pivot is reported, and the happy path agrees with `Cholesky::new`.
+    let m = Matrix4::from_diagonal(&na::Vector4::new(1.0, -1.0, -1.0, 1.0));
+    assert_eq!(Cholesky::new_with_info(m).unwrap_err(), 1);
+
+    let spd = Matrix4::new(
+        4.0, 2.0, 0.0, 0.0, 2.0, 5.0, 1.0, 0.0, 0.0, 1.0, 3.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+    );
+    let chol = Cholesky::new_with_info(spd).unwrap();
+    assert_eq!(chol.l(), Cholesky::new(spd).unwrap().l());
+    assert_relative_eq!(chol.l() * chol.l().transpose(), spd, epsilon = 1.0e-12);
+}
+
+#[test]
+fn cholesky_packed() {
+    use na::{DMatrix, DVector, SymmetricMatrix};
+
+    let m = DMatrix::from_fn(6, 6, |i, j| {
+        if i == j {
+            10.0
+        } else {
+            1.0 / (1 + i + j) as f64
+        }
+    });
+    let b = DMatrix::from_fn(6, 2, |i, j| (i + 3 * j) as f64);
+
+    let chol = m.clone().cholesky().unwrap();
+    let packed = SymmetricMatrix::from_lower_triangle(&m).cholesky().unwrap();
+
+    assert_relative_eq!(packed.l(), chol.l(), epsilon = 1.0e-12);
+    assert_relative_eq!(packed.solve(&b), chol.solve(&b), epsilon = 1.0e-12);
+    assert_relative_eq!(packed.determinant(), chol.determinant(), epsilon = 1.0e-9);
+    assert_eq!(packed.l_packed().len(), 21);
+
+    let mut x = b.column(1).into_owned();
+    packed.solve_mut(&mut x);
+    assert_relative_eq!(&m * x, b.column(1).into_owned(), epsilon = 1.0e-12);
+
+    // Not definite-positive.
+    let indefinite = SymmetricMatrix::from_fn(3, |i, j| if i == j { 1.0 } else { 2.0 });
+    assert!(indefinite.cholesky().is_none());
+    let mut semidefinite = SymmetricMatrix::from_fn(3, |i, j| if i == j { 1.0 } else { 0.0 });
+    semidefinite[(2, 2)] = 0.0;
+    assert!(semidefinite.cholesky().is_none());
+    let mut nan = SymmetricMatrix::from_fn(3, |i, j| if i == j { 1.0 } else { 0.0 });
+    nan[(1, 1)] = f64::NAN;
+    assert!(nan.cholesky().is_none());
+
+    let empty = SymmetricMatrix::<f64, _>::from_fn(0, |_, _| unreachable!());
+    assert_eq!(
+        empty.cholesky().unwrap().solve(&DVector::zeros(0)),
+        DVector::zeros(0)
+    );
+}
+
+#[cfg(feature = "proptest-support")]
+mod packed_cholesky_proptest {
+    use crate::proptest::*;
+    use na::debug::RandomSDP;
+    use na::dimension::Dynamic;
+    use na::{DMatrix, SymmetricMatrix};
+    use proptest::{prop_assert, proptest};
+
+    proptest! {
+        #[test]
+        fn cholesky_packed_agrees_with_full(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+            let m = RandomSDP::new(Dynamic::new(n), || rand::random::<f64>()).unwrap();
+            let b = DMatrix::<f64>::new_random(n, nb);
+
+            let packed = SymmetricMatrix::from_lower_triangle(&m).cholesky().unwrap();
+            prop_assert!(relative_eq!(packed.l(), m.clone().cholesky().unwrap().l(), epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(&m * packed.solve(&b), b, epsilon = 1.0e-6));
+        }
+    }
+}
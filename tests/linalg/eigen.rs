@@ -1,4 +1,4 @@
-use na::DMatrix;
+use na::{DMatrix, DVector};
 
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
@@ -201,3 +201,76 @@ fn symmetric_eigen_singular_24x24() {
 //
 //     relative_eq!(eig.eigenvectors, mv, epsilon = 1.0e-5)
 // }
+
+#[test]
+fn orthogonal_iteration_symmetric() {
+    let basis = DMatrix::from_fn(6, 6, |i, j| {
+        ((i * 5 + j * 3) % 7) as f64 - 3.0 + (i == j) as u8 as f64
+    });
+    let v = basis.qr().q();
+    let eigenvalues = DVector::from_vec(vec![0.5, -7.0, 0.1, 10.0, 1.0, 3.0]);
+    let m = &v * DMatrix::from_diagonal(&eigenvalues) * v.transpose();
+
+    let (q, ritz_values) = m.orthogonal_iteration(3, 1.0e-12, 1000).unwrap();
+    assert_relative_eq!(
+        ritz_values,
+        DVector::from_vec(vec![10.0, -7.0, 3.0]),
+        epsilon = 1.0e-9
+    );
+    assert_relative_eq!(q.ad_mul(&q), DMatrix::identity(3, 3), epsilon = 1.0e-12);
+
+    // The computed basis spans the eigenvectors of the three dominant eigenvalues.
+    let dominant = v.select_columns(&[3, 1, 5]);
+    assert_relative_eq!(
+        &q * q.transpose(),
+        &dominant * dominant.transpose(),
+        epsilon = 1.0e-9
+    );
+}
+
+#[test]
+fn orthogonal_iteration_non_symmetric() {
+    let m = DMatrix::from_row_slice(
+        4,
+        4,
+        &[
+            1.0, 2.0, 0.0, 1.0, //
+            0.0, 5.0, 1.0, 3.0, //
+            0.0, 0.0, 0.5, 2.0, //
+            0.0, 0.0, 0.0, -3.0,
+        ],
+    );
+
+    let (q, ritz_values) = m.orthogonal_iteration(2, 1.0e-12, 1000).unwrap();
+    assert_relative_eq!(
+        ritz_values,
+        DVector::from_vec(vec![5.0, -3.0]),
+        epsilon = 1.0e-9
+    );
+
+    // The columns of `q` are Schur vectors: the subspace they span is invariant.
+    let t = q.transpose() * &m * &q;
+    assert_relative_eq!(&m * &q, &q * &t, epsilon = 1.0e-9);
+    assert_relative_eq!(t[(1, 0)], 0.0, epsilon = 1.0e-9);
+}
+
+#[test]
+fn orthogonal_iteration_edge_cases() {
+    let m = DMatrix::from_diagonal(&DVector::from_vec(vec![1.0, 0.99, 0.5]));
+    // The ratio between the first two eigenvalues is too close to 1 to converge quickly.
+    assert!(m.orthogonal_iteration(1, 1.0e-12, 10).is_none());
+
+    let (q, ritz_values) = m.orthogonal_iteration(0, 1.0e-12, 10).unwrap();
+    assert_eq!(q.shape(), (3, 0));
+    assert!(ritz_values.is_empty());
+
+    // With the full dimension, the result is a Schur decomposition.
+    let m = DMatrix::from_diagonal(&DVector::from_vec(vec![0.5, -4.0, 2.0]));
+    let (q, ritz_values) = m.orthogonal_iteration(3, 1.0e-12, 0).unwrap();
+    assert_relative_eq!(
+        ritz_values,
+        DVector::from_vec(vec![-4.0, 2.0, 0.5]),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(q.ad_mul(&q), DMatrix::identity(3, 3), epsilon = 1.0e-12);
+}
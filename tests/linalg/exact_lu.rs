@@ -0,0 +1,113 @@
+use na::{DMatrix, DVector, ExactLU, Matrix3, Matrix3x4, Matrix4x3, Vector3};
+use num_rational::Ratio;
+
+type Q = Ratio<i64>;
+
+fn q(numer: i64, denom: i64) -> Q {
+    Ratio::new(numer, denom)
+}
+
+#[test]
+#[rustfmt::skip]
+fn exact_lu_rational_reconstruction() {
+    let m = Matrix3::new(
+        q(1, 2), q(1, 3),  q(1, 4),
+        q(1, 3), q(1, 4),  q(1, 5),
+        q(1, 4), q(1, 5),  q(1, 6));
+
+    let lu = ExactLU::new(m);
+    // The determinant of the 3x3 Hilbert matrix (shifted by one) is known in closed form.
+    assert_eq!(lu.determinant(), q(1, 43200));
+    assert!(lu.is_invertible());
+
+    let (p, l, u) = lu.unpack();
+    let mut lu = l * u;
+    p.inv_permute_rows(&mut lu);
+    assert_eq!(lu, m);
+}
+
+#[test]
+#[rustfmt::skip]
+fn exact_lu_rational_solve_and_inverse() {
+    let m = Matrix3::new(
+        q(0, 1), q(2, 3),  q(1, 1),
+        q(3, 1), q(1, 7),  q(-4, 1),
+        q(1, 2), q(5, 1),  q(9, 1));
+    let b = Vector3::new(q(1, 1), q(-2, 3), q(7, 5));
+
+    let lu = ExactLU::new(m);
+    let x = lu.solve(&b).unwrap();
+    assert_eq!(m * x, b);
+
+    let inv = lu.try_inverse().unwrap();
+    assert_eq!(m * inv, Matrix3::identity());
+    assert_eq!(inv * m, Matrix3::identity());
+}
+
+#[test]
+#[rustfmt::skip]
+fn exact_lu_rational_singular() {
+    let m = Matrix3::new(
+        q(1, 1), q(2, 1), q(3, 1),
+        q(2, 1), q(4, 1), q(6, 1),
+        q(1, 3), q(0, 1), q(1, 1));
+
+    let lu = ExactLU::new(m);
+    assert!(!lu.is_invertible());
+    assert_eq!(lu.determinant(), q(0, 1));
+    assert!(lu.solve(&Vector3::repeat(q(1, 1))).is_none());
+    assert!(lu.try_inverse().is_none());
+
+    let (p, l, u) = lu.unpack();
+    let mut lu = l * u;
+    p.inv_permute_rows(&mut lu);
+    assert_eq!(lu, m);
+}
+
+#[test]
+#[rustfmt::skip]
+fn exact_lu_rational_rectangular() {
+    let m = Matrix3x4::new(
+        q(0, 1), q(0, 1), q(1, 2), q(1, 1),
+        q(0, 1), q(3, 1), q(1, 1), q(-2, 5),
+        q(2, 1), q(1, 1), q(0, 1), q(1, 3));
+
+    let (p, l, u) = ExactLU::new(m).unpack();
+    let mut lu = l * u;
+    p.inv_permute_rows(&mut lu);
+    assert_eq!(lu, m);
+
+    let m = m.transpose();
+    let (p, l, u) = ExactLU::new(m).unpack();
+    let mut lu: Matrix4x3<Q> = l * u;
+    p.inv_permute_rows(&mut lu);
+    assert_eq!(lu, m);
+}
+
+#[test]
+fn exact_lu_rational_dynamic() {
+    let n = 6;
+    // The off-diagonal entries have magnitudes of at most 3, so a diagonal of 16 makes the matrix
+    // strictly diagonally dominant, hence invertible.
+    let m = DMatrix::from_fn(n, n, |i, j| {
+        if i == j {
+            q(16, 1)
+        } else {
+            q((i * i + 3 * j) as i64 % 7 - 3, ((i + j) % 2 + 1) as i64)
+        }
+    });
+    let b = DVector::from_fn(n, |i, _| q(i as i64 - 2, 3));
+
+    let lu = ExactLU::new(m.clone());
+    let (p, l, u) = lu.clone().unpack();
+    let mut plu = l * u;
+    p.inv_permute_rows(&mut plu);
+    assert_eq!(plu, m);
+
+    assert!(lu.is_invertible());
+    assert_eq!(&m * lu.solve(&b).unwrap(), b);
+
+    let empty = ExactLU::new(DMatrix::<Q>::zeros(0, 0));
+    assert_eq!(empty.determinant(), q(1, 1));
+    assert_eq!(empty.try_inverse(), Some(DMatrix::zeros(0, 0)));
+}
@@ -0,0 +1,76 @@
+use na::linalg::householder::Householder;
+use na::{Complex, DMatrix, DVector, Matrix3, Matrix3x4, Matrix4x3, Vector3};
+
+#[test]
+fn householder_zeroes_all_but_first_component() {
+    let v = Vector3::new(1.0, -2.0, 2.0);
+    let (householder, alpha) = Householder::new(&v);
+    assert_relative_eq!(alpha, -3.0, epsilon = 1.0e-12);
+    assert_relative_eq!(householder.axis().norm(), 1.0, epsilon = 1.0e-12);
+
+    let mut reflected = v;
+    householder.apply_left(&mut reflected);
+    assert_relative_eq!(reflected, Vector3::new(-3.0, 0.0, 0.0), epsilon = 1.0e-12);
+
+    // The reflection is its own inverse.
+    householder.apply_left(&mut reflected);
+    assert_relative_eq!(reflected, v, epsilon = 1.0e-12);
+}
+
+#[test]
+fn householder_complex() {
+    let v = DVector::from_vec(vec![
+        Complex::new(0.0, 2.0),
+        Complex::new(1.0, -1.0),
+        Complex::new(-3.0, 0.5),
+        Complex::new(0.0, 0.0),
+    ]);
+    let (householder, alpha) = Householder::new(&v);
+
+    // alpha has the modulus of the norm of `v`, and the opposite argument of `v[0]`.
+    assert_relative_eq!(alpha.norm(), v.norm(), epsilon = 1.0e-12);
+    assert_relative_eq!(alpha, Complex::new(0.0, -v.norm()), epsilon = 1.0e-12);
+
+    let mut reflected = v.clone();
+    householder.apply_left(&mut reflected);
+    let mut expected = DVector::zeros(4);
+    expected[0] = alpha;
+    assert_relative_eq!(reflected, expected, epsilon = 1.0e-12);
+}
+
+#[test]
+fn householder_apply_left_and_right() {
+    let (householder, _) = Householder::new(&Vector3::new(2.0, 1.0, -2.0));
+    let axis = householder.axis();
+    let h = Matrix3::identity() - axis * axis.transpose() * 2.0;
+    assert_relative_eq!(h * h, Matrix3::identity(), epsilon = 1.0e-12);
+
+    let m = Matrix3x4::from_fn(|i, j| (i * 4 + j) as f64 - 5.0);
+    let mut left = m;
+    householder.apply_left(&mut left);
+    assert_relative_eq!(left, h * m, epsilon = 1.0e-12);
+
+    let m = m.transpose();
+    let mut right: Matrix4x3<f64> = m;
+    householder.apply_right(&mut right);
+    assert_relative_eq!(right, m * h, epsilon = 1.0e-12);
+
+    let dm = DMatrix::from_fn(2, 3, |i, j| (i + 2 * j) as f64);
+    let mut right = dm.clone();
+    householder.apply_right(&mut right);
+    let h = DMatrix::from_iterator(3, 3, h.iter().copied());
+    assert_relative_eq!(right, dm * h, epsilon = 1.0e-12);
+}
+
+#[test]
+fn householder_of_zero_is_identity() {
+    let (householder, alpha) = Householder::new(&DVector::<f64>::zeros(3));
+    assert_eq!(alpha, 0.0);
+    assert_eq!(householder.axis(), &DVector::zeros(3));
+
+    let mut m = DMatrix::from_fn(3, 3, |i, j| (i * 3 + j) as f64);
+    let expected = m.clone();
+    householder.apply_left(&mut m);
+    householder.apply_right(&mut m);
+    assert_eq!(m, expected);
+}
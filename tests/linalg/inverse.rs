@@ -1,4 +1,4 @@
-use na::{Matrix1, Matrix2, Matrix3, Matrix4, Matrix5};
+use na::{Complex, DMatrix, Matrix1, Matrix2, Matrix3, Matrix4, Matrix5, Matrix6};
 
 #[test]
 fn matrix1_try_inverse() {
@@ -140,3 +140,185 @@ fn matrix5_try_inverse_scaled_identity() {
 
     assert_relative_eq!(a_inv, expected_inverse);
 }
+
+#[test]
+#[rustfmt::skip]
+fn adjugate_is_scaled_inverse() {
+    let a2 = Matrix2::new(  5.0,  -2.0,
+                          -10.0,   1.0);
+    let a3 = Matrix3::new(1.0, 2.0, 3.0,
+                          0.0, 1.0, 4.0,
+                          5.0, 6.0, 0.0);
+    let a4 = Matrix4::new(4.0, 1.0, 0.0, 2.0,
+                          1.0, 3.0, 1.0, 0.0,
+                          0.0, 1.0, 5.0, 1.0,
+                          2.0, 0.0, 1.0, 6.0);
+    let a5 = Matrix5::new(-2.0,   0.0,   2.0,   5.0,  -5.0,
+                          -6.0,   4.0,   4.0,  13.0, -15.0,
+                           4.0,  16.0, -14.0, -19.0,  12.0,
+                          12.0,  12.0, -22.0, -35.0,  34.0,
+                          -8.0,   4.0,  12.0,  27.0, -31.0);
+
+    assert_relative_eq!(a2.adjugate(), a2.try_inverse().unwrap() * a2.determinant(), epsilon = 1.0e-10);
+    assert_relative_eq!(a3.adjugate(), a3.try_inverse().unwrap() * a3.determinant(), epsilon = 1.0e-10);
+    assert_relative_eq!(a4.adjugate(), a4.try_inverse().unwrap() * a4.determinant(), epsilon = 1.0e-10);
+    assert_relative_eq!(a5.adjugate(), a5.try_inverse().unwrap() * a5.determinant(), epsilon = 1.0e-10);
+}
+
+#[test]
+#[rustfmt::skip]
+fn adjugate_singular() {
+    // The adjugate of a rank-deficient matrix is still well-defined.
+    let a = Matrix3::new(1.0, 2.0, 3.0,
+                         4.0, 5.0, 6.0,
+                         7.0, 8.0, 9.0);
+    let expected = Matrix3::new(-3.0,   6.0, -3.0,
+                                 6.0, -12.0,  6.0,
+                                -3.0,   6.0, -3.0);
+    assert_eq!(a.adjugate(), expected);
+    assert_eq!(a * a.adjugate(), Matrix3::zeros());
+
+    assert_eq!(Matrix1::new(5.0).adjugate(), Matrix1::new(1.0));
+    assert_eq!(DMatrix::<f64>::zeros(0, 0).adjugate(), DMatrix::zeros(0, 0));
+}
+
+#[test]
+#[rustfmt::skip]
+fn adjugate_complex() {
+    let a = Matrix2::new(Complex::new(1.0, 1.0), Complex::new(2.0, 0.0),
+                         Complex::new(3.0, 0.0), Complex::new(4.0, -1.0));
+    let expected = Matrix2::new(Complex::new( 4.0, -1.0), Complex::new(-2.0, 0.0),
+                                Complex::new(-3.0,  0.0), Complex::new( 1.0, 1.0));
+    assert_eq!(a.adjugate(), expected);
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    use crate::proptest::*;
+    use na::{DMatrix, Matrix3, Matrix4};
+    use proptest::{prop_assert, proptest};
+
+    proptest! {
+        #[test]
+        fn adjugate_times_matrix_is_scaled_identity(m3 in matrix3(), m4 in matrix4()) {
+            let det3 = m3.determinant();
+            prop_assert!(relative_eq!(m3 * m3.adjugate(), Matrix3::identity() * det3, epsilon = 1.0e-5));
+            prop_assert!(relative_eq!(m3.adjugate() * m3, Matrix3::identity() * det3, epsilon = 1.0e-5));
+
+            let det4 = m4.determinant();
+            prop_assert!(relative_eq!(m4 * m4.adjugate(), Matrix4::identity() * det4, epsilon = 1.0e-3));
+            prop_assert!(relative_eq!(m4.adjugate() * m4, Matrix4::identity() * det4, epsilon = 1.0e-3));
+        }
+
+        #[test]
+        fn adjugate_general_path_agrees_with_closed_forms(m3 in matrix3()) {
+            // Embedding the matrix in a larger block-diagonal one forces the cofactor-based path.
+            let mut big = DMatrix::identity(4, 4);
+            big.fixed_slice_mut::<3, 3>(0, 0).copy_from(&m3);
+            let big_adj = big.adjugate();
+            prop_assert!(relative_eq!(big_adj.fixed_slice::<3, 3>(0, 0).into_owned(), m3.adjugate(), epsilon = 1.0e-5));
+            prop_assert!(relative_eq!(big_adj[(3, 3)], m3.determinant(), epsilon = 1.0e-5));
+        }
+
+        #[test]
+        fn try_inverse_mut_agrees_with_lu(m in dmatrix()) {
+            let n = m.nrows().min(m.ncols());
+            let m = m.slice((0, 0), (n, n)).into_owned();
+            let lu = m.clone().lu();
+
+            let mut inv = m.clone();
+            if lu.rcond(&m) > 1.0e-6 {
+                prop_assert!(inv.try_inverse_mut());
+                prop_assert!(relative_eq!(inv, lu.try_inverse().unwrap(), epsilon = 1.0e-5, max_relative = 1.0e-5));
+                prop_assert!(relative_eq!(&m * &inv, DMatrix::identity(n, n), epsilon = 1.0e-5));
+            }
+        }
+    }
+}
+
+#[test]
+fn inverse_orthogonal() {
+    let rot = na::Rotation3::from_euler_angles(0.3, -1.2, 2.0);
+    let m = *rot.matrix();
+
+    assert_relative_eq!(
+        m.inverse_orthogonal(),
+        m.try_inverse().unwrap(),
+        epsilon = 1.0e-12
+    );
+    assert_eq!(m.try_inverse_orthogonal(1.0e-12), Some(m.transpose()));
+    assert_eq!(m.inverse_orthogonal(), *rot.inverse().matrix());
+
+    // A reflection is orthogonal as well, but not a rotation.
+    let refl = DMatrix::from_diagonal(&na::DVector::from_vec(vec![1.0, -1.0, 1.0, 1.0]));
+    assert_eq!(refl.try_inverse_orthogonal(1.0e-12), Some(refl.clone()));
+}
+
+#[test]
+fn inverse_orthogonal_unitary() {
+    let s = 0.5f64.sqrt();
+    let u = Matrix2::new(
+        Complex::new(s, 0.0),
+        Complex::new(0.0, s),
+        Complex::new(0.0, s),
+        Complex::new(s, 0.0),
+    );
+
+    let u_inv = u.try_inverse_orthogonal(1.0e-12).unwrap();
+    assert_eq!(u_inv, u.adjoint());
+    assert_relative_eq!(u_inv * u, Matrix2::identity(), epsilon = 1.0e-12);
+}
+
+#[test]
+fn try_inverse_orthogonal_rejects_non_orthogonal() {
+    let m = Matrix3::new(2.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0);
+    assert!(m.try_inverse().is_some());
+    assert!(m.try_inverse_orthogonal(1.0e-7).is_none());
+}
+
+#[test]
+#[should_panic]
+fn inverse_orthogonal_non_square() {
+    let _ = DMatrix::<f64>::zeros(2, 3).inverse_orthogonal();
+}
+
+#[test]
+fn try_inverse_mut_requires_pivoting() {
+    // A cyclic permutation matrix scaled by distinct factors has only zeros on its diagonal.
+    let m = Matrix6::from_fn(|i, j| {
+        if j == (i + 1) % 6 {
+            (i + 2) as f64
+        } else {
+            0.0
+        }
+    });
+    let mut inv = m;
+    assert!(inv.try_inverse_mut());
+    assert_relative_eq!(m * inv, Matrix6::identity(), epsilon = 1.0e-14);
+    assert_relative_eq!(inv * m, Matrix6::identity(), epsilon = 1.0e-14);
+
+    let m = DMatrix::from_fn(7, 7, |i, j| {
+        Complex::new(
+            (i * 3 + j * 5) as f64 % 7.0,
+            if i == j { 1.0 } else { -0.5 },
+        )
+    });
+    let mut inv = m.clone();
+    assert!(inv.try_inverse_mut());
+    assert_relative_eq!(&m * &inv, DMatrix::identity(7, 7), epsilon = 1.0e-12);
+}
+
+#[test]
+#[rustfmt::skip]
+fn try_inverse_mut_singular() {
+    let m = Matrix5::new(
+        1.0, 2.0, 3.0, 4.0, 5.0,
+        0.0, 1.0, 0.0, 1.0, 0.0,
+        2.0, 4.0, 6.0, 8.0, 10.0,
+        1.0, 0.0, 1.0, 0.0, 1.0,
+        3.0, 1.0, 4.0, 1.0, 5.0);
+    // `try_inverse_mut` may have overwritten its input when failing.
+    let mut inv = m;
+    assert!(!inv.try_inverse_mut());
+    assert!(m.try_inverse().is_none());
+}
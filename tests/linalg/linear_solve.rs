@@ -0,0 +1,148 @@
+use na::{Complex, DMatrix, Dim, FactorizedMatrix, LinearSolve, Matrix3, Matrix3x2, U3};
+use std::sync::Arc;
+use std::thread;
+
+fn residual<D: LinearSolve<f64, U3>>(decomp: &D, a: &Matrix3<f64>, b: &Matrix3x2<f64>) -> f64 {
+    let x = decomp.solve(b).unwrap();
+
+    let mut x_mut = *b;
+    assert!(decomp.solve_mut(&mut x_mut));
+    assert_eq!(x, x_mut);
+
+    (a * x - b).norm()
+}
+
+#[test]
+fn linear_solve_is_generic_over_decompositions() {
+    let a = Matrix3::new(4.0, 1.0, 0.5, 1.0, 3.0, -1.0, 0.5, -1.0, 5.0);
+    let b = Matrix3x2::new(1.0, 0.0, 2.0, -1.0, 3.0, 4.0);
+
+    assert!(residual(&a.lu(), &a, &b) < 1.0e-12);
+    assert!(residual(&a.full_piv_lu(), &a, &b) < 1.0e-12);
+    assert!(residual(&a.qr(), &a, &b) < 1.0e-12);
+    assert!(residual(&a.cholesky().unwrap(), &a, &b) < 1.0e-12);
+}
+
+#[test]
+fn linear_solve_singular() {
+    fn try_solve<D: LinearSolve<f64, U3>>(decomp: &D) -> bool {
+        decomp.solve(&Matrix3::identity()).is_some()
+    }
+
+    let singular = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 1.0);
+    assert!(!try_solve(&singular.lu()));
+    assert!(!try_solve(&singular.full_piv_lu()));
+}
+
+#[test]
+fn linear_solve_dynamic_complex() {
+    fn solve<R: Dim, D: LinearSolve<Complex<f64>, R>>(
+        decomp: &D,
+        b: &DMatrix<Complex<f64>>,
+    ) -> DMatrix<Complex<f64>>
+    where
+        na::constraint::ShapeConstraint: na::constraint::SameNumberOfRows<na::Dynamic, R>,
+    {
+        decomp.solve(b).unwrap()
+    }
+
+    let a = DMatrix::from_fn(4, 4, |i, j| {
+        if i == j {
+            Complex::new(10.0, 0.0)
+        } else {
+            Complex::new((i + j) as f64, i as f64 - j as f64)
+        }
+    });
+    let b = DMatrix::from_fn(4, 1, |i, _| Complex::new(i as f64, 1.0));
+
+    for x in [solve(&a.clone().lu(), &b), solve(&a.clone().qr(), &b)].iter() {
+        assert_relative_eq!(&a * x, b, epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn solve_spd_or_general() {
+    let b = Matrix3x2::new(1.0, 0.0, 2.0, -1.0, 3.0, 4.0);
+
+    // Symmetric positive-definite.
+    let spd = Matrix3::new(4.0, 1.0, 0.5, 1.0, 3.0, -1.0, 0.5, -1.0, 5.0);
+    let x = spd.solve_spd_or_general(&b, 1.0e-12).unwrap();
+    assert_relative_eq!(x, spd.cholesky().unwrap().solve(&b), epsilon = 1.0e-12);
+
+    // Symmetric indefinite.
+    let indefinite = Matrix3::new(1.0, 2.0, 0.0, 2.0, -1.0, 1.0, 0.0, 1.0, 3.0);
+    assert!(indefinite.cholesky().is_none());
+    let x = indefinite.solve_spd_or_general(&b, 1.0e-12).unwrap();
+    assert_relative_eq!(indefinite * x, b, epsilon = 1.0e-12);
+
+    // Not symmetric, although its lower triangle is the one of a positive-definite matrix.
+    let mut non_symmetric = spd;
+    non_symmetric[(0, 2)] = 7.0;
+    let x = non_symmetric.solve_spd_or_general(&b, 1.0e-12).unwrap();
+    assert_relative_eq!(non_symmetric * x, b, epsilon = 1.0e-12);
+
+    // The tolerance decides whether small pivots are accepted.
+    let tiny_pivot = Matrix3::new(1.0, 0.0, 0.0, 0.0, 1.0e-10, 0.0, 0.0, 0.0, 1.0);
+    let x = tiny_pivot.solve_spd_or_general(&b, 1.0e-8).unwrap();
+    assert_relative_eq!(tiny_pivot * x, b, epsilon = 1.0e-12);
+
+    // Singular.
+    let singular = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 3.0, 6.0, 9.0);
+    assert!(singular.solve_spd_or_general(&b, 1.0e-12).is_none());
+}
+
+#[test]
+fn factorized_matrix_caches_factorization() {
+    let spd = Matrix3::new(4.0, 1.0, 0.5, 1.0, 3.0, -1.0, 0.5, -1.0, 5.0);
+    let b = Matrix3x2::new(1.0, 0.0, 2.0, -1.0, 3.0, 4.0);
+
+    for factorized in &[FactorizedMatrix::new(spd), FactorizedMatrix::new_spd(spd)] {
+        assert!(!factorized.is_factorized());
+        assert!(residual(factorized, &spd, &b) < 1.0e-12);
+        assert!(factorized.is_factorized());
+        assert!(residual(factorized, &spd, &b) < 1.0e-12);
+        assert_eq!(factorized.matrix(), &spd);
+    }
+
+    // The Cholesky decomposition fails on indefinite matrices, even if they are invertible.
+    let indefinite = Matrix3::new(1.0, 2.0, 0.0, 2.0, 1.0, 0.0, 0.0, 0.0, 1.0);
+    let mut x = b;
+    assert!(FactorizedMatrix::new_spd(indefinite).solve(&b).is_none());
+    assert!(!FactorizedMatrix::new_spd(indefinite).solve_mut(&mut x));
+    assert!(residual(&FactorizedMatrix::new(indefinite), &indefinite, &b) < 1.0e-12);
+
+    let singular = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 3.0, 6.0, 9.0);
+    assert!(FactorizedMatrix::new(singular).solve(&b).is_none());
+}
+
+#[test]
+fn factorized_matrix_is_shared_across_threads() {
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    let n = 20;
+    let a = DMatrix::from_fn(n, n, |i, j| {
+        if i == j {
+            10.0
+        } else {
+            1.0 / (1 + i + j) as f64
+        }
+    });
+    let factorized = Arc::new(FactorizedMatrix::new(a.clone()));
+    assert_send_sync(&factorized);
+
+    let handles: Vec<_> = (0..4)
+        .map(|k| {
+            let factorized = Arc::clone(&factorized);
+            thread::spawn(move || {
+                let b = DMatrix::from_fn(n, 1, |i, _| (i * k) as f64);
+                (factorized.solve(&b).unwrap(), b)
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        let (x, b) = handle.join().unwrap();
+        assert!((&a * x - b).norm() < 1.0e-10);
+    }
+    assert!(factorized.is_factorized());
+}
@@ -1,4 +1,4 @@
-use na::Matrix3;
+use na::{Complex, DMatrix, DVector, Matrix2, Matrix3, Matrix4, RowVector3, Vector2};
 
 #[test]
 #[rustfmt::skip]
@@ -38,12 +38,124 @@ fn lu_simple_with_pivot() {
     assert!(relative_eq!(m, lu, epsilon = 1.0e-7));
 }
 
+#[test]
+fn lu_non_contiguous_slice() {
+    let m = DMatrix::from_fn(7, 7, |i, j| if i == j { 10.0 } else { (i + 2 * j) as f64 });
+    let expected = DMatrix::from_fn(3, 3, |i, j| m[(1 + 2 * i, 2 + 2 * j)]);
+
+    let slice = m.slice_with_steps((1, 2), (3, 3), (1, 1));
+    let lu = slice.lu();
+    let (p, l, u) = lu.unpack();
+    let mut lu = l * u;
+    p.inv_permute_rows(&mut lu);
+    assert!(relative_eq!(expected, lu, epsilon = 1.0e-7));
+
+    let slice = m.fixed_slice::<4, 4>(2, 1);
+    let lu = slice.lu();
+    assert!(relative_eq!(
+        lu.determinant(),
+        slice.clone_owned().lu().determinant(),
+        epsilon = 1.0e-7
+    ));
+}
+
+#[test]
+fn lu_rcond() {
+    let diag = Matrix3::new(4.0, 0.0, 0.0, 0.0, -2.0, 0.0, 0.0, 0.0, 0.5);
+    assert_relative_eq!(diag.lu().rcond(&diag), 0.125, epsilon = 1.0e-12);
+
+    // The Hilbert matrices are notoriously ill-conditioned.
+    let hilbert = DMatrix::from_fn(6, 6, |i, j| 1.0 / (i + j + 1) as f64);
+    let norm1 = |m: &DMatrix<f64>| m.abs().row_sum().max();
+    let exact = 1.0 / (norm1(&hilbert) * norm1(&hilbert.clone().try_inverse().unwrap()));
+    let estimate = hilbert.clone().lu().rcond(&hilbert);
+    assert!(estimate >= exact * (1.0 - 1.0e-6));
+    assert!(estimate <= exact * 3.0);
+
+    let singular = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 1.0);
+    assert_eq!(singular.lu().rcond(&singular), 0.0);
+
+    let empty = DMatrix::<f64>::zeros(0, 0);
+    assert_eq!(empty.clone().lu().rcond(&empty), 1.0);
+}
+
+#[test]
+fn lu_solve_checked() {
+    let m = Matrix3::new(2.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 4.0);
+    let b = Matrix3::new(1.0, 0.0, 0.0, 2.0, 1.0, 0.0, 3.0, 0.0, 1.0)
+        .column(0)
+        .into_owned();
+    let x = m.lu().solve_checked(&m, &b, 0.1).unwrap();
+    assert_relative_eq!(m * x, b, epsilon = 1.0e-10);
+
+    // The warning carries the estimated rcond and the solution.
+    let warning = m.lu().solve_checked(&m, &b, 0.9).unwrap_err();
+    assert_relative_eq!(warning.rcond, m.lu().rcond(&m));
+    assert_eq!(warning.solution, m.lu().solve(&b));
+
+    let hilbert = DMatrix::from_fn(8, 8, |i, j| 1.0 / (i + j + 1) as f64);
+    let b = DVector::from_element(8, 1.0);
+    let warning = hilbert
+        .clone()
+        .lu()
+        .solve_checked(&hilbert, &b, 1.0e-8)
+        .unwrap_err();
+    assert!(warning.rcond < 1.0e-8);
+    assert!(warning.solution.is_some());
+
+    // Singular matrices always produce a warning without solution.
+    let singular = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 1.0);
+    let warning = singular
+        .lu()
+        .solve_checked(&singular, &b.fixed_rows::<3>(0), 0.0)
+        .unwrap_err();
+    assert_eq!(warning.rcond, 0.0);
+    assert_eq!(warning.solution, None);
+}
+
+#[test]
+#[rustfmt::skip]
+fn complex_determinant() {
+    let i = Complex::new(0.0, 1.0);
+    let c = |re: f64| Complex::new(re, 0.0);
+
+    // Closed-form 2x2 and 3x3 determinants.
+    let m2 = Matrix2::new(
+        c(1.0) + i, c(2.0),
+        c(3.0),     c(4.0) - i);
+    assert_eq!(m2.determinant(), Complex::new(-1.0, 3.0));
+
+    // An even permutation of the diagonal [1 + i, i, 2].
+    let m3 = Matrix3::new(
+        c(0.0),     i,      c(0.0),
+        c(0.0),     c(0.0), c(2.0),
+        c(1.0) + i, c(0.0), c(0.0));
+    assert_eq!(m3.determinant(), Complex::new(-2.0, 2.0));
+    assert_eq!(m3.lu().determinant(), Complex::new(-2.0, 2.0));
+
+    // LU path, with an odd number of row swaps.
+    let m4 = Matrix4::new(
+        c(0.0), i,      c(0.0),     c(0.0),
+        c(2.0), c(0.0), c(0.0),     c(0.0),
+        c(0.0), c(0.0), c(1.0) - i, c(0.0),
+        c(0.0), c(0.0), c(0.0),     c(3.0));
+    assert_eq!(m4.determinant(), Complex::new(-6.0, -6.0));
+
+    // LU path, with an even number of row swaps.
+    let mut m4 = m4;
+    m4.swap_rows(2, 3);
+    assert_eq!(m4.determinant(), Complex::new(6.0, 6.0));
+
+    let dm = DMatrix::from_diagonal(&DVector::from_vec(vec![i, i, c(2.0), i, c(0.5) + i]));
+    assert_eq!(dm.determinant(), Complex::new(2.0, -1.0));
+}
+
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
     macro_rules! gen_tests(
         ($module: ident, $scalar: expr, $scalar_type: ty) => {
             mod $module {
-                use na::{DMatrix, Matrix4x3, DVector, Vector4};
+                use na::{ComplexField, DMatrix, Matrix4x3, DVector, Vector4};
                 #[allow(unused_imports)]
                 use crate::core::helper::{RandScalar, RandComplex};
                 use crate::proptest::*;
@@ -60,6 +172,12 @@ mod proptest_tests {
                         prop_assert!(relative_eq!(m, lu, epsilon = 1.0e-7))
                     }
 
+                    #[test]
+                    fn determinant_closed_forms_agree_with_lu(m2 in matrix2_($scalar), m3 in matrix3_($scalar)) {
+                        prop_assert!(relative_eq!(m2.determinant(), m2.lu().determinant(), epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(m3.determinant(), m3.lu().determinant(), epsilon = 1.0e-7));
+                    }
+
                     #[test]
                     fn lu_static_3_5(m in matrix3x5_($scalar)) {
                         let lu = m.lu();
@@ -104,6 +222,22 @@ mod proptest_tests {
                         prop_assert!(sol2.is_none() || relative_eq!(&m * sol2.unwrap(), b2, epsilon = 1.0e-6));
                     }
 
+                    #[test]
+                    fn lu_solve_transpose(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+                        let m  = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+
+                        let lu = m.clone().lu();
+                        let b1 = DVector::<$scalar_type>::new_random(n).map(|e| e.0);
+                        let b2 = DMatrix::<$scalar_type>::new_random(n, nb).map(|e| e.0);
+
+                        let sol1 = lu.solve_transpose(&b1);
+                        let sol2 = lu.solve_transpose(&b2);
+
+                        prop_assert!(sol1.is_none() || relative_eq!(m.transpose() * sol1.unwrap(), b1, epsilon = 1.0e-6));
+                        prop_assert!(sol2.is_none() || relative_eq!(m.transpose() * sol2.unwrap(), b2, epsilon = 1.0e-6));
+                        prop_assert!(lu.solve(&b1).is_some() == lu.solve_transpose(&b1).is_some());
+                    }
+
                     #[test]
                     fn lu_solve_static(m in matrix4_($scalar)) {
                          let lu = m.lu();
@@ -117,6 +251,23 @@ mod proptest_tests {
                          prop_assert!(sol2.is_none() || relative_eq!(&m * sol2.unwrap(), b2, epsilon = 1.0e-6));
                     }
 
+                    #[test]
+                    fn lu_rcond(m in dmatrix_($scalar)) {
+                        let m = if m.is_square() { m } else { m.transpose() * &m };
+                        let lu = m.clone().lu();
+
+                        if let Some(inv) = lu.try_inverse() {
+                            let norm1 = |m: DMatrix<f64>| m.row_sum().max();
+                            let exact = 1.0
+                                / (norm1(m.map(|e| e.modulus())) * norm1(inv.map(|e| e.modulus())));
+                            let estimate = lu.rcond(&m);
+
+                            // The estimated norm of the inverse is a lower bound.
+                            prop_assert!(estimate >= exact * (1.0 - 1.0e-6));
+                            prop_assert!(estimate <= 1.0 + 1.0e-7);
+                        }
+                    }
+
                     #[test]
                     fn lu_inverse(n in PROPTEST_MATRIX_DIM) {
                         let m  = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
@@ -156,3 +307,28 @@ mod proptest_tests {
     gen_tests!(complex, complex_f64(), RandComplex<f64>);
     gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
 }
+
+#[test]
+#[rustfmt::skip]
+fn lu_solve_transpose_row_vector() {
+    // The first pivot requires a row swap.
+    let m = Matrix3::new(
+        0.0, -1.0,  2.0,
+       -1.0,  2.0, -1.0,
+        2.0, -1.0,  4.0);
+    let b = RowVector3::new(1.0, 2.0, 3.0);
+
+    let x = m.lu().solve_transpose(&b.transpose()).unwrap().transpose();
+    assert_relative_eq!(x * m, b, epsilon = 1.0e-12);
+
+    // This is the transpose, not the adjoint.
+    let m = Matrix2::new(
+        Complex::new(1.0, 1.0), Complex::new(2.0, 0.0),
+        Complex::new(0.0, -1.0), Complex::new(3.0, 2.0));
+    let b = Vector2::new(Complex::new(1.0, 0.0), Complex::new(0.0, 1.0));
+    let x = m.lu().solve_transpose(&b).unwrap();
+    assert_relative_eq!(m.transpose() * x, b, epsilon = 1.0e-12);
+
+    let singular = Matrix2::new(1.0, 2.0, 2.0, 4.0);
+    assert!(singular.lu().solve_transpose(&Vector2::new(1.0, 1.0)).is_none());
+}
@@ -1,14 +1,20 @@
+mod autodiff;
 mod balancing;
 mod bidiagonal;
 mod cholesky;
 mod col_piv_qr;
 mod convolution;
 mod eigen;
+mod exact_lu;
 mod exp;
 mod full_piv_lu;
 mod hessenberg;
+mod householder;
 mod inverse;
+mod linear_solve;
 mod lu;
+mod polar;
+mod polynomial;
 mod qr;
 mod schur;
 mod solve;
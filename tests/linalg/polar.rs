@@ -0,0 +1,107 @@
+use na::{Complex, DMatrix, Matrix2, Matrix3, Matrix4, Polar};
+
+#[test]
+#[rustfmt::skip]
+fn polar_newton_simple() {
+    let m = Matrix3::new(
+        2.0, -1.0,  0.0,
+        1.0,  3.0,  0.5,
+       -1.0,  0.0,  4.0);
+
+    let polar = Polar::new_newton(m, 1.0e-14, 100).unwrap();
+    let (u, p) = polar.clone().unpack();
+
+    assert_relative_eq!(u.transpose() * u, Matrix3::identity(), epsilon = 1.0e-10);
+    assert_relative_eq!(p, p.transpose(), epsilon = 1.0e-10);
+    assert!(p.symmetric_eigenvalues().iter().all(|e| *e > 0.0));
+    assert_relative_eq!(polar.recompose(), m, epsilon = 1.0e-10);
+}
+
+#[test]
+fn polar_newton_matches_svd() {
+    let m = Matrix4::from_fn(|i, j| 1.0 / (i + j + 1) as f64 + if i == j { 1.0 } else { 0.0 });
+
+    let newton = Polar::new_newton(m, 1.0e-14, 100).unwrap();
+    let svd = Polar::try_new(m, 1.0e-15, 0).unwrap();
+
+    assert_relative_eq!(newton.u(), svd.u(), epsilon = 1.0e-10);
+    assert_relative_eq!(newton.p(), svd.p(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn polar_newton_complex() {
+    let m = Matrix2::new(
+        Complex::new(1.0, 2.0),
+        Complex::new(0.5, -1.0),
+        Complex::new(-2.0, 0.0),
+        Complex::new(3.0, 1.0),
+    );
+
+    let polar = Polar::new_newton(m, 1.0e-14, 100).unwrap();
+
+    assert_relative_eq!(
+        polar.u().adjoint() * polar.u(),
+        Matrix2::identity(),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(polar.p(), &polar.p().adjoint(), epsilon = 1.0e-10);
+    assert_relative_eq!(polar.recompose(), m, epsilon = 1.0e-10);
+}
+
+#[test]
+fn polar_newton_dynamic() {
+    let m = DMatrix::from_fn(10, 10, |i, j| {
+        ((i * 7 + j * 3) % 5) as f64 + if i == j { 10.0 } else { 0.0 }
+    });
+    let polar = Polar::new_newton(m.clone(), 1.0e-14, 100).unwrap();
+
+    assert_relative_eq!(
+        polar.u().transpose() * polar.u(),
+        DMatrix::identity(10, 10),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(polar.recompose(), m, epsilon = 1.0e-10);
+}
+
+#[test]
+fn polar_newton_singular() {
+    let m = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 1.0);
+
+    assert!(Polar::new_newton(m, 1.0e-12, 100).is_none());
+
+    // The SVD-based path still handles singular matrices.
+    let polar = Polar::try_new(m, 1.0e-15, 0).unwrap();
+    assert_relative_eq!(polar.recompose(), m, epsilon = 1.0e-10);
+}
+
+#[test]
+fn polar_newton_non_convergence() {
+    let m = Matrix3::new(100.0, 1.0, 0.0, 0.0, 0.01, 1.0, 3.0, 0.0, 1.0);
+
+    assert!(Polar::new_newton(m, 1.0e-14, 1).is_none());
+    assert!(Polar::new_newton(m, 1.0e-14, 0).is_some());
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    use na::Polar;
+
+    use crate::proptest::*;
+    use proptest::{prop_assert, proptest};
+
+    proptest! {
+        #[test]
+        fn polar_newton(m in dmatrix()) {
+            let n = m.nrows().min(m.ncols());
+            let m = m.resize(n, n, 0.0);
+            // Shift the diagonal to keep the matrix well-conditioned.
+            let m = &m + na::DMatrix::<f64>::identity(n, n) * (m.norm() + 1.0);
+
+            let polar = Polar::new_newton(m.clone(), 1.0e-14, 100).unwrap();
+            let id = na::DMatrix::<f64>::identity(n, n);
+
+            prop_assert!(relative_eq!(polar.u().transpose() * polar.u(), id, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(polar.recompose(), m, epsilon = 1.0e-7));
+        }
+    }
+}
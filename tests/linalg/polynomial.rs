@@ -0,0 +1,150 @@
+use na::{polynomial_roots, Complex, DMatrix, Matrix1, Matrix4};
+
+fn sorted_by_re_im(mut roots: Vec<Complex<f64>>) -> Vec<Complex<f64>> {
+    roots.sort_by(|a, b| (a.re, a.im).partial_cmp(&(b.re, b.im)).unwrap());
+    roots
+}
+
+// Evaluates the polynomial with the given coefficients (by decreasing degree) at `x`.
+fn eval(coeffs: &[f64], x: Complex<f64>) -> Complex<f64> {
+    coeffs.iter().fold(Complex::new(0.0, 0.0), |acc, c| {
+        acc * x + Complex::new(*c, 0.0)
+    })
+}
+
+#[test]
+#[rustfmt::skip]
+fn companion_matrix() {
+    assert_eq!(DMatrix::<f64>::companion(&[]), DMatrix::zeros(0, 0));
+    assert_eq!(DMatrix::companion(&[3.0]), Matrix1::new(-3.0));
+    assert_eq!(
+        DMatrix::companion(&[1, -2, 3, -4]),
+        Matrix4::new(-1, 2, -3, 4,
+                      1, 0,  0, 0,
+                      0, 1,  0, 0,
+                      0, 0,  1, 0)
+    );
+}
+
+#[test]
+fn polynomial_roots_real() {
+    // 2 (x - 1)(x + 2)(x - 3)(x + 4)
+    let coeffs = [2.0, 4.0, -26.0, -28.0, 48.0];
+    let roots = sorted_by_re_im(polynomial_roots(&coeffs, 1.0e-12, 1000).unwrap());
+    let expected = [-4.0, -2.0, 1.0, 3.0];
+
+    assert_eq!(roots.len(), 4);
+    for (root, expected) in roots.iter().zip(expected.iter()) {
+        assert_relative_eq!(*root, Complex::new(*expected, 0.0), epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn polynomial_roots_complex() {
+    // (x² + 1)(x² - 2x + 5)(x - 0.5)
+    let coeffs = [1.0, -2.5, 7.0, -5.0, 6.0, -2.5];
+    let roots = sorted_by_re_im(polynomial_roots(&coeffs, 1.0e-12, 1000).unwrap());
+    let expected = [
+        Complex::new(0.0, -1.0),
+        Complex::new(0.0, 1.0),
+        Complex::new(0.5, 0.0),
+        Complex::new(1.0, -2.0),
+        Complex::new(1.0, 2.0),
+    ];
+
+    assert_eq!(roots.len(), 5);
+    for (root, expected) in roots.iter().zip(expected.iter()) {
+        assert_relative_eq!(*root, *expected, epsilon = 1.0e-10);
+        assert!(eval(&coeffs, *root).norm() < 1.0e-10);
+    }
+}
+
+#[test]
+fn polynomial_roots_leading_and_trailing_zeros() {
+    // 0x⁵ + 0x⁴ + x³ - 3x² + 2x + 0 = x (x - 1)(x - 2)
+    let roots = polynomial_roots(&[0.0, 0.0, 1.0, -3.0, 2.0, 0.0], 1.0e-12, 1000).unwrap();
+    let roots = sorted_by_re_im(roots);
+    assert_eq!(roots.len(), 3);
+    assert_eq!(roots[0], Complex::new(0.0, 0.0));
+    assert_relative_eq!(roots[1], Complex::new(1.0, 0.0), epsilon = 1.0e-12);
+    assert_relative_eq!(roots[2], Complex::new(2.0, 0.0), epsilon = 1.0e-12);
+
+    // 3x² has a double root at zero.
+    let roots = polynomial_roots(&[0.0, 3.0, 0.0, 0.0], 1.0e-12, 1000).unwrap();
+    assert_eq!(roots, vec![Complex::new(0.0, 0.0); 2]);
+}
+
+#[test]
+fn polynomial_roots_low_degree() {
+    let roots = polynomial_roots(&[2.0, -4.0], 1.0e-12, 1000).unwrap();
+    assert_eq!(roots, vec![Complex::new(2.0, 0.0)]);
+
+    // Constant polynomials have no roots.
+    let no_roots: Vec<Complex<f64>> = Vec::new();
+    assert_eq!(
+        polynomial_roots(&[5.0], 1.0e-12, 1000),
+        Some(no_roots.clone())
+    );
+    assert_eq!(
+        polynomial_roots(&[0.0, 0.0, 5.0], 1.0e-12, 1000),
+        Some(no_roots.clone())
+    );
+    assert_eq!(
+        polynomial_roots(&[0.0, 0.0], 1.0e-12, 1000),
+        Some(no_roots.clone())
+    );
+    assert_eq!(polynomial_roots::<f64>(&[], 1.0e-12, 1000), Some(no_roots));
+}
+
+#[test]
+fn polynomial_roots_repeated_roots() {
+    // (x - 1)²(x + 1)² = x⁴ - 2x² + 1 used to make the Schur decomposition cycle forever.
+    let roots = polynomial_roots(&[1.0, 0.0, -2.0, 0.0, 1.0], 1.0e-12, 1000).unwrap();
+    let roots = sorted_by_re_im(roots);
+    assert_eq!(roots.len(), 4);
+    for (root, expected) in roots.iter().zip([-1.0, -1.0, 1.0, 1.0].iter()) {
+        assert_relative_eq!(*root, Complex::new(*expected, 0.0), epsilon = 1.0e-6);
+    }
+
+    // (x - 6)(x + 1)(x + 2)²(x - 3)(x - 5) used to yield NaN for the double root.
+    let coeffs = [1.0, -9.0, 1.0, 117.0, -2.0, -468.0, -360.0];
+    let roots = polynomial_roots(&coeffs, 1.0e-12, 1000).unwrap();
+    let roots = sorted_by_re_im(roots);
+    assert_eq!(roots.len(), 6);
+    for (root, expected) in roots.iter().zip([-2.0, -2.0, -1.0, 3.0, 5.0, 6.0].iter()) {
+        assert_relative_eq!(*root, Complex::new(*expected, 0.0), epsilon = 1.0e-6);
+    }
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    use super::{eval, sorted_by_re_im};
+    use na::{polynomial_roots, Complex};
+    use proptest::collection::vec;
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    proptest! {
+        #[test]
+        fn polynomial_roots_from_real_roots(expected in vec(-10i32..10, 1..8)) {
+            // Expand the product of (x - r) for every expected root.
+            let mut coeffs = vec![1.0];
+            for r in &expected {
+                coeffs.push(0.0);
+                for k in (1..coeffs.len()).rev() {
+                    coeffs[k] -= *r as f64 * coeffs[k - 1];
+                }
+            }
+
+            let roots = polynomial_roots(&coeffs, 1.0e-12, 1000);
+            prop_assert!(roots.is_some());
+            let roots = roots.unwrap();
+            prop_assert_eq!(roots.len(), expected.len());
+
+            let scale = coeffs.iter().fold(1.0f64, |acc, c| acc.max(c.abs()));
+            for root in sorted_by_re_im(roots) {
+                prop_assert!(eval(&coeffs, root).norm() <= 1.0e-8 * scale * (1.0 + root.norm()).powi(coeffs.len() as i32));
+                prop_assert!(expected.iter().any(|r| (root - Complex::new(*r as f64, 0.0)).norm() < 1.0e-1));
+            }
+        }
+    }
+}
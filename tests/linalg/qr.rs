@@ -116,3 +116,72 @@ macro_rules! gen_tests(
 
 gen_tests!(complex, complex_f64(), RandComplex<f64>);
 gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+
+#[test]
+fn qr_non_contiguous_slice() {
+    let m = na::DMatrix::from_fn(8, 6, |i, j| {
+        ((i * 7 + j * 3) % 5) as f64 + (i == j) as u8 as f64
+    });
+
+    let slice = m.slice_with_steps((0, 1), (4, 2), (1, 1));
+    let qr = slice.qr();
+    assert!(relative_eq!(
+        slice.clone_owned(),
+        qr.q() * qr.r(),
+        epsilon = 1.0e-7
+    ));
+
+    let slice = m.fixed_slice::<3, 3>(1, 2);
+    let qr = slice.qr();
+    assert!(relative_eq!(
+        slice.clone_owned(),
+        qr.q() * qr.r(),
+        epsilon = 1.0e-7
+    ));
+}
+
+#[test]
+fn qr_complex_unitary() {
+    use na::{Complex, DMatrix};
+
+    // Entries with arbitrary phases. The second column starts with zeros, so that factoring
+    // the last two columns starts with a zero pivot.
+    let a = DMatrix::from_row_slice(
+        4,
+        3,
+        &[
+            Complex::new(0.0, 2.0),
+            Complex::new(0.0, 0.0),
+            Complex::new(1.0, -1.0),
+            Complex::new(1.0, 1.0),
+            Complex::new(0.0, 0.0),
+            Complex::new(-2.0, 0.5),
+            Complex::new(-3.0, 0.5),
+            Complex::new(4.0, -2.0),
+            Complex::new(0.0, 3.0),
+            Complex::new(0.5, -1.0),
+            Complex::new(2.0, 2.0),
+            Complex::new(-1.0, 0.0),
+        ],
+    );
+
+    for m in [
+        a.clone(),
+        a.columns(1, 2).into_owned(),
+        a.rows(0, 1).into_owned(),
+    ]
+    .iter()
+    {
+        let qr = m.clone().qr();
+        let q = qr.q();
+        let r = qr.r();
+
+        assert_relative_eq!(
+            q.ad_mul(&q),
+            DMatrix::identity(q.ncols(), q.ncols()),
+            epsilon = 1.0e-12
+        );
+        assert!(r.is_upper_triangular(0.0));
+        assert_relative_eq!(&q * &r, *m, epsilon = 1.0e-12);
+    }
+}
@@ -1,4 +1,4 @@
-use na::{DMatrix, Matrix3, Matrix4};
+use na::{Complex, DMatrix, Matrix3, Matrix4};
 
 #[test]
 #[rustfmt::skip]
@@ -54,6 +54,22 @@ mod proptest_tests {
         }
     );
 
+    mod eigenvalues {
+        use crate::proptest::*;
+        use na::Complex;
+        use proptest::{prop_assert, proptest};
+
+        proptest! {
+            #[test]
+            fn schur_complex_eigenvalues_sum_to_trace(m in matrix4_(PROPTEST_F64)) {
+                let eigenvalues = m.schur().complex_eigenvalues();
+                let sum = eigenvalues.iter().fold(Complex::new(0.0, 0.0), |acc, e| acc + e);
+                prop_assert!(relative_eq!(sum.re, m.trace(), epsilon = 1.0e-7));
+                prop_assert!(relative_eq!(sum.im, 0.0, epsilon = 1.0e-7));
+            }
+        }
+    }
+
     gen_tests!(complex, complex_f64(), RandComplex<f64>);
     gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
 }
@@ -129,3 +145,116 @@ fn schur_singular() {
     let (vecs, vals) = m.clone().schur().unpack();
     assert!(relative_eq!(&vecs * vals * vecs.transpose(), m, epsilon = 1.0e-7))
 }
+
+#[test]
+fn schur_empty_eigenvalues() {
+    let schur = DMatrix::<f64>::zeros(0, 0).schur();
+    assert_eq!(schur.eigenvalues().unwrap().len(), 0);
+    assert_eq!(schur.complex_eigenvalues().len(), 0);
+}
+
+#[test]
+#[rustfmt::skip]
+fn schur_eigenvalues_real_and_complex_blocks() {
+    // Eigenvalues: 1 ± 2i, 3 and -1.
+    let m = Matrix4::new(
+        1.0, -2.0, 0.5, 0.0,
+        2.0,  1.0, 0.0, 1.0,
+        0.0,  0.0, 3.0, 0.2,
+        0.0,  0.0, 0.0, -1.0);
+
+    let schur = m.schur();
+    assert!(schur.eigenvalues().is_none());
+
+    let eigenvalues = schur.complex_eigenvalues();
+    let pair = eigenvalues.iter().position(|e| e.im != 0.0).unwrap();
+    assert_relative_eq!(eigenvalues[pair], Complex::new(1.0, 2.0), epsilon = 1.0e-7);
+    assert_relative_eq!(eigenvalues[pair + 1], Complex::new(1.0, -2.0), epsilon = 1.0e-7);
+
+    let mut reals: Vec<_> = eigenvalues.iter().filter(|e| e.im == 0.0).map(|e| e.re).collect();
+    reals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(reals.len(), 2);
+    assert_relative_eq!(reals[0], -1.0, epsilon = 1.0e-7);
+    assert_relative_eq!(reals[1], 3.0, epsilon = 1.0e-7);
+
+    // Without the rotation block, all the eigenvalues are real.
+    let real = m.fixed_slice::<2, 2>(2, 2).clone_owned();
+    let mut eigenvalues: Vec<_> = real.schur().eigenvalues().unwrap().iter().copied().collect();
+    eigenvalues.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_relative_eq!(eigenvalues[0], -1.0, epsilon = 1.0e-7);
+    assert_relative_eq!(eigenvalues[1], 3.0, epsilon = 1.0e-7);
+}
+
+#[test]
+#[rustfmt::skip]
+fn schur_eigenvalue_condition_numbers() {
+    // With `m = S D S⁻¹`, the right and left eigenvectors of `D[i]` are `S e_i` and `S⁻ᴴ e_i`,
+    // and `(S⁻ᴴ e_i)ᴴ S e_i = 1`.
+    let s = Matrix4::new(
+        1.0, 2.0, 0.0, 0.5,
+        0.0, 1.0, 3.0, 0.0,
+        0.5, 0.0, 1.0, 1.0,
+        0.0, 0.0, 0.5, 1.0);
+    let s_inv = s.try_inverse().unwrap();
+    let d = [1.0, 2.0, 4.0, 8.0];
+    let m = s * Matrix4::from_diagonal(&d.into()) * s_inv;
+
+    let schur = m.schur();
+    let eigenvalues = schur.eigenvalues().unwrap();
+    let cond = schur.eigenvalue_condition_numbers().unwrap();
+
+    for (lambda, c) in eigenvalues.iter().zip(cond.iter()) {
+        let i = d.iter().position(|e| relative_eq!(e, lambda, epsilon = 1.0e-7)).unwrap();
+        let expected = s.column(i).norm() * s_inv.row(i).norm();
+        assert_relative_eq!(*c, expected, epsilon = 1.0e-6);
+    }
+}
+
+#[test]
+fn schur_eigenvalue_condition_numbers_normal() {
+    let m = DMatrix::from_fn(5, 5, |i, j| {
+        (i + j) as f64 + if i == j { i as f64 } else { 0.0 }
+    });
+    let cond = m.schur().eigenvalue_condition_numbers().unwrap();
+    assert!(cond.iter().all(|c| relative_eq!(*c, 1.0, epsilon = 1.0e-7)));
+
+    // Eigenvalues with multiplicity but a full set of eigenvectors are still well-conditioned.
+    let cond = Matrix3::<f64>::identity()
+        .schur()
+        .eigenvalue_condition_numbers()
+        .unwrap();
+    assert_eq!(cond, na::Vector3::repeat(1.0));
+
+    let cond = DMatrix::<f64>::zeros(0, 0)
+        .schur()
+        .eigenvalue_condition_numbers();
+    assert_eq!(cond.unwrap().len(), 0);
+}
+
+#[test]
+#[rustfmt::skip]
+fn schur_eigenvalue_condition_numbers_defective() {
+    let jordan = Matrix3::new(
+        2.0, 1.0, 0.0,
+        0.0, 2.0, 1.0,
+        0.0, 0.0, 2.0);
+    let cond = jordan.schur().eigenvalue_condition_numbers().unwrap();
+    assert!(cond.iter().all(|c: &f64| c.is_finite() && *c > 1.0e10));
+}
+
+#[test]
+fn schur_eigenvalue_condition_numbers_complex() {
+    // A rotation has complex eigenvalues, which are not supported for real matrices.
+    let rot = Matrix3::new(0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 2.0);
+    assert!(rot.schur().eigenvalue_condition_numbers().is_none());
+
+    // An upper-triangular complex matrix has the same condition numbers as its real part.
+    let m = Matrix3::new(1.0, 10.0, 0.0, 0.0, 2.0, 5.0, 0.0, 0.0, 3.0);
+    let real_cond = m.schur().eigenvalue_condition_numbers().unwrap();
+    let cond = m
+        .map(|e| Complex::new(e, 0.0))
+        .schur()
+        .eigenvalue_condition_numbers()
+        .unwrap();
+    assert_relative_eq!(cond, real_cond, epsilon = 1.0e-7);
+}
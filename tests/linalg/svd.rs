@@ -1,4 +1,4 @@
-use na::{DMatrix, Matrix6};
+use na::{DMatrix, Matrix2, Matrix3, Matrix6};
 
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
@@ -24,10 +24,65 @@ mod proptest_tests {
                         let ds = DMatrix::from_diagonal(&s.map(|e| ComplexField::from_real(e)));
 
                         prop_assert!(s.iter().all(|e| *e >= 0.0));
+                        prop_assert!(s.as_slice().windows(2).all(|w| w[0] >= w[1]));
                         prop_assert!(relative_eq!(&u * ds * &v_t, recomp_m, epsilon = 1.0e-5));
                         prop_assert!(relative_eq!(m, recomp_m, epsilon = 1.0e-5));
                     }
 
+                    #[test]
+                    fn svd_rank_k_approximation(m in dmatrix_($scalar), k in 0..5usize) {
+                        let svd = m.clone().svd(true, true);
+                        let approx = svd.rank_k_approximation(k).unwrap();
+                        let k = cmp::min(k, svd.singular_values.len());
+                        let discarded = svd.singular_values.rows_range(k..).norm();
+
+                        prop_assert!(approx.rank(1.0e-7) <= k);
+                        prop_assert!(relative_eq!((m - approx).norm(), discarded, epsilon = 1.0e-5));
+                    }
+
+                    #[test]
+                    fn svd_zero_trailing_singular_values(m in dmatrix_($scalar), k in 0..5usize) {
+                        let svd = m.svd(true, true);
+                        let mut zeroed = svd.clone();
+                        zeroed.zero_trailing_singular_values(k);
+                        let k = cmp::min(k, svd.singular_values.len());
+
+                        prop_assert!(zeroed.u == svd.u);
+                        prop_assert!(zeroed.v_t == svd.v_t);
+                        prop_assert!(zeroed.singular_values.rows(0, k) == svd.singular_values.rows(0, k));
+                        prop_assert!(zeroed.singular_values.rows_range(k..).iter().all(|s| *s == 0.0));
+                    }
+
+                    #[test]
+                    fn svd_truncated(m in dmatrix_($scalar), k in 0..5usize) {
+                        let svd = m.clone().svd(true, true);
+                        let truncated = na::SVD::new_truncated(m.clone(), k);
+                        let k = cmp::min(k, svd.singular_values.len());
+                        let u = truncated.u.clone().unwrap();
+                        let v_t = truncated.v_t.clone().unwrap();
+
+                        prop_assert!(u.shape() == (m.nrows(), k));
+                        prop_assert!(v_t.shape() == (k, m.ncols()));
+                        prop_assert!(relative_eq!(truncated.singular_values, svd.singular_values.rows(0, k).into_owned(), epsilon = 1.0e-5));
+                        prop_assert!(relative_eq!(u.adjoint() * &u, DMatrix::identity(k, k), epsilon = 1.0e-5));
+                        prop_assert!(relative_eq!(truncated.recompose().unwrap(), svd.rank_k_approximation(k).unwrap(), epsilon = 1.0e-5));
+                    }
+
+                    #[test]
+                    fn svd_canonicalize_signs(m in dmatrix_($scalar)) {
+                        let mut svd = m.clone().svd(true, true);
+                        svd.canonicalize_signs();
+                        prop_assert!(relative_eq!(svd.recompose().unwrap(), m, epsilon = 1.0e-5));
+
+                        for col in svd.u.unwrap().column_iter() {
+                            let max = col.iter().fold(col[0], |max, e| {
+                                if e.modulus_squared() > max.modulus_squared() { *e } else { max }
+                            });
+                            prop_assert!(max.real() > 0.0);
+                            prop_assert!(relative_eq!(max.imaginary(), 0.0, epsilon = 1.0e-10));
+                        }
+                    }
+
                     #[test]
                     fn svd_static_5_3(m in matrix5x3_($scalar)) {
                         let svd = m.svd(true, true);
@@ -85,6 +140,15 @@ mod proptest_tests {
                         prop_assert!(v_t.is_orthogonal(1.0e-5));
                     }
 
+                    #[test]
+                    fn svd_signed_log_determinant(m in matrix4_($scalar)) {
+                        let (sign, ln_abs_det) = m.signed_log_determinant_svd();
+                        let det = sign.scale(ln_abs_det.exp());
+
+                        prop_assert!(relative_eq!(sign.modulus(), 1.0, epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(det, m.determinant(), epsilon = 1.0e-5));
+                    }
+
                     #[test]
                     fn svd_static_square_2x2(m in matrix2_($scalar)) {
                         let svd = m.svd(true, true);
@@ -138,6 +202,34 @@ mod proptest_tests {
 
     gen_tests!(complex, complex_f64(), RandComplex<f64>);
     gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+
+    mod bidiagonal {
+        use crate::proptest::*;
+        use na::{DMatrix, DVector, SVD};
+        use proptest::{prop_assert, proptest};
+
+        proptest! {
+            #[test]
+            fn svd_bidiagonal(m in dmatrix()) {
+                let n = m.nrows().min(m.ncols());
+                if n > 0 {
+                    let diagonal = DVector::from_fn(n, |i, _| m[(i, i)]);
+                    let off_diagonal = DVector::from_fn(n - 1, |i, _| m[(i, i + 1)]);
+                    let bidiagonal = DMatrix::from_fn(n, n, |i, j| {
+                        if i == j || i + 1 == j { m[(i, j)] } else { 0.0 }
+                    });
+
+                    let svd = SVD::try_new_bidiagonal(diagonal, off_diagonal, true, true, 1.0e-15, 0).unwrap();
+                    let (u, v_t) = (svd.u.clone().unwrap(), svd.v_t.clone().unwrap());
+
+                    prop_assert!(u.is_orthogonal(1.0e-7));
+                    prop_assert!(v_t.is_orthogonal(1.0e-7));
+                    prop_assert!(relative_eq!(svd.recompose().unwrap(), bidiagonal, epsilon = 1.0e-7));
+                    prop_assert!(relative_eq!(svd.singular_values, bidiagonal.svd(false, false).singular_values, epsilon = 1.0e-7));
+                }
+            }
+        }
+    }
 }
 
 // Test proposed on the issue #176 of rulinalg.
@@ -265,6 +357,104 @@ fn svd_zeros() {
     assert_eq!(Ok(m), svd.recompose());
 }
 
+#[test]
+fn svd_sorted_singular_values() {
+    // The bidiagonalization of a diagonal matrix is the matrix itself, so the
+    // singular values are naturally computed in the order of the diagonal.
+    let m = DMatrix::from_diagonal(&na::DVector::from_vec(vec![1.0, -5.0, 0.0, 3.0, 2.0]));
+    let svd = m.clone().svd(true, true);
+
+    assert_eq!(svd.singular_values.as_slice(), &[5.0, 3.0, 2.0, 1.0, 0.0]);
+    assert_relative_eq!(m, svd.recompose().unwrap(), epsilon = 1.0e-7);
+
+    let m = Matrix6::from_diagonal(&na::Vector6::new(0.5, 4.0, -6.0, 1.0, 0.0, 2.0));
+    assert_eq!(
+        m.singular_values(),
+        na::Vector6::new(6.0, 4.0, 2.0, 1.0, 0.5, 0.0)
+    );
+}
+
+#[test]
+fn svd_signed_log_determinant() {
+    // The determinant, -6e-360, is not representable.
+    let m = Matrix3::new(0.0, 2.0, 0.0, 3.0, 0.0, 0.0, 0.0, 0.0, 1.0) * 1.0e-120;
+    assert_eq!(m.determinant(), 0.0);
+
+    let (sign, ln_abs_det) = m.signed_log_determinant_svd();
+    assert_eq!(sign, -1.0);
+    assert_relative_eq!(
+        ln_abs_det,
+        6.0f64.ln() - 360.0 * 10.0f64.ln(),
+        epsilon = 1.0e-10
+    );
+
+    let singular = Matrix6::from_diagonal(&na::Vector6::new(0.5, 4.0, -6.0, 1.0, 0.0, 2.0));
+    let (sign, ln_abs_det) = singular.signed_log_determinant_svd();
+    assert_eq!(sign, 0.0);
+    assert_eq!(ln_abs_det, f64::NEG_INFINITY);
+
+    let (sign, ln_abs_det) = DMatrix::<f64>::identity(4, 4).signed_log_determinant_svd();
+    assert_eq!((sign, ln_abs_det), (1.0, 0.0));
+}
+
+#[test]
+fn svd_canonicalize_signs() {
+    let m = DMatrix::from_fn(5, 3, |i, j| ((i * 3 + j) as f64).sin());
+    let mut svd = m.clone().svd(true, true);
+    let mut flipped = svd.clone();
+
+    // Flip the signs of some pairs of singular vectors, which gives another valid SVD.
+    for i in &[0, 2] {
+        flipped.u.as_mut().unwrap().column_mut(*i).neg_mut();
+        flipped.v_t.as_mut().unwrap().row_mut(*i).neg_mut();
+    }
+    assert_relative_eq!(flipped.recompose().unwrap(), m, epsilon = 1.0e-10);
+
+    svd.canonicalize_signs();
+    flipped.canonicalize_signs();
+    assert_eq!(svd.u, flipped.u);
+    assert_eq!(svd.v_t, flipped.v_t);
+    assert_relative_eq!(svd.recompose().unwrap(), m, epsilon = 1.0e-10);
+
+    // Canonicalizing twice does nothing.
+    let canonical = svd.clone();
+    svd.canonicalize_signs();
+    assert_eq!(svd.u, canonical.u);
+
+    // Without `U`, the rows of `V^t` are normalized instead.
+    let mut svd_v = m.clone().svd(false, true);
+    svd_v.v_t.as_mut().unwrap().row_mut(1).neg_mut();
+    svd_v.canonicalize_signs();
+    for row in svd_v.v_t.unwrap().row_iter() {
+        let max = row.iter().fold(
+            0.0,
+            |max: f64, e| if e.abs() > max.abs() { *e } else { max },
+        );
+        assert!(max > 0.0);
+    }
+}
+
+#[test]
+fn svd_recompose_modified_singular_values() {
+    let m = DMatrix::from_fn(5, 3, |i, j| ((i * 3 + j * 7) % 11) as f64 - 4.0);
+    let mut svd = m.clone().svd(true, true);
+    assert_relative_eq!(m, svd.recompose().unwrap(), epsilon = 1.0e-7);
+
+    // Rank-2 approximation of a rank-3 matrix, for both tall and wide shapes.
+    svd.singular_values[2] = 0.0;
+    let approx = svd.recompose().unwrap();
+    assert_eq!(approx.shape(), (5, 3));
+    assert_eq!(approx.rank(1.0e-7), 2);
+
+    let mut svd = m.transpose().svd(true, true);
+    svd.singular_values[2] = 0.0;
+    assert_relative_eq!(
+        approx.transpose(),
+        svd.recompose().unwrap(),
+        epsilon = 1.0e-7
+    );
+}
+
 #[test]
 fn svd_identity() {
     let m = DMatrix::<f64>::identity(10, 10);
@@ -316,6 +506,30 @@ fn svd_with_delimited_subproblem() {
     assert_relative_eq!(m.transpose(), svd.recompose().unwrap(), epsilon = 1.0e-7);
 }
 
+#[test]
+fn svd_2x2_with_tiny_diagonal_element() {
+    // Upper-triangular 2x2 subproblems with a tiny diagonal element used to yield wrong
+    // singular values when the singular vectors were computed.
+    let m = Matrix2::new(
+        0.9219290578626418,
+        -82.87437830500667,
+        0.0,
+        2.3000276652336283e-14,
+    );
+    let svd = m.svd(true, true);
+    assert_relative_eq!(svd.singular_values, m.singular_values(), epsilon = 1.0e-10);
+    assert_relative_eq!(m, svd.recompose().unwrap(), epsilon = 1.0e-10);
+
+    let m = Matrix2::new(
+        3.740583769462722,
+        5.6222682351328545e-8,
+        0.0,
+        -5.036049050033964,
+    );
+    let svd = m.svd(true, true);
+    assert_relative_eq!(m, svd.recompose().unwrap(), epsilon = 1.0e-14);
+}
+
 #[test]
 #[rustfmt::skip]
 fn svd_fail() {
@@ -344,3 +558,54 @@ fn svd_err() {
         svd.clone().pseudo_inverse(-1.0)
     );
 }
+
+#[test]
+#[rustfmt::skip]
+fn svd_bidiagonal() {
+    use na::{Complex, Vector2, Vector3, SVD};
+
+    let diagonal = Vector3::new(3.0, 0.0, -2.0);
+    let off_diagonal = Vector2::new(1.0, 4.0);
+    let bidiagonal = Matrix3::new(
+        3.0, 1.0,  0.0,
+        0.0, 0.0,  4.0,
+        0.0, 0.0, -2.0);
+
+    let svd = SVD::<f64, _, _>::try_new_bidiagonal(diagonal, off_diagonal, true, true, 1.0e-15, 0).unwrap();
+    assert_relative_eq!(svd.recompose().unwrap(), bidiagonal, epsilon = 1.0e-12);
+    assert_relative_eq!(svd.singular_values, bidiagonal.singular_values(), epsilon = 1.0e-12);
+    assert!(svd.singular_values[2].abs() < 1.0e-12);
+
+    // Singular vectors can be requested in a complex field as well.
+    let svd = SVD::<Complex<f64>, _, _>::try_new_bidiagonal(diagonal, off_diagonal, true, false, 1.0e-15, 0).unwrap();
+    assert!(svd.v_t.is_none());
+    assert_relative_eq!(svd.singular_values, bidiagonal.singular_values(), epsilon = 1.0e-12);
+
+    // Degenerate cases.
+    let svd = SVD::<f64, _, _>::try_new_bidiagonal(Vector3::zeros(), Vector2::zeros(), true, true, 1.0e-15, 0).unwrap();
+    assert_eq!(svd.singular_values, Vector3::zeros());
+    assert_eq!(svd.recompose().unwrap(), Matrix3::zeros());
+
+    let d = na::DVector::from_element(1, -2.0);
+    let svd = SVD::<f64, _, _>::try_new_bidiagonal(d, na::DVector::zeros(0), true, true, 1.0e-15, 0).unwrap();
+    assert_eq!(svd.singular_values[0], 2.0);
+    assert_eq!(svd.recompose().unwrap(), DMatrix::from_element(1, 1, -2.0));
+}
+
+#[test]
+#[should_panic(expected = "the off-diagonal must have one element less than the diagonal")]
+fn svd_bidiagonal_wrong_off_diagonal_length() {
+    let d = na::DVector::from_element(3, 1.0);
+    let e = na::DVector::from_element(3, 1.0);
+    let _ = na::SVD::<f64, _, _>::try_new_bidiagonal(d, e, false, false, 1.0e-15, 0);
+}
+
+#[test]
+fn svd_truncated_keeps_all_when_k_is_large() {
+    let m = DMatrix::from_fn(3, 5, |i, j| ((i * 3 + j * 7) % 11) as f64 - 4.0);
+    let svd = na::SVD::try_new_truncated(m.clone(), 10, 1.0e-15, 0).unwrap();
+
+    assert_eq!(svd.singular_values.len(), 3);
+    assert_relative_eq!(svd.singular_values, m.singular_values(), epsilon = 1.0e-10);
+    assert_relative_eq!(svd.recompose().unwrap(), m, epsilon = 1.0e-10);
+}
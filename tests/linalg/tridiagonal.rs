@@ -39,6 +39,25 @@ macro_rules! gen_tests(
                     prop_assert!(relative_eq!(m.lower_triangle(), recomp.lower_triangle(), epsilon = 1.0e-7));
                 }
 
+                #[test]
+                fn solve_tridiagonal_agrees_with_dense(m in dmatrix_($scalar)) {
+                    let n = m.nrows().min(m.ncols());
+                    // Make the matrix diagonally dominant so that no pivot vanishes.
+                    let m = m.slice((0, 0), (n, n)).scale(1.0e-3) + na::DMatrix::identity(n, n);
+                    let sub: Vec<_> = (1..n).map(|i| m[(i, i - 1)]).collect();
+                    let diag: Vec<_> = (0..n).map(|i| m[(i, i)]).collect();
+                    let sup: Vec<_> = (1..n).map(|i| m[(i - 1, i)]).collect();
+                    let rhs = na::DVector::from_fn(n, |i, _| m[(n - 1 - i, i)]);
+
+                    let mut dense = m.clone();
+                    dense.fill_lower_triangle(na::zero(), 2);
+                    dense.fill_upper_triangle(na::zero(), 2);
+
+                    let x = na::solve_tridiagonal(&sub, &diag, &sup, &rhs).unwrap();
+                    prop_assert!(relative_eq!(&dense * &x, rhs, epsilon = 1.0e-7));
+                    prop_assert!(relative_eq!(x, dense.lu().solve(&rhs).unwrap(), epsilon = 1.0e-7));
+                }
+
                 #[test]
                 fn symm_tridiagonal_static_square_2x2(m in matrix2_($scalar)) {
                     let m = m.hermitian_part();
@@ -54,3 +73,27 @@ macro_rules! gen_tests(
 
 gen_tests!(complex, complex_f64());
 gen_tests!(f64, PROPTEST_F64);
+
+#[test]
+fn solve_tridiagonal_zero_pivot() {
+    use na::{solve_tridiagonal, DVector};
+
+    // The first pivot is zero, even though the matrix is invertible.
+    let rhs = DVector::from_element(2, 1.0);
+    assert!(solve_tridiagonal(&[1.0], &[0.0, 1.0], &[1.0], &rhs).is_none());
+
+    // The second pivot vanishes after elimination: the matrix is singular.
+    assert!(solve_tridiagonal(&[2.0], &[1.0, 4.0], &[2.0], &rhs).is_none());
+
+    let x = solve_tridiagonal(&[], &[4.0], &[], &DVector::from_element(1, 2.0)).unwrap();
+    assert_eq!(x, DVector::from_element(1, 0.5));
+    let x = solve_tridiagonal::<f64>(&[], &[], &[], &DVector::zeros(0)).unwrap();
+    assert!(x.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "the sub- and superdiagonals must have one element less")]
+fn solve_tridiagonal_wrong_lengths() {
+    let rhs = na::DVector::from_element(3, 1.0);
+    let _ = na::solve_tridiagonal(&[1.0, 1.0], &[4.0, 4.0, 4.0], &[1.0], &rhs);
+}